@@ -0,0 +1,44 @@
+use correct_word::bench_support::WordGenerator;
+use correct_word::{correct_word, Algorithm};
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+
+const ALGORITHMS: [(&str, Algorithm); 10] = [
+    ("levenshtein", Algorithm::Levenshtein),
+    ("lcs", Algorithm::Lcs),
+    ("ratcliff_obershelp", Algorithm::RatcliffObershelp),
+    ("partial", Algorithm::Partial),
+    ("osa", Algorithm::Osa),
+    ("damerau_levenshtein", Algorithm::DamerauLevenshtein),
+    ("subsequence", Algorithm::Subsequence),
+    ("cosine", Algorithm::Cosine),
+    ("jaccard", Algorithm::Jaccard),
+    ("sift4", Algorithm::Sift4),
+];
+
+fn algorithms(c: &mut Criterion) {
+    let words = [("short", 3..8), ("long", 20..40)];
+    let dictionaries = [("small", 100), ("large", 10_000)];
+
+    for (word_name, length) in words {
+        for (dictionary_name, size) in dictionaries {
+            let mut generator = WordGenerator::new(42);
+            let dictionary = generator.words(size, length.clone());
+            let input = generator.misspell(&dictionary[size / 2], 2);
+
+            let mut group = c.benchmark_group(format!("{word_name}_words/{dictionary_name}"));
+            for (name, algorithm) in ALGORITHMS {
+                group.bench_function(BenchmarkId::from_parameter(name), |b| {
+                    b.iter_batched(
+                        || dictionary.clone(),
+                        |options| correct_word(algorithm, input.clone(), options, None),
+                        BatchSize::LargeInput,
+                    )
+                });
+            }
+            group.finish();
+        }
+    }
+}
+
+criterion_group!(benches, algorithms);
+criterion_main!(benches);
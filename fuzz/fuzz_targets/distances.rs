@@ -0,0 +1,38 @@
+#![no_main]
+
+use correct_word::automaton::LevenshteinAutomaton;
+use correct_word::search::find_fuzzy;
+use correct_word::{Algorithm, Granularity, Scorer};
+use libfuzzer_sys::fuzz_target;
+
+const ALGORITHMS: [Algorithm; 10] = [
+    Algorithm::Levenshtein,
+    Algorithm::Lcs,
+    Algorithm::RatcliffObershelp,
+    Algorithm::Partial,
+    Algorithm::Osa,
+    Algorithm::DamerauLevenshtein,
+    Algorithm::Subsequence,
+    Algorithm::Cosine,
+    Algorithm::Jaccard,
+    Algorithm::Sift4,
+];
+
+// Splits the input in two strings at the first NUL, and runs every distance function on them
+fuzz_target!(|data: &str| {
+    let (a, b) = data.split_once('\0').unwrap_or((data, ""));
+    for algorithm in ALGORITHMS {
+        for granularity in [Granularity::Chars, Granularity::Words] {
+            let similarity = algorithm.similarity_with(a, b, granularity);
+            assert!((0.0..=1.0).contains(&similarity), "{algorithm:?} {similarity}");
+            algorithm.distance_with(a, b, granularity);
+        }
+    }
+
+    let distance = Algorithm::Levenshtein.distance(a, b);
+    assert_eq!(distance, Algorithm::Levenshtein.distance(b, a));
+    assert!(LevenshteinAutomaton::new(a, distance).matches(b));
+    for found in find_fuzzy(a, b, 2) {
+        assert!(b.is_char_boundary(found.start) && b.is_char_boundary(found.end));
+    }
+});
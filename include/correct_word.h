@@ -0,0 +1,102 @@
+/*
+ * C bindings for correct_word, built with the `capi` feature.
+ *
+ * Ownership rules:
+ * - Strings passed in are borrowed for the duration of the call, and must be NUL-terminated UTF-8.
+ * - A word returned in a CorrectWordResult belongs to the caller, and must be freed with correct_word_free_string.
+ *   Never free it with free(), and never pass a string that didn't come from this library to correct_word_free_string.
+ */
+
+
+#ifndef CORRECT_WORD_H
+#define CORRECT_WORD_H
+
+/* Generated with cbindgen from src/ffi.rs, don't edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The Levenshtein algorithm.
+#define CORRECT_WORD_LEVENSHTEIN 0
+
+// The longest common subsequence algorithm.
+#define CORRECT_WORD_LCS 1
+
+// The Ratcliff/Obershelp algorithm, like Python's difflib.
+#define CORRECT_WORD_RATCLIFF_OBERSHELP 2
+
+// The partial similarity, for finding a word inside a longer string.
+#define CORRECT_WORD_PARTIAL 3
+
+// The optimal string alignment distance, Levenshtein with swaps of adjacent characters.
+#define CORRECT_WORD_OSA 4
+
+// The true Damerau-Levenshtein distance.
+#define CORRECT_WORD_DAMERAU_LEVENSHTEIN 5
+
+// Matches the input as an abbreviation of the option, like fuzzy finders do.
+#define CORRECT_WORD_SUBSEQUENCE 6
+
+// The cosine similarity of the character bigrams.
+#define CORRECT_WORD_COSINE 7
+
+// The Jaccard index of the sets of character bigrams.
+#define CORRECT_WORD_JACCARD 8
+
+// A linear time approximation of the edit distance.
+#define CORRECT_WORD_SIFT4 9
+
+// The result of `correct_word_correct`.
+//
+// `word` is NULL when no option cleared the threshold, or when the arguments were invalid.
+// Otherwise, it must be freed with `correct_word_free_string`.
+// `confidence` is the similarity of the best option, from 0 to 1, or -1 when the arguments were invalid.
+typedef struct CorrectWordResult {
+  char *word;
+  double confidence;
+} CorrectWordResult;
+
+// Calculates the distance between two strings with the given algorithm.
+//
+// Returns -1 when a string is NULL or not valid UTF-8, or the algorithm is unknown.
+//
+// # Safety
+//
+// `string1` and `string2` must be NULL or point to NUL-terminated strings.
+int32_t correct_word_distance(const char *string1, const char *string2, int algorithm);
+
+// Calculates the similarity, from 0 to 1, between two strings with the given algorithm.
+//
+// Returns -1 when a string is NULL or not valid UTF-8, or the algorithm is unknown.
+//
+// # Safety
+//
+// `string1` and `string2` must be NULL or point to NUL-terminated strings.
+double correct_word_similarity(const char *string1, const char *string2, int algorithm);
+
+// Corrects a word from an array of `options_len` options, returning the best option that clears the threshold.
+// The threshold is the minimum similarity, from 0 to 1.
+//
+// Options that are NULL or not valid UTF-8 are skipped.
+// Invalid arguments give a result with a NULL word and a confidence of -1.
+//
+// # Safety
+//
+// `input` must be NULL or point to a NUL-terminated string.
+// `options` must be NULL or point to `options_len` pointers, each NULL or pointing to a NUL-terminated string.
+struct CorrectWordResult correct_word_correct(const char *input,
+                                              const char *const *options,
+                                              uintptr_t options_len,
+                                              int algorithm,
+                                              double threshold);
+
+// Frees a word returned by this library. Passing NULL does nothing.
+//
+// # Safety
+//
+// `string` must be NULL or a word returned by this library that hasn't been freed yet.
+void correct_word_free_string(char *string);
+
+#endif  /* CORRECT_WORD_H */
@@ -0,0 +1,144 @@
+use std::ops::RangeInclusive;
+
+use crate::confusion::ConfusionScorer;
+use crate::ensemble::Ensemble;
+use crate::metrics::cosine::CosineScorer;
+use crate::numeric::NumericScorer;
+use crate::{Algorithm, Scorer};
+
+/// # Trait: AlgorithmInfo
+/// A description of a [Scorer](trait.Scorer.html), for tools that list or pick algorithms at runtime,
+/// like a command line, a benchmark harness or a config loader.
+///
+/// * `name`: A short name in kebab case. For an [Algorithm](enum.Algorithm.html), it is the name it [parses](enum.Algorithm.html#impl-FromStr-for-Algorithm) from.
+/// * `is_metric`: Whether the [distance](trait.Scorer.html#method.distance) is a true metric: 0 only for equal strings,
+///   symmetric, and within the triangle inequality, so it can be used by metric indexes like BK-trees.
+/// * `supports_weights`: Whether some of its units or edits can cost more than others,
+///   like the n-grams of a [CosineScorer](metrics/cosine/struct.CosineScorer.html) with TF-IDF weights.
+/// * `range`: The similarities it returns. By default, from 0 to 1, like every scorer of the crate.
+///
+/// Use [Algorithm::ALL](enum.Algorithm.html#associatedconstant.ALL) to go through every algorithm.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, AlgorithmInfo};
+///
+/// let metrics: Vec<&str> = Algorithm::ALL
+///     .iter()
+///     .filter(|algorithm| algorithm.is_metric())
+///     .map(|algorithm| algorithm.name())
+///     .collect();
+/// assert_eq!(metrics, ["levenshtein", "lcs", "damerau-levenshtein"]);
+///
+/// for algorithm in Algorithm::ALL {
+///     assert_eq!(algorithm.name().parse::<Algorithm>(), Ok(algorithm));
+///     assert_eq!(algorithm.range(), 0.0..=1.0);
+/// }
+/// ```
+pub trait AlgorithmInfo: Scorer {
+    /// The name of the algorithm, in kebab case.
+    fn name(&self) -> &'static str;
+
+    /// Whether the distance is a true metric.
+    fn is_metric(&self) -> bool;
+
+    /// Whether some units or edits can be weighted more than others.
+    fn supports_weights(&self) -> bool;
+
+    /// The lowest and the highest similarity. By default, from 0 to 1.
+    fn range(&self) -> RangeInclusive<f64> {
+        0.0..=1.0
+    }
+}
+
+impl AlgorithmInfo for Algorithm {
+    fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Levenshtein => "levenshtein",
+            Algorithm::Lcs => "lcs",
+            Algorithm::RatcliffObershelp => "ratcliff-obershelp",
+            Algorithm::Partial => "partial",
+            Algorithm::Osa => "osa",
+            Algorithm::DamerauLevenshtein => "damerau-levenshtein",
+            Algorithm::Subsequence => "subsequence",
+            Algorithm::Cosine => "cosine",
+            Algorithm::Jaccard => "jaccard",
+            Algorithm::Sift4 => "sift4",
+        }
+    }
+
+    /// Osa is not a metric, since the pair it swaps can't be edited again: "ca" to "abc" takes 3 edits,
+    /// but "ca" to "ac" to "abc" takes 2.
+    fn is_metric(&self) -> bool {
+        matches!(
+            self,
+            Algorithm::Levenshtein | Algorithm::Lcs | Algorithm::DamerauLevenshtein
+        )
+    }
+
+    /// Only Cosine, through the TF-IDF weights of [CosineScorer](metrics/cosine/struct.CosineScorer.html).
+    fn supports_weights(&self) -> bool {
+        matches!(self, Algorithm::Cosine)
+    }
+}
+
+impl AlgorithmInfo for CosineScorer {
+    fn name(&self) -> &'static str {
+        "cosine"
+    }
+
+    fn is_metric(&self) -> bool {
+        false
+    }
+
+    fn supports_weights(&self) -> bool {
+        true
+    }
+}
+
+/// A confusion can cost 0, like a ligature and its letters, and then different strings have a distance of 0,
+/// so the distance is not a metric.
+impl AlgorithmInfo for ConfusionScorer {
+    fn name(&self) -> &'static str {
+        "confusion"
+    }
+
+    fn is_metric(&self) -> bool {
+        false
+    }
+
+    fn supports_weights(&self) -> bool {
+        true
+    }
+}
+
+/// "file09" and "file9" have a distance of 0, so the distance is not a metric.
+impl AlgorithmInfo for NumericScorer {
+    fn name(&self) -> &'static str {
+        "numeric"
+    }
+
+    fn is_metric(&self) -> bool {
+        false
+    }
+
+    fn supports_weights(&self) -> bool {
+        true
+    }
+}
+
+/// An ensemble isn't a metric even when all of its scorers are,
+/// since rounding the mean of their distances can break the triangle inequality.
+impl<S: Scorer> AlgorithmInfo for Ensemble<S> {
+    fn name(&self) -> &'static str {
+        "ensemble"
+    }
+
+    fn is_metric(&self) -> bool {
+        false
+    }
+
+    fn supports_weights(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,215 @@
+/// # Struct: LevenshteinAutomaton
+/// An automaton that accepts the strings within `max_edits` Levenshtein edits of a word.
+///
+/// It reads a string one character at a time, so it can walk an index of words, like a trie or an FST,
+/// and leave a branch as soon as no word below it can match, instead of comparing the word to every string.
+/// [matches](#method.matches) checks a whole string, and [start](#method.start), [step](#method.step)
+/// and [is_match](#method.is_match) walk one character at a time.
+///
+/// A state is a row of the Levenshtein DP matrix, where every distance above `max_edits` is the same,
+/// so equal states can be shared, or cached to build a DFA.
+/// With the `fst` feature it is also an `fst::Automaton`, that reads the UTF-8 bytes of the keys.
+///
+/// # Example
+/// ```
+/// use correct_word::automaton::LevenshteinAutomaton;
+///
+/// let automaton = LevenshteinAutomaton::new("hello", 1);
+/// assert!(automaton.matches("helo"));
+/// assert!(!automaton.matches("help"));
+///
+/// let mut state = automaton.start();
+/// for c in "hxy".chars() {
+///     state = automaton.step(&state, c);
+/// }
+/// assert!(!automaton.is_match(&state));
+/// // "hxy" is too far from every prefix of "hello" already
+/// assert!(!automaton.can_match(&state));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevenshteinAutomaton {
+    word: Vec<char>,
+    max_edits: u16,
+}
+
+/// # Struct: AutomatonState
+/// The state of a [LevenshteinAutomaton](struct.LevenshteinAutomaton.html) after reading some characters.
+/// It is only meaningful to the automaton that made it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AutomatonState {
+    /// The distance from the characters read to every prefix of the word, at most `max_edits + 1`.
+    row: Vec<u16>,
+}
+
+impl LevenshteinAutomaton {
+    /// Creates an automaton for the strings within `max_edits` edits of the word.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - The word to match.
+    /// * `max_edits` - The largest Levenshtein distance a string can be from the word.
+    pub fn new(word: &str, max_edits: u16) -> Self {
+        LevenshteinAutomaton {
+            word: word.chars().collect(),
+            max_edits,
+        }
+    }
+
+    /// The state before reading any character.
+    pub fn start(&self) -> AutomatonState {
+        let limit = self.limit();
+        AutomatonState {
+            row: (0..=self.word.len())
+                .map(|i| i.min(limit as usize) as u16)
+                .collect(),
+        }
+    }
+
+    /// The state after reading one more character.
+    pub fn step(&self, state: &AutomatonState, c: char) -> AutomatonState {
+        let limit = self.limit();
+        let row = &state.row;
+        let mut next = Vec::with_capacity(row.len());
+        next.push(row[0].saturating_add(1).min(limit));
+        for (i, &word_char) in self.word.iter().enumerate() {
+            let substitution = row[i].saturating_add(u16::from(word_char != c));
+            let cell = substitution
+                .min(row[i + 1].saturating_add(1))
+                .min(next[i].saturating_add(1));
+            next.push(cell.min(limit));
+        }
+        AutomatonState { row: next }
+    }
+
+    /// Whether the characters read are within `max_edits` of the word.
+    pub fn is_match(&self, state: &AutomatonState) -> bool {
+        self.distance(state).is_some()
+    }
+
+    /// Whether reading more characters can still lead to a match.
+    /// Once it is false, it stays false, so a search can skip everything that starts with the characters read.
+    pub fn can_match(&self, state: &AutomatonState) -> bool {
+        state.row.iter().any(|&distance| distance <= self.max_edits)
+    }
+
+    /// The Levenshtein distance between the characters read and the word, if it is within `max_edits`.
+    pub fn distance(&self, state: &AutomatonState) -> Option<u16> {
+        let distance = state.row[self.word.len()];
+        (distance <= self.max_edits).then_some(distance)
+    }
+
+    /// Whether the string is within `max_edits` of the word.
+    /// It stops reading as soon as the string can't match anymore.
+    pub fn matches(&self, text: &str) -> bool {
+        let mut state = self.start();
+        for c in text.chars() {
+            if !self.can_match(&state) {
+                return false;
+            }
+            state = self.step(&state, c);
+        }
+        self.is_match(&state)
+    }
+
+    /// The value of every distance that is too large.
+    fn limit(&self) -> u16 {
+        self.max_edits.saturating_add(1)
+    }
+}
+
+/// # Struct: ByteState
+/// The state of a [LevenshteinAutomaton](struct.LevenshteinAutomaton.html) as an `fst::Automaton`,
+/// with the first bytes of a character that isn't complete yet.
+#[cfg(feature = "fst")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ByteState {
+    state: AutomatonState,
+    pending: [u8; 4],
+    len: u8,
+}
+
+#[cfg(feature = "fst")]
+impl fst::Automaton for LevenshteinAutomaton {
+    type State = ByteState;
+
+    fn start(&self) -> ByteState {
+        ByteState {
+            state: LevenshteinAutomaton::start(self),
+            pending: [0; 4],
+            len: 0,
+        }
+    }
+
+    fn is_match(&self, state: &ByteState) -> bool {
+        state.len == 0 && LevenshteinAutomaton::is_match(self, &state.state)
+    }
+
+    fn can_match(&self, state: &ByteState) -> bool {
+        LevenshteinAutomaton::can_match(self, &state.state)
+    }
+
+    fn accept(&self, state: &ByteState, byte: u8) -> ByteState {
+        let mut next = state.clone();
+        next.pending[next.len as usize] = byte;
+        next.len += 1;
+        let width = match next.pending[0] {
+            0x00..=0x7f => 1,
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            0xf0..=0xf7 => 4,
+            _ => 1,
+        };
+        if (next.len as usize) < width {
+            return next;
+        }
+        // Invalid UTF-8 is read as one replacement character per sequence
+        let c = std::str::from_utf8(&next.pending[..width])
+            .ok()
+            .and_then(|text| text.chars().next())
+            .unwrap_or(char::REPLACEMENT_CHARACTER);
+        ByteState {
+            state: self.step(&state.state, c),
+            pending: [0; 4],
+            len: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::levenshtein::levenshtein_distance;
+
+    #[test]
+    fn matches_agrees_with_distance() {
+        let words = [
+            "",
+            "a",
+            "ab",
+            "hello",
+            "help",
+            "caf\u{e9}",
+            "cafe",
+            "\u{e9}t\u{e9}",
+        ];
+        for word in words {
+            for text in words {
+                let distance = levenshtein_distance(word.to_string(), text.to_string());
+                for max_edits in [0, 1, 2, 3, u16::MAX] {
+                    let automaton = LevenshteinAutomaton::new(word, max_edits);
+                    assert_eq!(
+                        automaton.matches(text),
+                        distance <= max_edits,
+                        "{word:?} {text:?} {max_edits}"
+                    );
+
+                    let state = text
+                        .chars()
+                        .fold(automaton.start(), |state, c| automaton.step(&state, c));
+                    let expected = (distance <= max_edits).then_some(distance);
+                    assert_eq!(automaton.distance(&state), expected);
+                }
+            }
+        }
+    }
+}
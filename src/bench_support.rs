@@ -0,0 +1,80 @@
+use std::ops::Range;
+
+/// # Struct: Word Generator
+/// A small deterministic generator for benchmark data.
+/// The same seed always gives the same words, on every platform,
+/// so benchmark runs can be compared with each other.
+///
+/// The words are made of lowercase ASCII letters. Use [misspell](struct.WordGenerator.html#method.misspell)
+/// to get inputs that are close to, but not the same as, the words in a dictionary.
+///
+/// # Example
+/// ```
+/// use correct_word::bench_support::WordGenerator;
+///
+/// let dictionary = WordGenerator::new(7).words(1000, 4..10);
+/// assert_eq!(dictionary.len(), 1000);
+/// assert_eq!(dictionary, WordGenerator::new(7).words(1000, 4..10));
+/// ```
+pub struct WordGenerator {
+    state: u64,
+}
+
+impl WordGenerator {
+    /// Creates a generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        // xorshift gets stuck on 0, so the seed is mixed with a constant first
+        WordGenerator {
+            state: seed ^ 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    /// Generates a single word with a length in the given range.
+    pub fn word(&mut self, length: Range<usize>) -> String {
+        let length = length.start + self.below(length.end - length.start);
+        (0..length).map(|_| self.letter()).collect()
+    }
+
+    /// Generates a dictionary of `count` words with lengths in the given range.
+    /// Duplicate words are possible, especially for short lengths.
+    pub fn words(&mut self, count: usize, length: Range<usize>) -> Vec<String> {
+        (0..count).map(|_| self.word(length.clone())).collect()
+    }
+
+    /// Applies `edits` random insertions, deletions and substitutions to a word.
+    pub fn misspell(&mut self, word: &str, edits: usize) -> String {
+        let mut chars: Vec<char> = word.chars().collect();
+        for _ in 0..edits {
+            match self.below(3) {
+                0 => {
+                    let position = self.below(chars.len() + 1);
+                    chars.insert(position, self.letter());
+                }
+                1 if !chars.is_empty() => {
+                    let position = self.below(chars.len());
+                    chars.remove(position);
+                }
+                _ if !chars.is_empty() => {
+                    let position = self.below(chars.len());
+                    chars[position] = self.letter();
+                }
+                _ => chars.push(self.letter()),
+            }
+        }
+        chars.into_iter().collect()
+    }
+
+    fn letter(&mut self) -> char {
+        (b'a' + self.below(26) as u8) as char
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        if bound == 0 {
+            return 0;
+        }
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        (self.state % bound as u64) as usize
+    }
+}
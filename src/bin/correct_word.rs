@@ -0,0 +1,219 @@
+use std::env;
+use std::io;
+use std::process::ExitCode;
+
+use correct_word::io::{correct_jsonl, JsonlConfig};
+use correct_word::{Algorithm, CorrectWord, Corrector, Dictionary, Threshold, UnknownAlgorithm};
+
+const USAGE: &str = "Usage: correct_word [OPTIONS] <WORD> [DICTIONARY]
+       correct_word [OPTIONS] --jsonl [DICTIONARY]
+
+Prints the best corrections of WORD from the words in DICTIONARY, one word per line.
+Without a DICTIONARY, or with -, the words are read from stdin.
+
+With --jsonl, reads records like {\"input\": \"helo\", \"options\": [\"hello\"]} from stdin
+and prints the corrections of every record as a JSON line.
+The records without options are corrected from the words in DICTIONARY.
+
+Options:
+  -a, --algorithm <NAME>    levenshtein, lcs, ratcliff-obershelp, partial, osa,
+                            damerau-levenshtein, subsequence, cosine, jaccard
+                            or sift4
+                            [default: levenshtein]
+  -t, --threshold <VALUE>   The minimum similarity, from 0 to 1 [default: 0.5]
+  -d, --max-distance <N>    The maximum distance instead of a minimum similarity
+  -n, --top-n <N>           The number of corrections to print [default: 1]
+      --json                Print the corrections as a JSON array
+      --jsonl               Correct JSON lines records from stdin
+  -h, --help                Print this help
+
+Exits with 1 when there is no correction.";
+
+struct Args {
+    algorithm: Algorithm,
+    threshold: Threshold,
+    top_n: usize,
+    json: bool,
+    jsonl: bool,
+    word: String,
+    dictionary: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let corrector = Corrector::new(args.algorithm).threshold(args.threshold);
+
+    if args.jsonl {
+        return correct_records(corrector, &args);
+    }
+
+    let options = match read_dictionary(args.dictionary.as_deref()) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("error: could not read the dictionary: {error}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let corrections = corrector.correct_n(&args.word, &options, args.top_n);
+
+    if args.json {
+        println!("{}", to_json(&corrections));
+    } else {
+        for correction in &corrections {
+            if let Some(word) = &correction.word {
+                println!("{word}\t{:.4}", correction.confidence);
+            }
+        }
+    }
+
+    if corrections.is_empty() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn correct_records(corrector: Corrector, args: &Args) -> ExitCode {
+    let mut config = JsonlConfig::new(corrector).top_n(args.top_n);
+    if let Some(path) = &args.dictionary {
+        match read_dictionary(Some(path)) {
+            Ok(dictionary) => config = config.dictionary(dictionary),
+            Err(error) => {
+                eprintln!("error: could not read the dictionary: {error}");
+                return ExitCode::from(2);
+            }
+        }
+    }
+
+    match correct_jsonl(io::stdin().lock(), io::stdout().lock(), &config) {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut algorithm = Algorithm::Levenshtein;
+    let mut threshold = Threshold::default();
+    let mut top_n = 1;
+    let mut json = false;
+    let mut jsonl = false;
+    let mut positional = Vec::new();
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{name} needs a value"));
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-a" | "--algorithm" => {
+                algorithm = value(&arg)?
+                    .parse()
+                    .map_err(|error: UnknownAlgorithm| error.to_string())?
+            }
+            "-t" | "--threshold" => {
+                let similarity = value(&arg)?;
+                threshold = match similarity.parse::<f64>() {
+                    Ok(similarity) if (0.0..=1.0).contains(&similarity) => {
+                        Threshold::Similarity(similarity)
+                    }
+                    _ => return Err(format!("the threshold {similarity} is not between 0 and 1")),
+                };
+            }
+            "-d" | "--max-distance" => {
+                let distance = value(&arg)?;
+                threshold = Threshold::MaxDistance(
+                    distance
+                        .parse()
+                        .map_err(|_| format!("the max distance {distance} is not a number"))?,
+                );
+            }
+            "-n" | "--top-n" => {
+                let n = value(&arg)?;
+                top_n = match n.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("top-n {n} is not a positive number")),
+                };
+            }
+            "--json" => json = true,
+            "--jsonl" => jsonl = true,
+            "-" => positional.push(arg),
+            _ if arg.starts_with('-') => return Err(format!("unknown option {arg}")),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let word = if jsonl {
+        String::new()
+    } else {
+        positional.next().ok_or("missing the word to correct")?
+    };
+    let dictionary = positional.next().filter(|path| path != "-");
+    if let Some(extra) = positional.next() {
+        return Err(format!("unexpected argument {extra}"));
+    }
+
+    Ok(Some(Args {
+        algorithm,
+        threshold,
+        top_n,
+        json,
+        jsonl,
+        word,
+        dictionary,
+    }))
+}
+
+/// Reads one word per line, skipping blank lines.
+fn read_dictionary(path: Option<&str>) -> io::Result<Vec<String>> {
+    let dictionary = match path {
+        Some(path) => Dictionary::from_file(path)?,
+        None => Dictionary::from_reader(io::stdin().lock())?,
+    };
+    Ok(dictionary.words().to_vec())
+}
+
+fn to_json(corrections: &[CorrectWord]) -> String {
+    let entries: Vec<String> = corrections
+        .iter()
+        .filter_map(|correction| {
+            let word = correction.word.as_ref()?;
+            Some(format!(
+                "{{\"word\":{},\"confidence\":{}}}",
+                json_string(word),
+                correction.confidence
+            ))
+        })
+        .collect();
+    format!("[{}]", entries.join(","))
+}
+
+fn json_string(string: &str) -> String {
+    let mut json = String::from("\"");
+    for c in string.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
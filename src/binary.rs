@@ -0,0 +1,59 @@
+use std::io::{self, Read, Write};
+
+/// Marks a missing value, like the word of a node that doesn't end one.
+pub(crate) const NONE: u32 = u32::MAX;
+
+pub(crate) fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+pub(crate) fn write_header<W: Write>(writer: &mut W, magic: &[u8; 4]) -> io::Result<()> {
+    writer.write_all(magic)
+}
+
+pub(crate) fn read_header<R: Read>(reader: &mut R, magic: &[u8; 4]) -> io::Result<()> {
+    let mut header = [0; 4];
+    reader.read_exact(&mut header)?;
+    if &header != magic {
+        return Err(invalid(
+            "not a correct_word file of this kind, or of another version",
+        ));
+    }
+    Ok(())
+}
+
+pub(crate) fn write_u32<W: Write>(writer: &mut W, value: usize) -> io::Result<()> {
+    let value = u32::try_from(value).map_err(|_| invalid("too big to be saved"))?;
+    writer.write_all(&value.to_le_bytes())
+}
+
+pub(crate) fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+pub(crate) fn write_string<W: Write>(writer: &mut W, string: &str) -> io::Result<()> {
+    write_u32(writer, string.len())?;
+    writer.write_all(string.as_bytes())
+}
+
+pub(crate) fn read_string<R: Read>(reader: &mut R) -> io::Result<String> {
+    let len = read_u32(reader)?;
+    read_string_of(reader, len)
+}
+
+/// Reads a string whose length has already been read.
+pub(crate) fn read_string_of<R: Read>(reader: &mut R, len: u32) -> io::Result<String> {
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != len as usize {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    String::from_utf8(bytes).map_err(|_| invalid("a word is not valid UTF-8"))
+}
+
+/// A capacity to reserve for `count` items read from a file, without trusting a corrupt count too much.
+pub(crate) fn capacity(count: u32) -> usize {
+    (count as usize).min(1 << 16)
+}
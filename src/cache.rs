@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+
+use crate::{instrument, Algorithm, CandidateSource, CorrectWord, Corrector, Dictionary, Scorer};
+
+/// # Struct: CachedCorrector
+/// A [Corrector](struct.Corrector.html) and the words it corrects against, with a bounded cache of the latest corrections,
+/// to be shared by the request handlers of a server.
+///
+/// Real traffic asks for the same misspellings over and over, so the cache keeps the corrections of the `capacity` inputs
+/// that were asked for last, and forgets the one that was asked for the longest time ago when it is full.
+/// The cache is behind a lock that is only held to look an input up or to store its correction, never while scoring,
+/// so handlers correcting different inputs don't wait on each other.
+///
+/// Like a `Corrector` and a [Dictionary](struct.Dictionary.html), it is `Send` and `Sync` whenever the scorer and the words are,
+/// so one instance can be shared between threads with an `Arc`.
+/// The corrector and the words can't be changed once they are cached, since that would make the cached corrections wrong.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, CachedCorrector, Corrector, Dictionary};
+/// use std::sync::Arc;
+///
+/// let dictionary = Dictionary::from_iter(["hello", "help", "world"]);
+/// let cached = Arc::new(CachedCorrector::new(Corrector::new(Algorithm::Levenshtein), dictionary, 1000));
+///
+/// let handlers: Vec<_> = (0..4)
+///     .map(|_| {
+///         let cached = Arc::clone(&cached);
+///         std::thread::spawn(move || cached.correct("wrold").word)
+///     })
+///     .collect();
+/// for handler in handlers {
+///     assert_eq!(handler.join().unwrap().as_deref(), Some("world"));
+/// }
+/// assert_eq!(cached.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct CachedCorrector<S: Scorer = Algorithm, C: CandidateSource = Dictionary> {
+    corrector: Corrector<S>,
+    source: C,
+    cache: Mutex<Lru<CorrectWord>>,
+}
+
+impl<S: Scorer, C: CandidateSource> CachedCorrector<S, C> {
+    /// Creates a cached corrector that corrects against the words of the source,
+    /// and keeps the corrections of up to `capacity` inputs. A `capacity` of 0 caches nothing.
+    pub fn new(corrector: Corrector<S>, source: C, capacity: usize) -> Self {
+        CachedCorrector {
+            corrector,
+            source,
+            cache: Mutex::new(Lru::new(capacity)),
+        }
+    }
+
+    /// Corrects a word like [Corrector::correct_from](struct.Corrector.html#method.correct_from),
+    /// returning the cached correction when the input was corrected recently.
+    pub fn correct(&self, input: &str) -> CorrectWord {
+        if let Some(correction) = self.lock().get(input) {
+            instrument::debug!("cached correction");
+            return correction.clone();
+        }
+        instrument::debug!("uncached correction");
+        let correction = self.corrector.correct_from(input, &self.source);
+        self.lock().insert(input, correction.clone());
+        correction
+    }
+
+    /// The corrector the words are corrected with.
+    pub fn corrector(&self) -> &Corrector<S> {
+        &self.corrector
+    }
+
+    /// The words the input is corrected against.
+    pub fn source(&self) -> &C {
+        &self.source
+    }
+
+    /// The number of inputs whose corrections are cached.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether no correction is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of inputs whose corrections can be cached at once.
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity
+    }
+
+    /// Forgets every cached correction.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Lru<CorrectWord>> {
+        // The cache is never left half updated, so a handler that panicked doesn't make it unusable
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Marks the end of the list of entries.
+const NONE: usize = usize::MAX;
+
+/// A map that keeps at most `capacity` entries, and drops the least recently used one to make room.
+/// The entries are kept in a list from the most recently used to the least, linked by their positions in `entries`.
+#[derive(Debug)]
+struct Lru<V> {
+    capacity: usize,
+    positions: HashMap<String, usize>,
+    entries: Vec<Entry<V>>,
+    first: usize,
+    last: usize,
+}
+
+#[derive(Debug)]
+struct Entry<V> {
+    key: String,
+    value: V,
+    previous: usize,
+    next: usize,
+}
+
+impl<V> Lru<V> {
+    fn new(capacity: usize) -> Self {
+        Lru {
+            capacity,
+            positions: HashMap::new(),
+            entries: Vec::new(),
+            first: NONE,
+            last: NONE,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn clear(&mut self) {
+        self.positions.clear();
+        self.entries.clear();
+        self.first = NONE;
+        self.last = NONE;
+    }
+
+    /// The value of the key, which becomes the most recently used.
+    fn get(&mut self, key: &str) -> Option<&V> {
+        let position = *self.positions.get(key)?;
+        self.unlink(position);
+        self.push_front(position);
+        Some(&self.entries[position].value)
+    }
+
+    /// Sets the value of the key, which becomes the most recently used, dropping the least recently used key if it is full.
+    fn insert(&mut self, key: &str, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        if let Some(&position) = self.positions.get(key) {
+            self.entries[position].value = value;
+            self.unlink(position);
+            self.push_front(position);
+            return;
+        }
+
+        let position = if self.entries.len() < self.capacity {
+            self.entries.push(Entry {
+                key: key.to_string(),
+                value,
+                previous: NONE,
+                next: NONE,
+            });
+            self.entries.len() - 1
+        } else {
+            // The slot of the least recently used entry is reused for the new one
+            let position = self.last;
+            self.unlink(position);
+            let entry = &mut self.entries[position];
+            self.positions.remove(&entry.key);
+            entry.key = key.to_string();
+            entry.value = value;
+            position
+        };
+        self.positions.insert(key.to_string(), position);
+        self.push_front(position);
+    }
+
+    fn unlink(&mut self, position: usize) {
+        let Entry { previous, next, .. } = self.entries[position];
+        match previous {
+            NONE => self.first = next,
+            previous => self.entries[previous].next = next,
+        }
+        match next {
+            NONE => self.last = previous,
+            next => self.entries[next].previous = previous,
+        }
+    }
+
+    fn push_front(&mut self, position: usize) {
+        self.entries[position].previous = NONE;
+        self.entries[position].next = self.first;
+        match self.first {
+            NONE => self.last = position,
+            first => self.entries[first].previous = position,
+        }
+        self.first = position;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lru_drops_the_least_recently_used() {
+        let mut lru = Lru::new(2);
+        lru.insert("a", 1);
+        lru.insert("b", 2);
+        assert_eq!(lru.get("a"), Some(&1));
+        lru.insert("c", 3);
+        assert_eq!(lru.get("b"), None);
+        assert_eq!(lru.get("a"), Some(&1));
+        assert_eq!(lru.get("c"), Some(&3));
+
+        lru.insert("c", 4);
+        lru.insert("d", 5);
+        assert_eq!(lru.get("a"), None);
+        assert_eq!(lru.get("c"), Some(&4));
+        assert_eq!(lru.get("d"), Some(&5));
+        assert_eq!(lru.len(), 2);
+
+        let mut empty = Lru::new(0);
+        empty.insert("a", 1);
+        assert_eq!(empty.get("a"), None);
+    }
+
+    #[test]
+    fn shared_types_are_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Corrector>();
+        assert_send_sync::<Dictionary>();
+        assert_send_sync::<CachedCorrector>();
+    }
+}
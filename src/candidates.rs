@@ -0,0 +1,112 @@
+use std::borrow::Cow;
+
+#[cfg(feature = "fst")]
+use crate::FstDictionary;
+#[cfg(feature = "mmap")]
+use crate::MmapDictionary;
+use crate::{Dictionary, TrieDictionary};
+
+/// # Trait: CandidateSource
+/// Anything that holds words to correct input against, like a slice of words, a [Dictionary](struct.Dictionary.html)
+/// or an index like [TrieDictionary](struct.TrieDictionary.html).
+///
+/// A [Corrector](struct.Corrector.html) asks the source for the candidates within a Levenshtein distance of the input,
+/// and then scores only those with [correct_from](struct.Corrector.html#method.correct_from).
+/// The distance comes from [Scorer::max_levenshtein](trait.Scorer.html#method.max_levenshtein),
+/// and is `u16::MAX` when the scorer doesn't know it.
+///
+/// A source has to return every word within `max_distance`, but it may return words that are further away.
+/// A plain list can simply return all of its words, and an index can skip the ones it knows are too far.
+/// The words should come in the order they were added, because the first word wins a tie.
+/// They are borrowed from the source, or owned when it doesn't keep them as strings, like an [FstDictionary](struct.FstDictionary.html).
+///
+/// # Example
+/// ```
+/// use std::borrow::Cow;
+/// use correct_word::{Algorithm, CandidateSource, Corrector};
+///
+/// // Only words with the same first letter as the input are candidates
+/// struct ByFirstLetter(Vec<String>);
+///
+/// impl CandidateSource for ByFirstLetter {
+///     fn candidates(&self, input: &str, _max_distance: u16) -> impl Iterator<Item = Cow<'_, str>> {
+///         let first = input.chars().next();
+///         self.0.iter().filter(move |word| word.chars().next() == first).map(|word| Cow::Borrowed(word.as_str()))
+///     }
+/// }
+///
+/// let source = ByFirstLetter(vec!["pello".to_string(), "hello".to_string()]);
+/// let result = Corrector::new(Algorithm::Levenshtein).correct_from("hallo", &source);
+/// assert_eq!(result.word.as_deref(), Some("hello"));
+/// ```
+pub trait CandidateSource {
+    /// The words that might be within `max_distance` Levenshtein edits of the input.
+    fn candidates(&self, input: &str, max_distance: u16) -> impl Iterator<Item = Cow<'_, str>>;
+
+    /// Whether the source has exactly this word, so a correct input is returned without scoring anything.
+    /// By default, the candidates within a distance of 0 are searched for it. Sources with a faster lookup should use it.
+    fn contains(&self, word: &str) -> bool {
+        self.candidates(word, 0).any(|candidate| candidate == word)
+    }
+}
+
+impl<W: AsRef<str>> CandidateSource for [W] {
+    fn candidates(&self, _input: &str, _max_distance: u16) -> impl Iterator<Item = Cow<'_, str>> {
+        self.iter().map(|word| Cow::Borrowed(word.as_ref()))
+    }
+}
+
+impl<W: AsRef<str>> CandidateSource for Vec<W> {
+    fn candidates(&self, input: &str, max_distance: u16) -> impl Iterator<Item = Cow<'_, str>> {
+        self.as_slice().candidates(input, max_distance)
+    }
+}
+
+impl CandidateSource for Dictionary {
+    fn candidates(&self, input: &str, max_distance: u16) -> impl Iterator<Item = Cow<'_, str>> {
+        Dictionary::candidates(self, input, max_distance).map(Cow::Borrowed)
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        Dictionary::contains(self, word)
+    }
+}
+
+impl CandidateSource for TrieDictionary {
+    fn candidates(&self, input: &str, max_distance: u16) -> impl Iterator<Item = Cow<'_, str>> {
+        self.within_in_order(input, max_distance).map(Cow::Borrowed)
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        TrieDictionary::contains(self, word)
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl CandidateSource for MmapDictionary {
+    fn candidates(&self, input: &str, max_distance: u16) -> impl Iterator<Item = Cow<'_, str>> {
+        // Every insertion or deletion changes the length by one character
+        let length = input.chars().count();
+        self.words()
+            .filter(move |word| {
+                max_distance == u16::MAX
+                    || word.chars().count().abs_diff(length) <= max_distance as usize
+            })
+            .map(Cow::Borrowed)
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        MmapDictionary::contains(self, word)
+    }
+}
+
+#[cfg(feature = "fst")]
+impl<D: AsRef<[u8]>> CandidateSource for FstDictionary<D> {
+    fn candidates(&self, input: &str, max_distance: u16) -> impl Iterator<Item = Cow<'_, str>> {
+        self.within(input, max_distance).into_iter().map(Cow::Owned)
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        FstDictionary::contains(self, word)
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt;
+
+use crate::identifiers::IdentifierScorer;
+use crate::{Algorithm, Corrector};
+
+/// # Struct: KeySuggestion
+/// The expected key an unknown key of a config file was meant to be, returned by [suggest_key](fn.suggest_key.html)
+/// and [suggest_from_serde_error](fn.suggest_from_serde_error.html).
+///
+/// * `key`: The unknown key, as it was written.
+/// * `suggestion`: The expected key it is the closest to, or None if none is close enough.
+/// * `confidence`: The similarity between the two keys, from 0 to 1, or 0 without a suggestion.
+///
+/// It displays as a message for the user, like "unknown field `colr`, did you mean `color`?".
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeySuggestion {
+    pub key: String,
+    pub suggestion: Option<String>,
+    pub confidence: f64,
+}
+
+impl KeySuggestion {
+    /// The message for the user, like "unknown field `colr`, did you mean `color`?".
+    pub fn message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for KeySuggestion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown field `{}`", self.key)?;
+        match &self.suggestion {
+            Some(suggestion) => write!(f, ", did you mean `{suggestion}`?"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Finds the expected key an unknown key of a config file was meant to be.
+///
+/// The keys are compared with an [IdentifierScorer](../identifiers/struct.IdentifierScorer.html) over
+/// [Algorithm::Osa](../enum.Algorithm.html), so `max-size`, `maxSize` and `max_size` are the same key,
+/// and a swap of two letters is one edit. They need a similarity of 0.7.
+///
+/// # Arguments
+///
+/// * `unknown` - The key that isn't expected, like the one of a serde `unknown field` error.
+/// * `expected` - The keys that are, like the `expected` of that error.
+///
+/// # Returns
+///
+/// `KeySuggestion` - The unknown key, with the closest expected key if there is one close enough.
+///
+/// # Example
+/// ```
+/// use correct_word::config::suggest_key;
+///
+/// let expected = ["color", "max_size", "timeout"];
+/// assert_eq!(suggest_key("colr", &expected).message(), "unknown field `colr`, did you mean `color`?");
+/// assert_eq!(suggest_key("maxSize", &expected).suggestion.as_deref(), Some("max_size"));
+/// assert_eq!(suggest_key("verbose", &expected).message(), "unknown field `verbose`");
+/// ```
+pub fn suggest_key<S: AsRef<str>>(unknown: &str, expected: &[S]) -> KeySuggestion {
+    let corrector = Corrector::new(IdentifierScorer::new(Algorithm::Osa)).threshold(0.7);
+    let correction = corrector.correct(unknown, expected);
+    KeySuggestion {
+        key: unknown.to_string(),
+        confidence: if correction.word.is_some() {
+            correction.confidence
+        } else {
+            0.0
+        },
+        suggestion: correction.word,
+    }
+}
+
+/// Finds the expected key from the message of a serde `unknown field` error, like
+/// "unknown field `colr`, expected `color` or `size` at line 1 column 7",
+/// for when all there is to go on is the error, like with `serde_path_to_error` or a boxed error.
+/// The message can have anything before and after it, like the path of the field or the position in the file.
+///
+/// # Returns
+///
+/// `Option<KeySuggestion>` - The suggestion of [suggest_key](fn.suggest_key.html) for the unknown and the expected fields,
+/// or None if the message isn't an `unknown field` error.
+///
+/// # Example
+/// ```
+/// use correct_word::config::suggest_from_serde_error;
+///
+/// let error = "server: unknown field `prot`, expected one of `host`, `port`, `timeout` at line 3 column 5";
+/// let suggestion = suggest_from_serde_error(error).unwrap();
+/// assert_eq!((suggestion.key.as_str(), suggestion.suggestion.as_deref()), ("prot", Some("port")));
+///
+/// assert_eq!(suggest_from_serde_error("unknown field `a`, there are no fields").unwrap().suggestion, None);
+/// assert_eq!(suggest_from_serde_error("invalid type: string, expected u16"), None);
+/// ```
+pub fn suggest_from_serde_error(message: &str) -> Option<KeySuggestion> {
+    let (_, rest) = message.split_once("unknown field `")?;
+    let (unknown, rest) = rest.split_once('`')?;
+    let expected: Vec<&str> = match rest.split_once(", expected ") {
+        // Every other part between backticks is a field
+        Some((_, fields)) => fields.split('`').skip(1).step_by(2).collect(),
+        None => Vec::new(),
+    };
+    Some(suggest_key(unknown, &expected))
+}
+
+#[cfg(all(test, feature = "jsonl"))]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[allow(dead_code)]
+    struct Server {
+        host: String,
+        port: u16,
+        max_connections: u32,
+    }
+
+    #[test]
+    fn serde_errors_are_parsed() {
+        let error = serde_json::from_str::<Server>(r#"{"host": "a", "maxConnection": 1}"#)
+            .unwrap_err()
+            .to_string();
+        let suggestion = suggest_from_serde_error(&error).unwrap();
+        assert_eq!(suggestion.key, "maxConnection");
+        assert_eq!(suggestion.suggestion.as_deref(), Some("max_connections"));
+
+        #[derive(Debug, Deserialize)]
+        #[serde(deny_unknown_fields)]
+        #[allow(dead_code)]
+        struct Port {
+            port: u16,
+        }
+        let error = serde_json::from_str::<Port>(r#"{"prot": 1}"#)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            suggest_from_serde_error(&error)
+                .unwrap()
+                .suggestion
+                .as_deref(),
+            Some("port")
+        );
+    }
+}
@@ -0,0 +1,446 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::ops::Range;
+use std::path::Path;
+
+use crate::metrics::damerau::{osa_cost, EditCosts};
+use crate::Scorer;
+
+/// The confusions of [ConfusionScorer::ocr](struct.ConfusionScorer.html#method.ocr), with the cost of each.
+/// The closer the shapes, the cheaper the substitution.
+const OCR: &[(&str, &str, f64)] = &[
+    ("0", "O", 0.2),
+    ("0", "o", 0.3),
+    ("0", "D", 0.4),
+    ("1", "l", 0.2),
+    ("1", "I", 0.2),
+    ("l", "I", 0.2),
+    ("1", "i", 0.4),
+    ("l", "i", 0.4),
+    ("5", "S", 0.3),
+    ("8", "B", 0.3),
+    ("2", "Z", 0.4),
+    ("6", "G", 0.4),
+    ("c", "e", 0.4),
+    ("rn", "m", 0.2),
+    ("cl", "d", 0.3),
+    ("vv", "w", 0.2),
+    ("ri", "n", 0.4),
+    ("li", "h", 0.4),
+];
+
+/// The look-alikes of [ConfusionScorer::confusables](struct.ConfusionScorer.html#method.confusables)
+/// and [detect_confusables](fn.detect_confusables.html), with the string they look like and the cost of substituting one for the other.
+/// The letters of other scripts that are drawn the same as a Latin letter cost nothing,
+/// and the characters that only look close to one, like "1" and "l", cost a little.
+const CONFUSABLES: &[(&str, &str, f64)] = &[
+    // Cyrillic
+    ("а", "a", 0.0),
+    ("с", "c", 0.0),
+    ("ԁ", "d", 0.0),
+    ("е", "e", 0.0),
+    ("һ", "h", 0.0),
+    ("і", "i", 0.0),
+    ("ј", "j", 0.0),
+    ("о", "o", 0.0),
+    ("р", "p", 0.0),
+    ("ԛ", "q", 0.0),
+    ("ѕ", "s", 0.0),
+    ("ԝ", "w", 0.0),
+    ("х", "x", 0.0),
+    ("у", "y", 0.0),
+    ("А", "A", 0.0),
+    ("В", "B", 0.0),
+    ("С", "C", 0.0),
+    ("Е", "E", 0.0),
+    ("Н", "H", 0.0),
+    ("Ј", "J", 0.0),
+    ("К", "K", 0.0),
+    ("М", "M", 0.0),
+    ("О", "O", 0.0),
+    ("Р", "P", 0.0),
+    ("Ѕ", "S", 0.0),
+    ("Т", "T", 0.0),
+    ("Х", "X", 0.0),
+    ("Ү", "Y", 0.0),
+    // Greek
+    ("ο", "o", 0.0),
+    ("ϲ", "c", 0.0),
+    ("ν", "v", 0.1),
+    ("α", "a", 0.1),
+    ("ι", "i", 0.1),
+    ("Α", "A", 0.0),
+    ("Β", "B", 0.0),
+    ("Ε", "E", 0.0),
+    ("Ζ", "Z", 0.0),
+    ("Η", "H", 0.0),
+    ("Κ", "K", 0.0),
+    ("Μ", "M", 0.0),
+    ("Ν", "N", 0.0),
+    ("Ο", "O", 0.0),
+    ("Ρ", "P", 0.0),
+    ("Τ", "T", 0.0),
+    ("Υ", "Y", 0.0),
+    ("Χ", "X", 0.0),
+    // Latin
+    ("ɑ", "a", 0.0),
+    ("ɡ", "g", 0.0),
+    ("ı", "i", 0.1),
+    // Everything that looks like a lowercase L
+    ("I", "l", 0.1),
+    ("1", "l", 0.1),
+    ("|", "l", 0.1),
+    ("І", "l", 0.1),
+    ("Ι", "l", 0.1),
+    ("Ӏ", "l", 0.1),
+    ("ӏ", "l", 0.1),
+    ("0", "o", 0.1),
+    ("rn", "m", 0.1),
+    ("vv", "w", 0.1),
+    ("cl", "d", 0.1),
+];
+
+/// The rows of a QWERTY keyboard, for [ConfusionScorer::keyboard](struct.ConfusionScorer.html#method.keyboard).
+/// Each row is shifted right by about half a key from the one above, so a key touches two keys of the next row.
+const QWERTY: [&str; 4] = ["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// # Struct: ConfusionScorer
+/// A [Scorer](../trait.Scorer.html) with a weighted edit distance, where substituting strings that are often confused
+/// costs less than a whole edit, like the characters an OCR engine mixes up.
+///
+/// It is the Levenshtein distance, where inserting or deleting a character costs 1, and substituting one costs 1,
+/// unless the characters are a confusion. A confusion is a pair of short strings and the cost of substituting one for the other,
+/// in either direction. The strings can have several characters, so "rn" can be read as "m" for less than the two edits
+/// Levenshtein counts. The similarity is 1 minus the cost over the number of characters of the longer string.
+///
+/// Use [ocr](#method.ocr) for the usual OCR confusions, like "0" and "O", "1", "l" and "I", "rn" and "m", or "cl" and "d",
+/// add your own with [confusion](#method.confusion), or load a confusion matrix from a CSV with [from_csv](#method.from_csv).
+/// Use [confusables](#method.confusables) for the characters that look the same on screen, like the Cyrillic "а" and the Latin "a".
+///
+/// # Example
+/// ```
+/// use correct_word::confusion::ConfusionScorer;
+/// use correct_word::{Algorithm, Corrector, Scorer};
+///
+/// let scorer = ConfusionScorer::ocr();
+/// assert_eq!(scorer.similarity("C0RN", "CORN"), 0.95);
+/// assert!(scorer.similarity("modern", "rnodern") > Algorithm::Levenshtein.similarity("modern", "rnodern"));
+///
+/// // A scan of "close" where "cl" was read as "d"
+/// let options = ["hose", "close"];
+/// assert_eq!(Corrector::new(Algorithm::Levenshtein).correct("dose", &options).word.unwrap(), "hose");
+/// assert_eq!(Corrector::new(scorer).correct("dose", &options).word.unwrap(), "close");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfusionScorer {
+    costs: HashMap<(String, String), f64>,
+    /// The lengths in characters of the pairs of strings that have a confusion, to only look those up.
+    lengths: HashSet<(usize, usize)>,
+}
+
+impl ConfusionScorer {
+    /// Creates a scorer without confusions, which is the Levenshtein distance until some are added.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a scorer with the confusions of OCR engines: digits and letters of the same shape,
+    /// like "0" and "O", "1", "l" and "I" or "5" and "S", and pairs of letters that look like one, like "rn" and "m",
+    /// "cl" and "d" or "vv" and "w". More can be added like for any other scorer.
+    pub fn ocr() -> Self {
+        OCR.iter().fold(Self::new(), |scorer, &(a, b, cost)| {
+            scorer.confusion(a, b, cost)
+        })
+    }
+
+    /// Creates a scorer with the confusions of characters that look the same, for names that were made to look like others,
+    /// like a package or a domain name with a Cyrillic "а" instead of a Latin "a".
+    ///
+    /// The letters of Cyrillic and Greek that are drawn like a Latin letter cost nothing to substitute for it, or for each other.
+    /// The characters that only look close, like "1", "I", "|" and "l", "0" and "o", or "rn" and "m", cost 0.1.
+    /// Comparing the name to the names it could be an imitation of then tells apart a name that only looks the same,
+    /// with a similarity of 1 or close to it, from one that is just similar. See also [detect_confusables](fn.detect_confusables.html).
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::confusion::ConfusionScorer;
+    /// use correct_word::{Algorithm, Scorer};
+    ///
+    /// let scorer = ConfusionScorer::confusables();
+    /// // With a Cyrillic "а"
+    /// assert_eq!(scorer.similarity("pаypal", "paypal"), 1.0);
+    /// assert_eq!(Algorithm::Levenshtein.similarity("pаypal", "paypal"), 0.8333333333333334);
+    /// assert_eq!(scorer.similarity("paypa1", "paypal"), 0.9833333333333333);
+    /// assert_eq!(scorer.similarity("paypol", "paypal"), Algorithm::Levenshtein.similarity("paypol", "paypal"));
+    /// ```
+    pub fn confusables() -> Self {
+        let mut scorer = Self::new();
+        for (i, &(a, prototype, cost)) in CONFUSABLES.iter().enumerate() {
+            scorer = scorer.confusion(a, prototype, cost);
+            // Two look-alikes of the same string look like each other too
+            for &(b, _, other) in CONFUSABLES[..i].iter().filter(|(_, p, _)| *p == prototype) {
+                scorer = scorer.confusion(a, b, cost.max(other));
+            }
+        }
+        scorer
+    }
+
+    /// Creates a scorer where hitting a key next to the right one on a QWERTY keyboard, like "s" for "a",
+    /// costs half an edit, for the typos of people typing on one. Only the neighbors on the same row and on the rows
+    /// above and below count, for the lowercase letters and the digits.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::confusion::ConfusionScorer;
+    /// use correct_word::Scorer;
+    ///
+    /// let scorer = ConfusionScorer::keyboard();
+    /// assert_eq!(scorer.similarity("hrllo", "hello"), 0.9);
+    /// assert_eq!(scorer.similarity("hpllo", "hello"), 0.8);
+    /// ```
+    pub fn keyboard() -> Self {
+        let mut scorer = Self::new();
+        for (row, keys) in QWERTY.iter().enumerate() {
+            let keys: Vec<char> = keys.chars().collect();
+            let below: Vec<char> = QWERTY
+                .get(row + 1)
+                .map_or(Vec::new(), |keys| keys.chars().collect());
+            for (i, &key) in keys.iter().enumerate() {
+                let neighbors = keys
+                    .get(i + 1)
+                    .into_iter()
+                    .chain(below.get(i.wrapping_sub(1)))
+                    .chain(below.get(i));
+                for &neighbor in neighbors {
+                    scorer = scorer.confusion(&key.to_string(), &neighbor.to_string(), 0.5);
+                }
+            }
+        }
+        scorer
+    }
+
+    /// Makes substituting `a` for `b`, or `b` for `a`, cost `cost` instead of an edit per character.
+    /// A cost below 0 is 0, and an empty string or a pair of equal strings is ignored.
+    pub fn confusion(mut self, a: &str, b: &str, cost: f64) -> Self {
+        if a.is_empty() || b.is_empty() || a == b {
+            return self;
+        }
+        let cost = cost.max(0.0);
+        let (length1, length2) = (a.chars().count(), b.chars().count());
+        self.lengths.insert((length1, length2));
+        self.lengths.insert((length2, length1));
+        self.costs.insert((a.to_string(), b.to_string()), cost);
+        self.costs.insert((b.to_string(), a.to_string()), cost);
+        self
+    }
+
+    /// Adds the confusions of another scorer to the ones this one has, like the [confusables](#method.confusables)
+    /// to the [keyboard](#method.keyboard) typos. A confusion of the other scorer replaces the cost this one had for it.
+    pub fn merge(mut self, other: ConfusionScorer) -> Self {
+        self.costs.extend(other.costs);
+        self.lengths.extend(other.lengths);
+        self
+    }
+
+    /// Loads a confusion matrix from a CSV, with an `a,b,cost` row for each confusion, like the output of an OCR error analysis.
+    ///
+    /// Blank lines and lines starting with `#` are skipped, and so is a first line whose cost isn't a number, like a header.
+    /// The fields are trimmed, and can't have commas in them.
+    /// A row that isn't a confusion, or whose cost isn't a number, gives an `InvalidData` error naming the line.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::confusion::ConfusionScorer;
+    /// use correct_word::Scorer;
+    ///
+    /// let csv = "from,to,cost\n0,O,0.1\n# Ligatures\nﬁ,fi,0\n";
+    /// let scorer = ConfusionScorer::from_csv(csv.as_bytes()).unwrap();
+    /// assert_eq!(scorer.similarity("0wl", "Owl"), 0.9666666666666667);
+    /// assert_eq!(scorer.similarity("ﬁsh", "fish"), 1.0);
+    ///
+    /// assert!(ConfusionScorer::from_csv("0,O\n".as_bytes()).is_err());
+    /// ```
+    pub fn from_csv<R: BufRead>(reader: R) -> io::Result<Self> {
+        Self::new().with_csv(reader)
+    }
+
+    /// Loads a confusion matrix from a CSV file, like [from_csv](#method.from_csv).
+    pub fn from_csv_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_csv(BufReader::new(File::open(path)?))
+    }
+
+    /// Adds the confusions of a CSV to the ones the scorer has, like [from_csv](#method.from_csv),
+    /// to extend the [ocr](#method.ocr) preset for an engine. A confusion of the CSV replaces the cost the scorer had for it.
+    pub fn with_csv<R: BufRead>(mut self, reader: R) -> io::Result<Self> {
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = |message: &str| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: {message}", number + 1),
+                )
+            };
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let [a, b, cost] = fields[..] else {
+                return Err(invalid("expected a row like a,b,cost"));
+            };
+            match cost.parse::<f64>() {
+                Ok(cost) if cost.is_finite() => self = self.confusion(a, b, cost),
+                _ if number == 0 => continue,
+                _ => return Err(invalid("the cost is not a number")),
+            }
+        }
+        Ok(self)
+    }
+
+    /// The cheapest edits between the strings, and the length of the longer one.
+    fn cost(&self, a: &str, b: &str) -> (f64, usize) {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        let costs = Confusions {
+            scorer: self,
+            longest: self
+                .lengths
+                .iter()
+                .map(|&(length, _)| length)
+                .max()
+                .unwrap_or(1),
+            key: RefCell::default(),
+        };
+        (osa_cost(&a, &b, &costs), a.len().max(b.len()))
+    }
+
+    /// The cost of substituting `a` for `b`, if it is a confusion.
+    fn confusion_cost(&self, key: &mut (String, String), a: &[char], b: &[char]) -> Option<f64> {
+        if !self.lengths.contains(&(a.len(), b.len())) {
+            return None;
+        }
+        key.0.clear();
+        key.0.extend(a);
+        key.1.clear();
+        key.1.extend(b);
+        self.costs.get(key).copied()
+    }
+}
+
+/// The edit costs of a [ConfusionScorer](struct.ConfusionScorer.html), with a buffer to look the confusions up without allocating.
+struct Confusions<'a> {
+    scorer: &'a ConfusionScorer,
+    longest: usize,
+    key: RefCell<(String, String)>,
+}
+
+impl EditCosts<char> for Confusions<'_> {
+    fn substitution(&self, a: &char, b: &char) -> f64 {
+        let key = &mut self.key.borrow_mut();
+        self.scorer.confusion_cost(key, &[*a], &[*b]).unwrap_or(1.0)
+    }
+
+    /// A swap is two substitutions, like in the Levenshtein distance.
+    fn transposition(&self, _: &char, _: &char) -> f64 {
+        f64::INFINITY
+    }
+
+    fn longest_confusion(&self) -> usize {
+        self.longest
+    }
+
+    fn confusion(&self, a: &[char], b: &[char]) -> Option<f64> {
+        let key = &mut self.key.borrow_mut();
+        self.scorer.confusion_cost(key, a, b)
+    }
+}
+
+impl Scorer for ConfusionScorer {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        match self.cost(a, b) {
+            (_, 0) => 1.0,
+            (cost, length) => (1.0 - cost / length as f64).max(0.0),
+        }
+    }
+
+    /// The cost of the edits, rounded up, so a confusion is one edit.
+    fn distance(&self, a: &str, b: &str) -> u16 {
+        let (cost, _) = self.cost(a, b);
+        cost.ceil().min(u16::MAX as f64) as u16
+    }
+}
+
+/// # Struct: Confusable
+/// A part of two strings that look alike, found by [detect_confusables](fn.detect_confusables.html),
+/// as the byte ranges it is at in each of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Confusable {
+    pub a: Range<usize>,
+    pub b: Range<usize>,
+}
+
+/// Finds where two strings differ, if every difference is a pair of characters that look alike,
+/// with the look-alikes of [ConfusionScorer::confusables](struct.ConfusionScorer.html#method.confusables).
+///
+/// # Arguments
+/// * `a` - A string, like the name to check
+/// * `b` - The string it might imitate
+///
+/// # Returns
+/// `Option<Vec<Confusable>>` - The parts of the strings that differ but look alike, in order,
+/// or None if the strings don't look the same. Equal strings look the same, with no differences.
+///
+/// # Example
+/// ```
+/// use correct_word::confusion::detect_confusables;
+///
+/// // A Cyrillic "а", and a "1" for an "l"
+/// let differences = detect_confusables("pаypa1", "paypal").unwrap();
+/// assert_eq!(differences.len(), 2);
+/// assert_eq!(&"pаypa1"[differences[0].a.clone()], "а");
+/// assert_eq!(&"paypal"[differences[1].b.clone()], "l");
+///
+/// assert_eq!(detect_confusables("rnicrosoft", "microsoft").unwrap().len(), 1);
+/// assert_eq!(detect_confusables("paypal", "paypal"), Some(Vec::new()));
+/// assert_eq!(detect_confusables("paypol", "paypal"), None);
+/// ```
+pub fn detect_confusables(a: &str, b: &str) -> Option<Vec<Confusable>> {
+    let (units_a, units_b) = (skeleton(a), skeleton(b));
+    if units_a.len() != units_b.len() {
+        return None;
+    }
+    let mut differences = Vec::new();
+    for ((span_a, prototype_a), (span_b, prototype_b)) in units_a.into_iter().zip(units_b) {
+        if prototype_a != prototype_b {
+            return None;
+        }
+        if a[span_a.clone()] != b[span_b.clone()] {
+            differences.push(Confusable {
+                a: span_a,
+                b: span_b,
+            });
+        }
+    }
+    Some(differences)
+}
+
+/// Splits a string into its look-alikes and its other characters, with the string each of them looks like.
+fn skeleton(text: &str) -> Vec<(Range<usize>, &str)> {
+    let mut units = Vec::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let start = text.len() - rest.len();
+        // The longest look-alike first, so "rn" is one "m" rather than an "r" and an "n"
+        let (length, prototype) = CONFUSABLES
+            .iter()
+            .filter(|(confusable, ..)| rest.starts_with(confusable))
+            .max_by_key(|(confusable, ..)| confusable.len())
+            .map(|&(confusable, prototype, _)| (confusable.len(), prototype))
+            .unwrap_or((c.len_utf8(), &rest[..c.len_utf8()]));
+        units.push((start..start + length, prototype));
+        rest = &rest[length..];
+    }
+    units
+}
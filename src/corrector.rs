@@ -0,0 +1,1562 @@
+use crate::instrument;
+use crate::metrics::levenshtein::{levenshtein_ops, EditOp};
+use crate::normalizer::{match_case, strip_accents};
+use crate::short_words::Acceptance;
+use crate::{
+    Algorithm, Cancelled, CandidateSource, CorrectBytes, CorrectOsStr, CorrectPayload,
+    CorrectPayloads, CorrectWord, CorrectWordRef, CorrectionError, Granularity, Match, Normalizer,
+    Query, Scorer, ScoringTrace, ShortWords, Threshold, TieBreak, TraceEntry, TraceOutcome,
+};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::sync::atomic::{self, AtomicBool};
+
+/// # Struct: Corrector
+/// A reusable configuration for correcting words.
+/// It holds the algorithm, or any other [Scorer](trait.Scorer.html), and the threshold, so they don't have to be passed to every call,
+/// and it is the place for options that don't fit in the arguments of [correct_word](fn.correct_word.html).
+///
+/// The options can be any slice of strings, like `&[&str]` or `&[String]`.
+/// By default, the threshold is a similarity of 0.5, the same as [correct_word](fn.correct_word.html).
+///
+/// A corrector is `Send` and `Sync` whenever its scorer is, like the built-in algorithms, so it can be shared between threads.
+/// To also cache the corrections of repeated inputs, use a [CachedCorrector](struct.CachedCorrector.html).
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector};
+///
+/// let corrector = Corrector::new(Algorithm::Levenshtein).threshold(0.4);
+/// let result = corrector.correct("hilo", &["hello", "world"]);
+/// assert_eq!(result.word.unwrap(), "hello");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Corrector<S: Scorer = Algorithm> {
+    scorer: S,
+    threshold: Threshold,
+    granularity: Granularity,
+    edit_ops: bool,
+    tie_break: TieBreak,
+    prefix_bonus: Option<(usize, f64)>,
+    max_length_difference: Option<usize>,
+    normalizer: Normalizer,
+    short_words: ShortWords,
+    joined_words: bool,
+    accent_preference: Option<f64>,
+    dedup: bool,
+    preserve_case: bool,
+}
+
+impl<S: Scorer> Corrector<S> {
+    /// The number of options [correct_blocking_off](#method.correct_blocking_off) scores before yielding to the runtime.
+    #[cfg(feature = "tokio")]
+    pub const YIELD_EVERY: usize = 1024;
+
+    /// Creates a corrector using the given algorithm, or scorer, and the default threshold.
+    pub fn new(scorer: S) -> Self {
+        Corrector {
+            scorer,
+            threshold: Threshold::default(),
+            granularity: Granularity::default(),
+            edit_ops: false,
+            tie_break: TieBreak::default(),
+            prefix_bonus: None,
+            max_length_difference: None,
+            normalizer: Normalizer::new(),
+            short_words: ShortWords::default(),
+            joined_words: false,
+            accent_preference: None,
+            dedup: false,
+            preserve_case: false,
+        }
+    }
+
+    /// Sets the threshold an option has to clear to be returned. A plain `f64` is a minimum similarity.
+    pub fn threshold(mut self, threshold: impl Into<Threshold>) -> Self {
+        self.threshold = threshold.into();
+        self
+    }
+
+    /// Sets what counts as a single unit when comparing the input to an option. By default, every character is a unit.
+    /// A [Threshold::MaxDistance](enum.Threshold.html) counts the same units, so with words it is the number of words that differ.
+    pub fn granularity(mut self, granularity: Granularity) -> Self {
+        self.granularity = granularity;
+        self
+    }
+
+    /// Sets whether the corrections include the edits that turn the input into the corrected word.
+    /// They are calculated with [levenshtein_ops](metrics/levenshtein/fn.levenshtein_ops.html), whatever the algorithm.
+    /// By default, they are not included.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::metrics::levenshtein::EditOp;
+    /// use correct_word::{Algorithm, Corrector};
+    ///
+    /// let corrector = Corrector::new(Algorithm::Levenshtein).edit_ops(true);
+    /// let result = corrector.correct("helo", &["hello", "world"]);
+    /// assert_eq!(result.ops.unwrap(), vec![EditOp::Insert { source: 2, target: 2, char: 'l' }]);
+    /// ```
+    pub fn edit_ops(mut self, enabled: bool) -> Self {
+        self.edit_ops = enabled;
+        self
+    }
+
+    /// Sets which of several options with the same confidence comes first. By default, the first of the options.
+    pub fn tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+
+    /// Rewards options that start with the same characters as the input, like the Winkler bonus of Jaro-Winkler,
+    /// whatever the algorithm.
+    ///
+    /// With `l` the number of leading characters both share, up to `len`, the similarity becomes
+    /// `similarity + l * weight * (1 - similarity)`. So a shared prefix closes part of the gap to a perfect score,
+    /// and the similarity is never more than 1. Winkler used a `len` of 4 and a `weight` of 0.1.
+    /// The bonus is added before the threshold is checked.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector};
+    ///
+    /// let options = ["start", "state"];
+    /// let corrector = Corrector::new(Algorithm::Levenshtein);
+    /// assert_eq!(corrector.correct("stat", &options).word.unwrap(), "start");
+    ///
+    /// let corrector = corrector.prefix_bonus(4, 0.1);
+    /// assert_eq!(corrector.correct("stat", &options).word.unwrap(), "state");
+    /// ```
+    pub fn prefix_bonus(mut self, len: usize, weight: f64) -> Self {
+        self.prefix_bonus = Some((len, weight));
+        self
+    }
+
+    /// Skips the options whose length differs from the input by more than `k` characters, without scoring them.
+    ///
+    /// Every insertion or deletion changes the length by one, so an option can't be closer than the difference of the lengths.
+    /// When the [Scorer](trait.Scorer.html) knows the largest distance that can clear the threshold,
+    /// like the Levenshtein algorithms do, the options further away than that are always skipped,
+    /// and this only makes the filter tighter. A tighter filter is faster, but misses corrections that add or drop more characters.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector};
+    ///
+    /// let options = ["accommodation", "accord"];
+    /// let corrector = Corrector::new(Algorithm::Lcs).threshold(0.4);
+    /// assert_eq!(corrector.correct("acomodation", &options).word.unwrap(), "accommodation");
+    ///
+    /// let corrector = corrector.max_length_difference(1);
+    /// assert_eq!(corrector.correct("acomodation", &options).word, None);
+    /// ```
+    pub fn max_length_difference(mut self, k: usize) -> Self {
+        self.max_length_difference = Some(k);
+        self
+    }
+
+    /// Sets the [Normalizer](struct.Normalizer.html) the input and every option go through before they are scored,
+    /// like one that lowercases them and strips punctuation. The corrections are still the options as they were written.
+    /// By default, nothing is normalized.
+    ///
+    /// The prefix bonus and the length filter look at the normalized strings.
+    /// Indexes like [TrieDictionary](struct.TrieDictionary.html) hold the words as they were written,
+    /// so with a normalizer they can't skip any of them in [correct_from](#method.correct_from).
+    /// [correct_bytes](#method.correct_bytes) doesn't normalize anything.
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Matches the input and the options without their accents, so "Jose" and "José" are equal,
+    /// but still prefers the options written with the same accents as the input.
+    ///
+    /// Whether an option clears the threshold only depends on its similarity without accents.
+    /// Its confidence is that similarity, lowered by `preference` times what the accents cost:
+    /// the similarity without accents minus the similarity with them. So "José" wins over "Jose" for the input "José",
+    /// and "Jose" wins for "Jose", while both clear the threshold. The accents are the ones the
+    /// [strip_accents](struct.Normalizer.html#method.strip_accents) step of a normalizer removes,
+    /// and they are stripped after the [normalizer](#method.normalizer).
+    ///
+    /// Indexes like [TrieDictionary](struct.TrieDictionary.html) hold the words with their accents,
+    /// so they can't skip any of them in [correct_from](#method.correct_from).
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector};
+    ///
+    /// let names = ["Jose", "José", "Josefa"];
+    /// let corrector = Corrector::new(Algorithm::Levenshtein).ignore_accents(0.1);
+    /// assert_eq!(corrector.correct("José", &names).word.unwrap(), "José");
+    /// assert_eq!(corrector.correct("Jose", &names).word.unwrap(), "Jose");
+    ///
+    /// let result = corrector.correct("Josè", &names[1..]);
+    /// assert_eq!(result.word.unwrap(), "José");
+    /// assert_eq!(result.confidence, 0.975);
+    /// ```
+    pub fn ignore_accents(mut self, preference: f64) -> Self {
+        self.accent_preference = Some(preference.max(0.0));
+        self
+    }
+
+    /// Sets how strict the corrector is with short inputs, which otherwise match almost anything.
+    /// See [ShortWords](enum.ShortWords.html) for the policies. By default, short inputs have the same threshold as the others.
+    pub fn short_words(mut self, policy: ShortWords) -> Self {
+        self.short_words = policy;
+        self
+    }
+
+    /// Also suggests splitting an input that two words of the source were joined into, like "helloworld" into "hello world",
+    /// in [correct_from](#method.correct_from) and [correct_n_from](#method.correct_n_from). Disabled by default.
+    ///
+    /// A missing space is one of the most common typos, but every single word is far from the two joined together.
+    /// So when the source doesn't contain the input, every split of the input into two words the source contains
+    /// is added to the candidates, and scored against the input like any other of them:
+    /// with the Levenshtein algorithm, "hello world" is a single insertion away from "helloworld".
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector, Dictionary};
+    ///
+    /// let dictionary = Dictionary::from_iter(["hello", "help", "world"]);
+    /// let corrector = Corrector::new(Algorithm::Levenshtein);
+    /// assert_eq!(corrector.correct_from("helloworld", &dictionary).word.as_deref(), Some("hello"));
+    ///
+    /// let corrector = corrector.joined_words(true);
+    /// assert_eq!(corrector.correct_from("helloworld", &dictionary).word.as_deref(), Some("hello world"));
+    /// assert_eq!(corrector.correct_from("helo", &dictionary).word.as_deref(), Some("hello"));
+    /// ```
+    pub fn joined_words(mut self, enabled: bool) -> Self {
+        self.joined_words = enabled;
+        self
+    }
+
+    /// Scores each distinct option once, skipping the ones equal to an option before them. Disabled by default.
+    ///
+    /// Word lists merged from several sources often have the same word more than once.
+    /// Without deduplication, each copy is scored, and [correct_n](#method.correct_n) can return the same word several times.
+    /// With it, only the first copy is scored and returned, and a [trace](#method.trace) marks the others as duplicates.
+    /// To get every payload of a word that comes with several, use [correct_payloads](#method.correct_payloads).
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector};
+    ///
+    /// let options = ["hello", "help", "hello"];
+    /// let corrector = Corrector::new(Algorithm::Levenshtein).threshold(0.5);
+    /// assert_eq!(corrector.correct_n("helo", &options, 3).len(), 3);
+    ///
+    /// let corrector = corrector.dedup(true);
+    /// let words: Vec<String> = corrector
+    ///     .correct_n("helo", &options, 3)
+    ///     .into_iter()
+    ///     .filter_map(|correction| correction.word)
+    ///     .collect();
+    /// assert_eq!(words, ["hello", "help"]);
+    /// ```
+    pub fn dedup(mut self, enabled: bool) -> Self {
+        self.dedup = enabled;
+        self
+    }
+
+    /// Writes the corrections in the case of the input, when the input is all uppercase or capitalized. Disabled by default.
+    ///
+    /// With a [normalizer](#method.normalizer) that lowercases, "Helo" at the start of a sentence is corrected to the option "hello",
+    /// which reads oddly. With this, it is corrected to "Hello", and "HELO" to "HELLO".
+    /// A lowercase or mixed case input, like "helo" or "iPhon", keeps the case of the option, so "pari" is still corrected to "Paris".
+    /// The [edit ops](#method.edit_ops) are those of the correction as it is returned.
+    ///
+    /// It only changes the corrections that are copies of the options, not the ones that borrow from them, like [correct_ref](#method.correct_ref).
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector, Normalizer};
+    ///
+    /// let options = ["hello", "help", "Paris"];
+    /// let corrector = Corrector::new(Algorithm::Levenshtein)
+    ///     .normalizer(Normalizer::new().lowercase())
+    ///     .preserve_case(true);
+    /// assert_eq!(corrector.correct("Helo", &options).word.as_deref(), Some("Hello"));
+    /// assert_eq!(corrector.correct("HELO", &options).word.as_deref(), Some("HELLO"));
+    /// assert_eq!(corrector.correct("pari", &options).word.as_deref(), Some("Paris"));
+    /// ```
+    pub fn preserve_case(mut self, enabled: bool) -> Self {
+        self.preserve_case = enabled;
+        self
+    }
+
+    /// Corrects a word, returning the best option that clears the threshold.
+    /// When several options are equally good, the [tie break](#method.tie_break) decides, and by default the first one wins.
+    ///
+    /// If no option clears the threshold, the word is None and the confidence is the similarity of the best option,
+    /// of the ones that were scored in full: not skipped by the [length filter](#method.max_length_difference),
+    /// nor found by the scorer to be further away than a correction can be, like the banded DP of Levenshtein does.
+    ///
+    /// An input that is already one of the options is returned with a confidence of 1, without scoring any option,
+    /// since nothing is closer to a word than the word itself. Most inputs are usually correct, so this skips most scans.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector};
+    ///
+    /// // Every option contains "hel", so they are all perfect partial matches
+    /// let corrector = Corrector::new(Algorithm::Partial);
+    /// let result = corrector.correct("hel", &["hello", "help", "hel"]);
+    /// assert_eq!(result.word.as_deref(), Some("hel"));
+    /// assert_eq!(result.confidence, 1.0);
+    /// ```
+    pub fn correct<O: AsRef<str>>(&self, input: &str, options: &[O]) -> CorrectWord {
+        self.owned(input, self.correct_ref(input, options))
+    }
+
+    /// Corrects a word like [correct](#method.correct), with a word that borrows from the options instead of copying it.
+    pub fn correct_ref<'a, O: AsRef<str>>(
+        &self,
+        input: &str,
+        options: &'a [O],
+    ) -> CorrectWordRef<'a> {
+        match self.correction(input, options) {
+            (Some((index, confidence)), _) => {
+                let word = options[index].as_ref();
+                CorrectWordRef {
+                    word: Some(word),
+                    confidence,
+                    ops: self.ops(input, word),
+                }
+            }
+            (None, best_confidence) => CorrectWordRef {
+                word: None,
+                confidence: best_confidence,
+                ops: None,
+            },
+        }
+    }
+
+    /// Corrects a word, returning up to `n` of the options that clear the threshold, best first.
+    /// Options with the same confidence are ordered by the [tie break](#method.tie_break), and by default keep the order they had in `options`.
+    /// An option equal to the input always comes first.
+    pub fn correct_n<O: AsRef<str>>(
+        &self,
+        input: &str,
+        options: &[O],
+        n: usize,
+    ) -> Vec<CorrectWord> {
+        self.correct_n_ref(input, options, n)
+            .into_iter()
+            .map(|correction| self.owned(input, correction))
+            .collect()
+    }
+
+    /// Corrects a word like [correct_n](#method.correct_n), with words that borrow from the options instead of copying them.
+    pub fn correct_n_ref<'a, O: AsRef<str>>(
+        &self,
+        input: &str,
+        options: &'a [O],
+        n: usize,
+    ) -> Vec<CorrectWordRef<'a>> {
+        self.ranked(input, options, n)
+            .into_iter()
+            .map(|(_, word, confidence)| CorrectWordRef {
+                word: Some(word),
+                confidence,
+                ops: self.ops(input, word),
+            })
+            .collect()
+    }
+
+    /// Corrects a word like [correct_ref](#method.correct_ref), with options that are `(word, payload)` pairs,
+    /// returning the payload of the corrected option along with it.
+    /// Use it to get straight to the handler of a command, or the record of a name, without looking the word up again.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector};
+    ///
+    /// let handlers: [(&str, fn() -> &'static str); 2] = [("start", || "starting"), ("stop", || "stopping")];
+    /// let result = Corrector::new(Algorithm::Levenshtein).correct_payload("strat", &handlers);
+    /// assert_eq!((result.payload.unwrap())(), "starting");
+    /// ```
+    pub fn correct_payload<'a, K: AsRef<str>, T>(
+        &self,
+        input: &str,
+        options: &'a [(K, T)],
+    ) -> CorrectPayload<'a, T> {
+        let words: Vec<&str> = options.iter().map(|(word, _)| word.as_ref()).collect();
+        match self.correction(input, &words) {
+            (Some((index, confidence)), _) => CorrectPayload {
+                word: Some(words[index]),
+                payload: Some(&options[index].1),
+                confidence,
+                ops: self.ops(input, words[index]),
+            },
+            (None, best_confidence) => CorrectPayload {
+                word: None,
+                payload: None,
+                confidence: best_confidence,
+                ops: None,
+            },
+        }
+    }
+
+    /// Corrects a word like [correct_payload](#method.correct_payload), returning every payload of the corrected word,
+    /// for options where the same word comes with several payloads, like an alias shared by two commands.
+    /// The payloads are in the order of the options. The corrected word is the same with or without [dedup](#method.dedup).
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector};
+    ///
+    /// let packages = [("serde", "crates.io"), ("serde", "vendored"), ("rand", "crates.io")];
+    /// let result = Corrector::new(Algorithm::Levenshtein).correct_payloads("sedre", &packages);
+    /// assert_eq!(result.word, Some("serde"));
+    /// assert_eq!(result.payloads, [&"crates.io", &"vendored"]);
+    /// ```
+    pub fn correct_payloads<'a, K: AsRef<str>, T>(
+        &self,
+        input: &str,
+        options: &'a [(K, T)],
+    ) -> CorrectPayloads<'a, T> {
+        let words: Vec<&str> = options.iter().map(|(word, _)| word.as_ref()).collect();
+        match self.correction(input, &words) {
+            (Some((index, confidence)), _) => CorrectPayloads {
+                word: Some(words[index]),
+                payloads: options
+                    .iter()
+                    .filter(|(word, _)| word.as_ref() == words[index])
+                    .map(|(_, payload)| payload)
+                    .collect(),
+                confidence,
+                ops: self.ops(input, words[index]),
+            },
+            (None, best_confidence) => CorrectPayloads {
+                word: None,
+                payloads: Vec::new(),
+                confidence: best_confidence,
+                ops: None,
+            },
+        }
+    }
+
+    /// Corrects a word like [correct_n_ref](#method.correct_n_ref), with options that are `(word, payload)` pairs,
+    /// returning the payloads of the corrected options along with them.
+    pub fn correct_n_payload<'a, K: AsRef<str>, T>(
+        &self,
+        input: &str,
+        options: &'a [(K, T)],
+        n: usize,
+    ) -> Vec<CorrectPayload<'a, T>> {
+        let words: Vec<&str> = options.iter().map(|(word, _)| word.as_ref()).collect();
+        self.ranked(input, &words, n)
+            .into_iter()
+            .map(|(index, _, confidence)| {
+                let (word, payload) = &options[index];
+                CorrectPayload {
+                    word: Some(word.as_ref()),
+                    payload: Some(payload),
+                    confidence,
+                    ops: self.ops(input, word.as_ref()),
+                }
+            })
+            .collect()
+    }
+
+    /// Corrects a word like [correct_n](#method.correct_n), returning up to `n` of the options that clear the threshold
+    /// as [Match](struct.Match.html)es, with their index in the options, their rank, and the distance and the similarity
+    /// of the scorer besides the confidence.
+    pub fn matches<'a, O: AsRef<str>>(
+        &self,
+        input: &str,
+        options: &'a [O],
+        n: usize,
+    ) -> Vec<Match<'a>> {
+        let normalized = self.normalizer.normalize(input);
+        self.ranked(input, options, n)
+            .into_iter()
+            .enumerate()
+            .map(|(position, (index, word, confidence))| {
+                let option = self.normalizer.normalize(word);
+                Match {
+                    word,
+                    index,
+                    rank: position + 1,
+                    distance: self
+                        .scorer
+                        .distance_with(&normalized, &option, self.granularity),
+                    similarity: self
+                        .scorer
+                        .similarity_with(&normalized, &option, self.granularity),
+                    confidence,
+                }
+            })
+            .collect()
+    }
+
+    /// Corrects a word, returning every option that clears the threshold, best first, like [correct_n](#method.correct_n) without a cap.
+    /// Use it when the downstream code decides between the matches itself, like in record linkage.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector};
+    ///
+    /// let options = ["Jon Smith", "John Smyth", "Jane Doe", "John Smith"];
+    /// let corrector = Corrector::new(Algorithm::Levenshtein).threshold(0.8);
+    /// let matches: Vec<String> = corrector
+    ///     .all_matches("John Smith", &options)
+    ///     .into_iter()
+    ///     .filter_map(|correction| correction.word)
+    ///     .collect();
+    /// assert_eq!(matches, vec!["John Smith", "Jon Smith", "John Smyth"]);
+    /// ```
+    pub fn all_matches<O: AsRef<str>>(&self, input: &str, options: &[O]) -> Vec<CorrectWord> {
+        self.correct_n(input, options, usize::MAX)
+    }
+
+    /// Scores the options lazily, yielding the index, the option and the confidence of every option that clears the threshold,
+    /// in the order of `options`.
+    /// This is what [correct](#method.correct) and [correct_n](#method.correct_n) are built on,
+    /// for when you want to filter, sort or stop early yourself.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector};
+    ///
+    /// let options = ["hello", "world", "help", "helm"];
+    /// let corrector = Corrector::new(Algorithm::Levenshtein);
+    /// // The first option that is only one edit away
+    /// let first = corrector.score_iter("helo", &options).find(|&(_, _, confidence)| confidence >= 0.75);
+    /// assert_eq!(first, Some((0, "hello", 0.8)));
+    /// ```
+    pub fn score_iter<'a, 'b, O: AsRef<str>>(
+        &'b self,
+        input: &'b str,
+        options: &'a [O],
+    ) -> impl Iterator<Item = (usize, &'a str, f64)> + 'b
+    where
+        'a: 'b,
+    {
+        self.scored(input, options)
+            .filter(|&(.., accepted)| accepted)
+            .map(|(index, option, confidence, _)| (index, option, confidence))
+    }
+
+    /// Corrects a word like [correct](#method.correct), yielding to the tokio runtime between chunks of options,
+    /// so scanning hundreds of thousands of them doesn't keep other tasks on the same thread from running.
+    /// The options are scored in chunks of [YIELD_EVERY](#associatedconstant.YIELD_EVERY).
+    ///
+    /// This still scores the options on the runtime's thread. For scans too slow even then,
+    /// move the corrector and the options into `tokio::task::spawn_blocking` instead.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector};
+    ///
+    /// let options: Vec<String> = (0..5000).map(|i| format!("word{i}")).collect();
+    /// let corrector = Corrector::new(Algorithm::Levenshtein);
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// let result = runtime.block_on(corrector.correct_blocking_off("wrod4321", &options));
+    /// assert_eq!(result, corrector.correct("wrod4321", &options));
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub async fn correct_blocking_off<O: AsRef<str>>(
+        &self,
+        input: &str,
+        options: &[O],
+    ) -> CorrectWord {
+        if let Some(index) = self.exact(input, options) {
+            return self.owned(input, self.exact_match(options[index].as_ref()));
+        }
+        let mut best: Option<(&str, f64)> = None;
+        let mut best_confidence: f64 = 0.0;
+        for chunk in options.chunks(Self::YIELD_EVERY) {
+            let (chunk_best, chunk_confidence) = self.best(input, chunk);
+            best_confidence = best_confidence.max(chunk_confidence);
+            if let Some((_, word, confidence)) = chunk_best {
+                let candidate = (word, confidence);
+                // An earlier chunk wins a tie, like an earlier option does
+                if best.is_none_or(|best| self.rank(candidate, best) == Ordering::Less) {
+                    best = Some(candidate);
+                }
+            }
+            tokio::task::yield_now().await;
+        }
+
+        match best {
+            Some((word, confidence)) => self.corrected(input, word, confidence),
+            None => CorrectWord {
+                word: None,
+                confidence: best_confidence,
+                ops: None,
+            },
+        }
+    }
+
+    /// Corrects a word like [correct](#method.correct), recording what happened to every option in a
+    /// [ScoringTrace](struct.ScoringTrace.html), to find out why a word was or wasn't suggested.
+    /// It scores the options the same way, so it is only slower by the trace it builds.
+    pub fn trace<O: AsRef<str>>(&self, input: &str, options: &[O]) -> ScoringTrace {
+        let entries = self
+            .assessed(input, options, None)
+            .map(|(index, option, outcome)| TraceEntry {
+                index,
+                option: option.to_string(),
+                outcome,
+            })
+            .collect();
+        ScoringTrace {
+            input: input.to_string(),
+            threshold: self.threshold,
+            entries,
+            result: self.correct(input, options),
+        }
+    }
+
+    /// Traces a correction like [trace](#method.trace), with the options of a [CandidateSource](trait.CandidateSource.html).
+    /// Only the candidates of the source are in the trace: the words it skipped as too far away are not.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector, TrieDictionary};
+    ///
+    /// let dictionary: TrieDictionary = ["hello", "help", "world"].into_iter().collect();
+    /// let trace = Corrector::new(Algorithm::Levenshtein).threshold(0.7).trace_from("helo", &dictionary);
+    /// assert_eq!(trace.entries.len(), 2);
+    /// assert_eq!(trace.result.word.as_deref(), Some("hello"));
+    /// ```
+    pub fn trace_from<C: CandidateSource + ?Sized>(&self, input: &str, source: &C) -> ScoringTrace {
+        self.trace(input, &self.candidates(input, source, None))
+    }
+
+    /// Corrects a word like [correct](#method.correct), with the options of a [CandidateSource](trait.CandidateSource.html),
+    /// like a [TrieDictionary](struct.TrieDictionary.html), that skips the ones too far away to clear the threshold.
+    /// An input the source [contains](trait.CandidateSource.html#method.contains) is returned without looking up any candidates,
+    /// which is a hash lookup for a [Dictionary](struct.Dictionary.html).
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector, TrieDictionary};
+    ///
+    /// let dictionary: TrieDictionary = ["hello", "help", "world"].into_iter().collect();
+    /// let result = Corrector::new(Algorithm::Levenshtein).correct_from("helo", &dictionary);
+    /// assert_eq!(result.word.as_deref(), Some("hello"));
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn correct_from<C: CandidateSource + ?Sized>(
+        &self,
+        input: &str,
+        source: &C,
+    ) -> CorrectWord {
+        if self.accepts_exact(input) && source.contains(input) {
+            instrument::debug!("the source contains the input");
+            return self.exact_match(input).into_owned();
+        }
+        self.correct(input, &self.candidates(input, source, None))
+    }
+
+    /// Corrects a word like [correct_n](#method.correct_n), with the options of a [CandidateSource](trait.CandidateSource.html).
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn correct_n_from<C: CandidateSource + ?Sized>(
+        &self,
+        input: &str,
+        source: &C,
+        n: usize,
+    ) -> Vec<CorrectWord> {
+        self.correct_n(input, &self.candidates(input, source, None), n)
+    }
+
+    /// Corrects a word like [correct_n](#method.correct_n), giving up as soon as `cancel` is set,
+    /// like when a user typed another character and the suggestions for this input are no longer needed.
+    /// The flag is checked before every option is scored, so it can be set from another thread while the options are scanned.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use correct_word::{Algorithm, Cancelled, Corrector};
+    ///
+    /// let options = ["hello", "help", "world"];
+    /// let corrector = Corrector::new(Algorithm::Levenshtein);
+    /// let cancel = AtomicBool::new(false);
+    /// let suggestions = corrector.correct_n_cancellable("helo", &options, 2, &cancel);
+    /// assert_eq!(suggestions, Ok(corrector.correct_n("helo", &options, 2)));
+    ///
+    /// cancel.store(true, Ordering::Relaxed);
+    /// assert_eq!(corrector.correct_n_cancellable("helo", &options, 2, &cancel), Err(Cancelled));
+    /// ```
+    pub fn correct_n_cancellable<O: AsRef<str>>(
+        &self,
+        input: &str,
+        options: &[O],
+        n: usize,
+        cancel: &AtomicBool,
+    ) -> Result<Vec<CorrectWord>, Cancelled> {
+        let mut results = Vec::new();
+        for (index, option, confidence, accepted) in self.scored(input, options) {
+            if cancel.load(atomic::Ordering::Relaxed) {
+                return Err(Cancelled);
+            }
+            if accepted {
+                results.push((index, option, confidence));
+            }
+        }
+        let corrections = self
+            .top(input, results, n)
+            .into_iter()
+            .map(|(_, word, confidence)| self.corrected(input, word, confidence))
+            .collect();
+        Ok(corrections)
+    }
+
+    /// Corrects a word like [correct_n_from](#method.correct_n_from), giving up as soon as `cancel` is set,
+    /// like [correct_n_cancellable](#method.correct_n_cancellable).
+    /// The flag is also checked once the candidates are looked up, but not while the source looks them up.
+    pub fn correct_n_from_cancellable<C: CandidateSource + ?Sized>(
+        &self,
+        input: &str,
+        source: &C,
+        n: usize,
+        cancel: &AtomicBool,
+    ) -> Result<Vec<CorrectWord>, Cancelled> {
+        if cancel.load(atomic::Ordering::Relaxed) {
+            return Err(Cancelled);
+        }
+        let candidates = self.candidates(input, source, None);
+        self.correct_n_cancellable(input, &candidates, n, cancel)
+    }
+
+    /// Corrects a word like [correct_n](#method.correct_n), with the limits of a [Query](struct.Query.html) on top of the threshold:
+    /// the number of corrections, a minimum confidence, a maximum distance and whether an exact match is included.
+    /// The options too far away for the query are skipped like the ones too far away for the threshold.
+    pub fn query<O: AsRef<str>>(
+        &self,
+        input: &str,
+        options: &[O],
+        query: &Query,
+    ) -> Vec<CorrectWord> {
+        let normalized = self.normalizer.normalize(input);
+        let bound = self.query_bound(&normalized, query);
+        let results = self
+            .assessed(input, options, bound)
+            .filter_map(|(index, option, outcome)| match outcome {
+                TraceOutcome::Accepted { confidence } => Some((index, option, confidence)),
+                _ => None,
+            })
+            .filter(|&(_, option, confidence)| {
+                if !query.include_exact && option == input {
+                    return false;
+                }
+                if query.min_confidence.is_some_and(|min| confidence < min) {
+                    return false;
+                }
+                query.max_distance.is_none_or(|max| {
+                    let option = self.normalizer.normalize(option);
+                    match self.granularity {
+                        Granularity::Chars => self
+                            .scorer
+                            .distance_within(&normalized, &option, max)
+                            .is_some(),
+                        granularity => {
+                            self.scorer.distance_with(&normalized, &option, granularity) <= max
+                        }
+                    }
+                })
+            })
+            .collect();
+        self.top(input, results, query.max_results)
+            .into_iter()
+            .map(|(_, word, confidence)| self.corrected(input, word, confidence))
+            .collect()
+    }
+
+    /// Corrects a word like [query](#method.query), with the options of a [CandidateSource](trait.CandidateSource.html).
+    /// The source only looks for candidates within the distance the threshold and the query both allow.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector, Query, TrieDictionary};
+    ///
+    /// let dictionary: TrieDictionary = ["hello", "help", "yellow", "world"].into_iter().collect();
+    /// let corrector = Corrector::new(Algorithm::Levenshtein).threshold(0.3);
+    /// let query = Query::new().min_confidence(0.7).max_results(5);
+    /// let corrections = corrector.query_from("helo", &dictionary, &query);
+    /// assert_eq!(corrections.len(), 2);
+    /// assert!(corrections.iter().all(|correction| correction.confidence >= 0.7));
+    /// ```
+    pub fn query_from<C: CandidateSource + ?Sized>(
+        &self,
+        input: &str,
+        source: &C,
+        query: &Query,
+    ) -> Vec<CorrectWord> {
+        let bound = self.query_bound(input, query);
+        self.query(input, &self.candidates(input, source, bound), query)
+    }
+
+    /// The longest run of characters [correct_compound](#method.correct_compound) corrects as a single word.
+    pub const COMPOUND_MAX_WORD: usize = 24;
+
+    /// Corrects a text of several words, where spaces may be missing, extra, or in the wrong place,
+    /// like the `lookup_compound` of SymSpell: "whereis th elove" is "where is the love".
+    ///
+    /// The text is split into words again, ignoring where its spaces were, and every word is corrected from the source.
+    /// Of all the ways to split it, the one with the fewest edits wins:
+    /// * Correcting a word costs its [distance](trait.Scorer.html#method.distance) to the correction.
+    /// * A word of the input that has no correction is kept as it is, and costs its length.
+    /// * Adding or removing a space costs half an edit, since they are the most common typos in a text.
+    ///
+    /// When two splits cost the same, the one with fewer words wins. Words are at most
+    /// [COMPOUND_MAX_WORD](#associatedconstant.COMPOUND_MAX_WORD) characters long,
+    /// and the words of the result are separated by single spaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The text to correct.
+    /// * `source` - The words to correct from, like a slice or a [Dictionary](struct.Dictionary.html).
+    ///
+    /// # Returns
+    ///
+    /// `CorrectWord` - The corrected text, with its similarity to the input. The word is None for a text without words.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector};
+    ///
+    /// let words = ["where", "is", "the", "love", "glove", "we", "here"];
+    /// let corrector = Corrector::new(Algorithm::Levenshtein);
+    /// let result = corrector.correct_compound("whereis th elove", &words[..]);
+    /// assert_eq!(result.word.as_deref(), Some("where is the love"));
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn correct_compound<C: CandidateSource + ?Sized>(
+        &self,
+        input: &str,
+        source: &C,
+    ) -> CorrectWord {
+        // The characters of the text without its whitespace, and whether there was whitespace right before each of them
+        let mut chars = Vec::new();
+        let mut spaced = Vec::new();
+        let mut space = false;
+        for c in input.chars() {
+            if c.is_whitespace() {
+                space = true;
+            } else {
+                spaced.push(space && !chars.is_empty());
+                chars.push(c);
+                space = false;
+            }
+        }
+        if chars.is_empty() {
+            return CorrectWord {
+                word: None,
+                confidence: 0.0,
+                ops: None,
+            };
+        }
+
+        // The cheapest split of the first `j` characters: its cost, its number of words, and where its last word starts
+        let mut best: Vec<Option<(f64, usize, usize)>> = vec![None; chars.len() + 1];
+        let mut words = vec![String::new(); chars.len() + 1];
+        best[0] = Some((0.0, 0, 0));
+        // Where the word of the input that the current character is in starts
+        let mut token_start = 0;
+        for end in 1..=chars.len() {
+            if spaced[end - 1] {
+                token_start = end - 1;
+            }
+            let token_end = end == chars.len() || spaced[end];
+            let nearest = end.saturating_sub(Self::COMPOUND_MAX_WORD);
+            let whole_token = (token_end && token_start < nearest).then_some(token_start);
+            for start in whole_token.into_iter().chain(nearest..end) {
+                let Some((cost, count, _)) = best[start] else {
+                    continue;
+                };
+                let segment: String = chars[start..end].iter().collect();
+                let correction = if end - start <= Self::COMPOUND_MAX_WORD {
+                    self.correct_from(&segment, source).word
+                } else {
+                    None
+                };
+                let (word, edits) = match correction {
+                    Some(word) => {
+                        let edits = self.scorer.distance(&segment, &word) as f64;
+                        (word, edits)
+                    }
+                    // Only whole words of the input are kept without a correction
+                    None if token_end && start == token_start => {
+                        let edits = (end - start) as f64;
+                        (segment, edits)
+                    }
+                    None => continue,
+                };
+                let removed = spaced[start + 1..end]
+                    .iter()
+                    .filter(|&&space| space)
+                    .count();
+                let added = usize::from(start > 0 && !spaced[start]);
+                let cost = cost + edits + 0.5 * (removed + added) as f64;
+                let better = best[end].is_none_or(|(best_cost, best_count, _)| {
+                    cost < best_cost || (cost == best_cost && count + 1 < best_count)
+                });
+                if better {
+                    best[end] = Some((cost, count + 1, start));
+                    words[end] = word;
+                }
+            }
+        }
+
+        let mut split = Vec::new();
+        let mut end = chars.len();
+        while end > 0 {
+            let (_, _, start) = best[end].expect("the prefixes of a split have a split");
+            split.push(std::mem::take(&mut words[end]));
+            end = start;
+        }
+        split.reverse();
+        let text = split.join(" ");
+        CorrectWord {
+            confidence: self.scorer.similarity(input, &text),
+            ops: self.ops(input, &text),
+            word: Some(text),
+        }
+    }
+
+    /// Corrects a byte string like [correct](#method.correct), for inputs and options that don't have to be valid UTF-8,
+    /// like raw identifiers, file names on Unix or binary tokens.
+    ///
+    /// Every byte is a unit, whatever the [granularity](#method.granularity), and the options are scored with
+    /// [Scorer::similarity_bytes](trait.Scorer.html#method.similarity_bytes).
+    /// The [prefix bonus](#method.prefix_bonus), the [length filter](#method.max_length_difference),
+    /// a [Threshold::MaxDistance](enum.Threshold.html) and the [tie break](#method.tie_break) all count bytes too.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::{Algorithm, Corrector};
+    ///
+    /// // File names in Latin-1, which String can't hold
+    /// let options: Vec<&[u8]> = vec![b"r\xe9sum\xe9.txt", b"notes.txt"];
+    /// let corrector = Corrector::new(Algorithm::DamerauLevenshtein);
+    /// let result = corrector.correct_bytes(b"r\xe9smu\xe9.txt", &options);
+    /// assert_eq!(result.word.as_deref(), Some(options[0]));
+    /// ```
+    pub fn correct_bytes<O: AsRef<[u8]>>(&self, input: &[u8], options: &[O]) -> CorrectBytes {
+        let mut best: Option<(&[u8], f64)> = None;
+        let mut best_confidence = 0.0;
+        for (option, confidence, accepted) in self.scored_bytes(input, options) {
+            if confidence > best_confidence {
+                best_confidence = confidence;
+            }
+            if !accepted {
+                continue;
+            }
+            if best.is_none_or(|best| self.rank_bytes((option, confidence), best) == Ordering::Less)
+            {
+                best = Some((option, confidence));
+            }
+        }
+        match best {
+            Some((word, confidence)) => CorrectBytes {
+                word: Some(word.to_vec()),
+                confidence,
+            },
+            None => CorrectBytes {
+                word: None,
+                confidence: best_confidence,
+            },
+        }
+    }
+
+    /// Corrects a byte string like [correct_bytes](#method.correct_bytes), returning up to `n` of the options that clear the threshold, best first.
+    pub fn correct_n_bytes<O: AsRef<[u8]>>(
+        &self,
+        input: &[u8],
+        options: &[O],
+        n: usize,
+    ) -> Vec<CorrectBytes> {
+        let mut results: Vec<(&[u8], f64)> = self
+            .scored_bytes(input, options)
+            .filter(|&(.., accepted)| accepted)
+            .map(|(option, confidence, _)| (option, confidence))
+            .collect();
+
+        results.sort_by(|a, b| self.rank_bytes(*a, *b));
+        results.truncate(n);
+        results
+            .into_iter()
+            .map(|(word, confidence)| CorrectBytes {
+                word: Some(word.to_vec()),
+                confidence,
+            })
+            .collect()
+    }
+
+    /// Corrects an `OsStr`, like a file name or a path, for "did you mean this file?" on names that aren't valid UTF-8.
+    /// The options can be any slice of `OsStr`s, like `&[PathBuf]` or `&[OsString]`.
+    ///
+    /// The input and the options are scored as strings, with any invalid sequence replaced by `U+FFFD`,
+    /// so the scorer and all the other settings work the same as with [correct](#method.correct).
+    /// The word is the option as it was, never the lossy string, so it always names a file that exists.
+    /// Since two different invalid sequences become the same `U+FFFD`, an option equal to the input always wins
+    /// over one that only looks the same, with a confidence of 1.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::paths::PathScorer;
+    /// use correct_word::{Algorithm, Corrector};
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let candidates = vec![PathBuf::from("Cargo.toml"), PathBuf::from("Cargo.lock")];
+    /// let corrector = Corrector::new(PathScorer::new(Algorithm::Levenshtein));
+    /// let result = corrector.correct_os_str(Path::new("Crago.toml").as_os_str(), &candidates);
+    /// assert_eq!(result.word.map(PathBuf::from), Some(PathBuf::from("Cargo.toml")));
+    /// ```
+    pub fn correct_os_str<O: AsRef<OsStr>>(&self, input: &OsStr, options: &[O]) -> CorrectOsStr {
+        let exact = options.iter().position(|option| option.as_ref() == input);
+        let (correction, best_confidence) = match exact {
+            Some(index) if self.accepts_exact(&input.to_string_lossy()) => {
+                (Some((index, 1.0)), 1.0)
+            }
+            _ => {
+                let lossy: Vec<_> = options
+                    .iter()
+                    .map(|option| option.as_ref().to_string_lossy())
+                    .collect();
+                self.correction(&input.to_string_lossy(), &lossy)
+            }
+        };
+        match correction {
+            Some((index, confidence)) => CorrectOsStr {
+                word: Some(options[index].as_ref().to_os_string()),
+                confidence,
+            },
+            None => CorrectOsStr {
+                word: None,
+                confidence: best_confidence,
+            },
+        }
+    }
+
+    /// Corrects a word like [correct](#method.correct), checking the input, the options and the threshold first.
+    pub fn try_correct<O: AsRef<str>>(
+        &self,
+        input: &str,
+        options: &[O],
+    ) -> Result<CorrectWord, CorrectionError> {
+        if input.is_empty() {
+            return Err(CorrectionError::EmptyInput);
+        }
+        if options.is_empty() {
+            return Err(CorrectionError::EmptyOptions);
+        }
+        if let Threshold::Similarity(similarity) = self.threshold {
+            if !(0.0..=1.0).contains(&similarity) {
+                return Err(CorrectionError::InvalidThreshold(similarity));
+            }
+        }
+
+        Ok(self.correct(input, options))
+    }
+
+    /// The index of the option equal to the input, if there is one and it would clear the threshold.
+    fn exact<O: AsRef<str>>(&self, input: &str, options: &[O]) -> Option<usize> {
+        if !self.accepts_exact(input) {
+            return None;
+        }
+        options.iter().position(|option| option.as_ref() == input)
+    }
+
+    /// Whether an option equal to the input clears the threshold, with its similarity of 1 and distance of 0.
+    fn accepts_exact(&self, input: &str) -> bool {
+        match self.acceptance(&self.normalizer.normalize(input)) {
+            Acceptance::Threshold(Threshold::Similarity(similarity)) => similarity <= 1.0,
+            Acceptance::Threshold(Threshold::MaxDistance(_)) | Acceptance::Exact => true,
+            Acceptance::Never => false,
+        }
+    }
+
+    /// What an option needs to be a correction of the normalized input.
+    fn acceptance(&self, input: &str) -> Acceptance {
+        self.short_words
+            .acceptance(input.chars().count(), self.threshold)
+    }
+
+    /// The correction of an input that is already one of the options.
+    fn exact_match<'a>(&self, word: &'a str) -> CorrectWordRef<'a> {
+        CorrectWordRef {
+            word: Some(word),
+            confidence: 1.0,
+            ops: self.edit_ops.then(Vec::new),
+        }
+    }
+
+    /// A copy of a correction, in the case of the input with [preserve_case](#method.preserve_case).
+    fn owned(&self, input: &str, correction: CorrectWordRef) -> CorrectWord {
+        match correction.word {
+            Some(word) if self.preserve_case => self.corrected(input, word, correction.confidence),
+            _ => correction.into_owned(),
+        }
+    }
+
+    /// The correction of the input to an option, in the case of the input with [preserve_case](#method.preserve_case).
+    fn corrected(&self, input: &str, word: &str, confidence: f64) -> CorrectWord {
+        let word = if self.preserve_case {
+            match_case(input, word)
+        } else {
+            Cow::Borrowed(word)
+        };
+        CorrectWord {
+            ops: self.ops(input, &word),
+            word: Some(word.into_owned()),
+            confidence,
+        }
+    }
+
+    fn ops(&self, input: &str, word: &str) -> Option<Vec<EditOp>> {
+        if self.edit_ops {
+            Some(levenshtein_ops(input.to_string(), word.to_string()))
+        } else {
+            None
+        }
+    }
+
+    /// The candidates of the source for the input, within the largest Levenshtein distance of a correction,
+    /// or within `bound` when it is tighter.
+    fn candidates<'a, C: CandidateSource + ?Sized>(
+        &self,
+        input: &str,
+        source: &'a C,
+        bound: Option<u16>,
+    ) -> Vec<Cow<'a, str>> {
+        let bound = bound.filter(|_| self.normalizer.is_empty());
+        let max_distance = tighter(self.max_levenshtein(input), bound).unwrap_or(u16::MAX);
+        let mut candidates: Vec<Cow<'a, str>> = source.candidates(input, max_distance).collect();
+        if self.joined_words && !source.contains(input) {
+            // The splits come after the words, so a word wins a tie with them
+            let splits = input.char_indices().skip(1).filter_map(|(index, _)| {
+                let (first, second) = input.split_at(index);
+                (source.contains(first) && source.contains(second))
+                    .then(|| Cow::Owned(format!("{first} {second}")))
+            });
+            candidates.extend(splits);
+        }
+        instrument::debug!(
+            candidates = candidates.len(),
+            max_distance,
+            "looked up the candidates"
+        );
+        candidates
+    }
+
+    /// The best option that clears the threshold with its index and confidence, and the best confidence of any option.
+    fn best<'a, O: AsRef<str>>(
+        &self,
+        input: &str,
+        options: &'a [O],
+    ) -> (Option<(usize, &'a str, f64)>, f64) {
+        let mut best: Option<(usize, &str, f64)> = None;
+        let mut best_confidence = 0.0;
+        for (index, option, confidence, accepted) in self.scored(input, options) {
+            if confidence > best_confidence {
+                best_confidence = confidence;
+            }
+            if !accepted {
+                continue;
+            }
+            if best.is_none_or(|(_, word, best)| {
+                self.rank((option, confidence), (word, best)) == Ordering::Less
+            }) {
+                best = Some((index, option, confidence));
+            }
+        }
+        (best, best_confidence)
+    }
+
+    /// The index and confidence of the correction, like [best](#method.best) after looking for an exact match,
+    /// and the best confidence of any option.
+    fn correction<O: AsRef<str>>(&self, input: &str, options: &[O]) -> (Option<(usize, f64)>, f64) {
+        if let Some(index) = self.exact(input, options) {
+            return (Some((index, 1.0)), 1.0);
+        }
+        let (best, best_confidence) = self.best(input, options);
+        (
+            best.map(|(index, _, confidence)| (index, confidence)),
+            best_confidence,
+        )
+    }
+
+    /// The indices and confidences of up to `n` of the options that clear the threshold, best first, with an exact match first.
+    fn ranked<'a, O: AsRef<str>>(
+        &self,
+        input: &str,
+        options: &'a [O],
+        n: usize,
+    ) -> Vec<(usize, &'a str, f64)> {
+        let results = self.score_iter(input, options).collect();
+        self.top(input, results, n)
+    }
+
+    /// The best `n` of the options that clear the threshold, with an exact match first, like [ranked](#method.ranked).
+    fn top<'a>(
+        &self,
+        input: &str,
+        mut results: Vec<(usize, &'a str, f64)>,
+        n: usize,
+    ) -> Vec<(usize, &'a str, f64)> {
+        results.sort_by(|a, b| self.rank((a.1, a.2), (b.1, b.2)));
+        // An exact match is the best correction, even when others tie with it
+        if self.accepts_exact(input) {
+            if let Some(position) = results.iter().position(|&(_, word, _)| word == input) {
+                results[..=position].rotate_right(1);
+            }
+        }
+        results.truncate(n);
+        results
+    }
+
+    /// Scores every option, with whether it clears the threshold.
+    fn scored<'a, 'b, O: AsRef<str>>(
+        &'b self,
+        input: &'b str,
+        options: &'a [O],
+    ) -> impl Iterator<Item = (usize, &'a str, f64, bool)> + 'b
+    where
+        'a: 'b,
+    {
+        self.assessed(input, options, None)
+            .filter_map(|(index, option, outcome)| match outcome {
+                TraceOutcome::Skipped { .. }
+                | TraceOutcome::Duplicate { .. }
+                | TraceOutcome::OutOfBand { .. } => None,
+                TraceOutcome::Rejected { confidence } => Some((index, option, confidence, false)),
+                TraceOutcome::Accepted { confidence } => Some((index, option, confidence, true)),
+            })
+    }
+
+    /// Scores every option that isn't skipped by the length filter.
+    /// `bound` is a Levenshtein distance the options that matter are within, when it is tighter than the threshold's.
+    fn assessed<'a, 'b, O: AsRef<str>>(
+        &'b self,
+        input: &'b str,
+        options: &'a [O],
+        bound: Option<u16>,
+    ) -> impl Iterator<Item = (usize, &'a str, TraceOutcome)> + 'b
+    where
+        'a: 'b,
+    {
+        let input = self.normalizer.normalize(input);
+        let length = input.chars().count();
+        let band = tighter(self.normalized_max_levenshtein(&input), bound);
+        let max_difference = self.length_bound(band);
+        let acceptance = self.short_words.acceptance(length, self.threshold);
+        let mut seen: HashMap<&'a str, usize> = HashMap::new();
+        options.iter().enumerate().map(move |(index, option)| {
+            let option = option.as_ref();
+            if self.dedup {
+                if let Some(&first) = seen.get(option) {
+                    return (index, option, TraceOutcome::Duplicate { first });
+                }
+                seen.insert(option, index);
+            }
+            let normalized = self.normalizer.normalize(option);
+            if let Some(max) = max_difference {
+                let difference = normalized.chars().count().abs_diff(length);
+                if difference > max {
+                    let outcome = TraceOutcome::Skipped {
+                        length_difference: difference,
+                        max_length_difference: max,
+                    };
+                    return (index, option, outcome);
+                }
+            }
+            let outcome = match self.score_normalized(&input, &normalized, acceptance, band) {
+                Some((confidence, true)) => TraceOutcome::Accepted { confidence },
+                Some((confidence, false)) => TraceOutcome::Rejected { confidence },
+                None => TraceOutcome::OutOfBand {
+                    max_distance: band.unwrap_or_default(),
+                },
+            };
+            (index, option, outcome)
+        })
+    }
+
+    /// The largest difference in length an option can have from the normalized input and still be a correction, if there is one,
+    /// from the largest Levenshtein distance a correction can have from it.
+    fn length_bound(&self, band: Option<u16>) -> Option<usize> {
+        match (self.max_length_difference, band.map(usize::from)) {
+            (Some(k), Some(distance)) => Some(k.min(distance)),
+            (k, distance) => k.or(distance),
+        }
+    }
+
+    /// Scores every byte string option that isn't skipped by the length filter, with whether it clears the threshold.
+    fn scored_bytes<'a, 'b, O: AsRef<[u8]>>(
+        &'b self,
+        input: &'b [u8],
+        options: &'a [O],
+    ) -> impl Iterator<Item = (&'a [u8], f64, bool)> + 'b
+    where
+        'a: 'b,
+    {
+        let acceptance = self.short_words.acceptance(input.len(), self.threshold);
+        options.iter().filter_map(move |option| {
+            let option = option.as_ref();
+            if let Some(max) = self.max_length_difference {
+                if option.len().abs_diff(input.len()) > max {
+                    return None;
+                }
+            }
+            let confidence = self.scorer.similarity_bytes(input, option);
+            let prefix = self.prefix_bonus.map_or(0, |(len, _)| {
+                input
+                    .iter()
+                    .zip(option)
+                    .take(len)
+                    .take_while(|(a, b)| a == b)
+                    .count()
+            });
+            let (confidence, accepted) = self.judge(
+                confidence,
+                prefix,
+                acceptance,
+                || input == option,
+                |max| self.scorer.distance_bytes(input, option) <= max,
+            );
+            Some((option, confidence, accepted))
+        })
+    }
+
+    /// Compares two scored options. `Less` means `a` is the better correction.
+    pub(crate) fn rank(&self, a: (&str, f64), b: (&str, f64)) -> Ordering {
+        b.1.total_cmp(&a.1)
+            .then_with(|| self.tie_break.compare(a.0, b.0))
+    }
+
+    /// Compares two scored byte string options, like [rank](#method.rank).
+    fn rank_bytes(&self, a: (&[u8], f64), b: (&[u8], f64)) -> Ordering {
+        b.1.total_cmp(&a.1)
+            .then_with(|| self.tie_break.compare_bytes(a.0, b.0))
+    }
+
+    /// The largest Levenshtein distance an option can be from the input and still clear the threshold, if the scorer knows it.
+    /// It is None with a normalizer, since the bound is on the normalized strings, not on the options as they are written.
+    pub(crate) fn max_levenshtein(&self, input: &str) -> Option<u16> {
+        if !self.normalizer.is_empty() {
+            return None;
+        }
+        self.normalized_max_levenshtein(input)
+    }
+
+    /// Like [max_levenshtein](#method.max_levenshtein), between the normalized input and the normalized options.
+    fn normalized_max_levenshtein(&self, input: &str) -> Option<u16> {
+        // The bound would be on the strings without their accents
+        if self.granularity != Granularity::Chars || self.accent_preference.is_some() {
+            return None;
+        }
+        match self.acceptance(input) {
+            Acceptance::Threshold(threshold) => self.threshold_bound(input, threshold),
+            Acceptance::Exact | Acceptance::Never => Some(0),
+        }
+    }
+
+    /// The largest Levenshtein distance of an option within the limits of the query, if the scorer knows it.
+    fn query_bound(&self, input: &str, query: &Query) -> Option<u16> {
+        if self.granularity != Granularity::Chars || self.accent_preference.is_some() {
+            return None;
+        }
+        let confidence = query
+            .min_confidence
+            .and_then(|min| self.threshold_bound(input, Threshold::Similarity(min)));
+        let distance = query.max_distance.and_then(|max| {
+            self.scorer
+                .max_levenshtein(input, Threshold::MaxDistance(max))
+        });
+        tighter(confidence, distance)
+    }
+
+    /// The largest Levenshtein distance of an option that clears the threshold, between the normalized input and option,
+    /// with the prefix bonus, if the scorer knows it.
+    fn threshold_bound(&self, input: &str, threshold: Threshold) -> Option<u16> {
+        let threshold = match (threshold, self.prefix_bonus) {
+            // The bonus can lift an option that is further away over the threshold
+            (Threshold::Similarity(similarity), Some((len, weight))) => {
+                let bonus = (len as f64 * weight).clamp(0.0, 1.0);
+                if bonus >= 1.0 {
+                    return None;
+                }
+                Threshold::Similarity((similarity - bonus) / (1.0 - bonus))
+            }
+            (threshold, _) => threshold,
+        };
+        self.scorer.max_levenshtein(input, threshold)
+    }
+
+    /// Scores an option, returning its similarity to the input and whether it clears the threshold,
+    /// or None if the scorer found it further away than a correction can be without scoring it in full.
+    pub(crate) fn score(&self, input: &str, option: &str) -> Option<(f64, bool)> {
+        let input = self.normalizer.normalize(input);
+        let option = self.normalizer.normalize(option);
+        let band = self.normalized_max_levenshtein(&input);
+        self.score_normalized(&input, &option, self.acceptance(&input), band)
+    }
+
+    /// Scores an option like [score](#method.score), once the input and the option are normalized.
+    /// `band` is the [normalized_max_levenshtein](#method.normalized_max_levenshtein) of the input.
+    fn score_normalized(
+        &self,
+        input: &str,
+        option: &str,
+        acceptance: Acceptance,
+        band: Option<u16>,
+    ) -> Option<(f64, bool)> {
+        let Some(preference) = self.accent_preference else {
+            return self.score_units(input, option, acceptance, band);
+        };
+        let (bare_input, bare_option) = (strip_accents(input), strip_accents(option));
+        let (confidence, accepted) =
+            self.score_units(&bare_input, &bare_option, acceptance, band)?;
+        if bare_input == input && bare_option == option {
+            return Some((confidence, accepted));
+        }
+        let bare = self
+            .scorer
+            .similarity_with(&bare_input, &bare_option, self.granularity);
+        let accented = self.scorer.similarity_with(input, option, self.granularity);
+        let penalty = preference * (bare - accented);
+        if penalty > 0.0 {
+            return Some(((confidence - penalty).max(0.0), accepted));
+        }
+        Some((confidence, accepted))
+    }
+
+    /// Scores the units of an option, with the prefix bonus and the threshold.
+    ///
+    /// With the largest Levenshtein distance a correction can have, the scorer can stop at that distance,
+    /// like the banded DP of [Algorithm::Levenshtein](enum.Algorithm.html).
+    /// An option it finds further away can't clear the threshold, and is None, without a confidence.
+    fn score_units(
+        &self,
+        input: &str,
+        option: &str,
+        acceptance: Acceptance,
+        band: Option<u16>,
+    ) -> Option<(f64, bool)> {
+        let confidence = match band {
+            Some(max) if self.granularity == Granularity::Chars => {
+                self.scorer.similarity_within(input, option, max)?
+            }
+            _ => self.scorer.similarity_with(input, option, self.granularity),
+        };
+        let prefix = self.prefix_bonus.map_or(0, |(len, _)| {
+            input
+                .chars()
+                .zip(option.chars())
+                .take(len)
+                .take_while(|(a, b)| a == b)
+                .count()
+        });
+        Some(self.judge(
+            confidence,
+            prefix,
+            acceptance,
+            || input == option,
+            |max| match self.granularity {
+                Granularity::Chars => self.scorer.distance_within(input, option, max).is_some(),
+                granularity => self.scorer.distance_with(input, option, granularity) <= max,
+            },
+        ))
+    }
+
+    /// Adds the prefix bonus for the `prefix` leading units the input and the option share, up to the length of the bonus,
+    /// and checks what the option needs, calling `within` with the maximum distance only when there is one.
+    fn judge(
+        &self,
+        confidence: f64,
+        prefix: usize,
+        acceptance: Acceptance,
+        equal: impl FnOnce() -> bool,
+        within: impl FnOnce(u16) -> bool,
+    ) -> (f64, bool) {
+        // A NaN from a custom scorer would never compare as better or worse than anything
+        let confidence = if confidence.is_nan() { 0.0 } else { confidence };
+        let confidence = match self.prefix_bonus {
+            Some((_, weight)) => {
+                let bonus = (prefix as f64 * weight).clamp(0.0, 1.0);
+                confidence + bonus * (1.0 - confidence)
+            }
+            None => confidence,
+        };
+        let accepted = match acceptance {
+            Acceptance::Threshold(Threshold::Similarity(similarity)) => confidence >= similarity,
+            Acceptance::Threshold(Threshold::MaxDistance(max)) => within(max),
+            Acceptance::Exact => equal(),
+            Acceptance::Never => false,
+        };
+        (confidence, accepted)
+    }
+}
+
+/// The smaller of two bounds, when there is one.
+fn tighter(a: Option<u16>, b: Option<u16>) -> Option<u16> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    /// Levenshtein, counting how many times two strings are compared in full.
+    struct Counting {
+        full: Cell<usize>,
+    }
+
+    impl Scorer for Counting {
+        fn similarity(&self, a: &str, b: &str) -> f64 {
+            self.full.set(self.full.get() + 1);
+            Scorer::similarity(&Algorithm::Levenshtein, a, b)
+        }
+
+        fn max_levenshtein(&self, input: &str, threshold: Threshold) -> Option<u16> {
+            Algorithm::Levenshtein.max_levenshtein(input, threshold)
+        }
+
+        fn similarity_within(&self, a: &str, b: &str, max_distance: u16) -> Option<f64> {
+            Algorithm::Levenshtein.similarity_within(a, b, max_distance)
+        }
+    }
+
+    #[test]
+    fn options_outside_the_band_are_not_scored_in_full() {
+        let input = "é".repeat(10_000);
+        let far = format!("{}{}", "é".repeat(5_000), "è".repeat(5_000));
+        let near = format!("{}{}", "é".repeat(8_950), "è".repeat(1_050));
+        // The band of a similarity of 0.9 is 1111 edits, and the one of a distance of 3 is 3 edits
+        for (threshold, max_distance, confidence) in [
+            (Threshold::Similarity(0.9), 1111, 1.0 - 1_050.0 / 10_000.0),
+            (Threshold::MaxDistance(3), 3, 0.0),
+        ] {
+            let scorer = Counting { full: Cell::new(0) };
+            let corrector = Corrector::new(scorer).threshold(threshold);
+            let trace = corrector.trace(&input, &[&far, &near]);
+            assert_eq!(
+                trace.entries[0].outcome,
+                TraceOutcome::OutOfBand { max_distance }
+            );
+            assert_eq!(trace.result.word, None);
+            // Only the options scored in full count for the confidence
+            assert_eq!(trace.result.confidence, confidence);
+            assert_eq!(corrector.scorer.full.get(), 0);
+        }
+
+        let corrector = Corrector::new(Algorithm::Levenshtein).threshold(0.9);
+        let trace = corrector.trace("hello", &["world"]);
+        assert_eq!(
+            trace.entries[0].outcome,
+            TraceOutcome::OutOfBand { max_distance: 0 }
+        );
+        assert_eq!(trace.result.confidence, 0.0);
+    }
+}
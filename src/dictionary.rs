@@ -0,0 +1,368 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+#[cfg(feature = "serialize")]
+use std::io::{Read, Write};
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+
+#[cfg(feature = "serialize")]
+use crate::binary;
+#[cfg(any(feature = "lang-en", feature = "lang-de"))]
+use crate::Lang;
+use crate::{instrument, Cancelled, CorrectWord, Corrector, Partition, Query, Scorer};
+
+/// # Struct: Dictionary
+/// A list of known words to correct input against.
+///
+/// A dictionary can be built from any list of words, or loaded from a file or reader with one word per line.
+/// Blank lines are skipped, and the words are trimmed.
+/// Use [suggest](#method.suggest) to correct a word with a [Corrector](struct.Corrector.html).
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector, Dictionary};
+///
+/// let dictionary = Dictionary::from_reader("hello\nhelp\n\nworld\n".as_bytes()).unwrap();
+/// assert_eq!(dictionary.len(), 3);
+///
+/// let suggestions = dictionary.suggest(&Corrector::new(Algorithm::Levenshtein), "helo", 2);
+/// assert_eq!(suggestions[0].word.as_deref(), Some("hello"));
+/// assert_eq!(suggestions[1].word.as_deref(), Some("help"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dictionary {
+    words: Vec<String>,
+    partition: Partition,
+    /// The positions of the words in every bucket of the partition, in order. Empty without a partition.
+    buckets: BTreeMap<usize, Vec<usize>>,
+    /// Every word, for the exact lookups of [contains](#method.contains).
+    lookup: HashSet<String>,
+}
+
+impl Dictionary {
+    /// Creates an empty dictionary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a dictionary from a reader, with one word per line.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn from_reader<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut words = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            let word = line.trim();
+            if !word.is_empty() {
+                words.push(word.to_string());
+            }
+        }
+        let dictionary = Dictionary::from(words);
+        instrument::debug!(words = dictionary.len(), "loaded a dictionary");
+        Ok(dictionary)
+    }
+
+    /// Loads a dictionary from a file, with one word per line.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(path = %path.as_ref().display()))
+    )]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Loads a dictionary from a file, with one word per line, like [from_file](#method.from_file),
+    /// reading it with tokio so the runtime isn't blocked while a large file is read.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::Dictionary;
+    ///
+    /// let path = std::env::temp_dir().join("correct_word_from_file_async.txt");
+    /// std::fs::write(&path, "hello\nworld\n").unwrap();
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// let dictionary = runtime.block_on(Dictionary::from_file_async(&path)).unwrap();
+    /// assert_eq!(dictionary.words(), ["hello", "world"]);
+    /// # std::fs::remove_file(path).unwrap();
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub async fn from_file_async<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let contents = tokio::fs::read(path).await?;
+        Self::from_reader(contents.as_slice())
+    }
+
+    /// Creates a dictionary from the word list built in for a language, most frequent words first.
+    /// See [Lang](enum.Lang.html) for the languages and the features that build them in.
+    #[cfg(any(feature = "lang-en", feature = "lang-de"))]
+    pub fn builtin(lang: Lang) -> Self {
+        Dictionary::from_iter(lang.words())
+    }
+
+    /// The words of the dictionary, in the order they were added.
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// The number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Whether the dictionary has no words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Adds a word to the end of the dictionary. Returns false if the word was already there.
+    pub fn insert(&mut self, word: impl Into<String>) -> bool {
+        let word = word.into();
+        if self.contains(&word) {
+            return false;
+        }
+        self.push(word);
+        true
+    }
+
+    /// Removes every copy of a word from the dictionary, keeping the order of the others.
+    /// Returns false if the word wasn't there.
+    pub fn remove(&mut self, word: &str) -> bool {
+        if !self.lookup.remove(word) {
+            return false;
+        }
+        self.words.retain(|known| known != word);
+        self.rebuild_buckets();
+        true
+    }
+
+    /// Whether the dictionary has exactly this word, with a hash lookup.
+    pub fn contains(&self, word: &str) -> bool {
+        self.lookup.contains(word)
+    }
+
+    /// Splits the words into buckets, so [suggest](#method.suggest) and the other lookups through
+    /// [CandidateSource](trait.CandidateSource.html) only score the buckets that can hold a correction.
+    /// See [Partition](enum.Partition.html) for the ways to split them. The words keep their order.
+    /// The partition is not part of the dictionary that is [serialized](#method.serialize).
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(partition = ?partition))
+    )]
+    pub fn partition(mut self, partition: Partition) -> Self {
+        self.partition = partition;
+        self.rebuild_buckets();
+        instrument::debug!(buckets = self.buckets.len(), "partitioned a dictionary");
+        self
+    }
+
+    /// The words that might be within `max_distance` of the input, in the order they were added.
+    pub(crate) fn candidates(&self, input: &str, max_distance: u16) -> impl Iterator<Item = &str> {
+        let positions = match self.partition {
+            Partition::None => None,
+            Partition::FirstChar => Some(
+                self.buckets
+                    .get(&self.partition.key(input))
+                    .cloned()
+                    .unwrap_or_default(),
+            ),
+            Partition::Length => {
+                let length = input.chars().count();
+                let max_distance = max_distance as usize;
+                let range =
+                    length.saturating_sub(max_distance)..=length.saturating_add(max_distance);
+                let mut positions: Vec<usize> = self
+                    .buckets
+                    .range(range)
+                    .flat_map(|(_, positions)| positions.iter().copied())
+                    .collect();
+                positions.sort_unstable();
+                Some(positions)
+            }
+        };
+        let all = positions.is_none().then_some(self.words.iter());
+        all.into_iter()
+            .flatten()
+            .chain(
+                positions
+                    .into_iter()
+                    .flatten()
+                    .map(|position| &self.words[position]),
+            )
+            .map(String::as_str)
+    }
+
+    fn push(&mut self, word: String) {
+        if self.partition != Partition::None {
+            let key = self.partition.key(&word);
+            self.buckets.entry(key).or_default().push(self.words.len());
+        }
+        self.lookup.insert(word.clone());
+        self.words.push(word);
+    }
+
+    fn rebuild_buckets(&mut self) {
+        self.buckets.clear();
+        if self.partition == Partition::None {
+            return;
+        }
+        for (position, word) in self.words.iter().enumerate() {
+            let key = self.partition.key(word);
+            self.buckets.entry(key).or_default().push(position);
+        }
+    }
+
+    /// Returns up to `n` corrections of the input from the words of the dictionary, best first,
+    /// the same as [Corrector::correct_n_from](struct.Corrector.html#method.correct_n_from).
+    pub fn suggest<S: Scorer>(
+        &self,
+        corrector: &Corrector<S>,
+        input: &str,
+        n: usize,
+    ) -> Vec<CorrectWord> {
+        corrector.correct_n_from(input, self, n)
+    }
+
+    /// Returns the corrections of the input within the limits of a [Query](struct.Query.html), best first,
+    /// the same as [Corrector::query_from](struct.Corrector.html#method.query_from).
+    pub fn suggest_query<S: Scorer>(
+        &self,
+        corrector: &Corrector<S>,
+        input: &str,
+        query: &Query,
+    ) -> Vec<CorrectWord> {
+        corrector.query_from(input, self, query)
+    }
+
+    /// Returns up to `n` corrections of the input like [suggest](#method.suggest), giving up with [Cancelled](struct.Cancelled.html)
+    /// as soon as `cancel` is set, the same as [Corrector::correct_n_from_cancellable](struct.Corrector.html#method.correct_n_from_cancellable).
+    /// Use it to abandon a lookup in a large dictionary when the input changes before it is done.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::sync::Arc;
+    /// use correct_word::{Algorithm, Corrector, Dictionary};
+    ///
+    /// let dictionary = Dictionary::from_iter(["hello", "help", "world"]);
+    /// let corrector = Corrector::new(Algorithm::Levenshtein);
+    /// let cancel = Arc::new(AtomicBool::new(false));
+    ///
+    /// // Another thread, like the one handling the keystrokes, sets the flag
+    /// let typing = Arc::clone(&cancel);
+    /// std::thread::spawn(move || typing.store(true, Ordering::Relaxed)).join().unwrap();
+    /// assert!(dictionary.suggest_cancellable(&corrector, "helo", 3, &cancel).is_err());
+    /// ```
+    pub fn suggest_cancellable<S: Scorer>(
+        &self,
+        corrector: &Corrector<S>,
+        input: &str,
+        n: usize,
+        cancel: &AtomicBool,
+    ) -> Result<Vec<CorrectWord>, Cancelled> {
+        corrector.correct_n_from_cancellable(input, self, n, cancel)
+    }
+
+    /// Writes the dictionary in a compact binary format, to be loaded with [deserialize](#method.deserialize).
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::Dictionary;
+    ///
+    /// let dictionary = Dictionary::from_iter(["hello", "world"]);
+    /// let mut bytes = Vec::new();
+    /// dictionary.serialize(&mut bytes).unwrap();
+    /// assert_eq!(Dictionary::deserialize(bytes.as_slice()).unwrap(), dictionary);
+    /// ```
+    #[cfg(feature = "serialize")]
+    pub fn serialize<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        binary::write_header(&mut writer, MAGIC)?;
+        binary::write_u32(&mut writer, self.words.len())?;
+        for word in &self.words {
+            binary::write_string(&mut writer, word)?;
+        }
+        writer.flush()
+    }
+
+    /// Loads a dictionary written by [serialize](#method.serialize).
+    /// A reader that doesn't hold a serialized dictionary gives an `InvalidData` error.
+    #[cfg(feature = "serialize")]
+    pub fn deserialize<R: Read>(mut reader: R) -> io::Result<Self> {
+        binary::read_header(&mut reader, MAGIC)?;
+        let count = binary::read_u32(&mut reader)?;
+        let mut words = Vec::with_capacity(binary::capacity(count));
+        for _ in 0..count {
+            words.push(binary::read_string(&mut reader)?);
+        }
+        Ok(Dictionary::from(words))
+    }
+}
+
+#[cfg(feature = "serialize")]
+const MAGIC: &[u8; 4] = b"CWD1";
+
+impl<W: Into<String>> FromIterator<W> for Dictionary {
+    fn from_iter<I: IntoIterator<Item = W>>(words: I) -> Self {
+        Dictionary::from(words.into_iter().map(Into::into).collect::<Vec<String>>())
+    }
+}
+
+impl<W: Into<String>> Extend<W> for Dictionary {
+    /// Adds the words to the end of the dictionary, like [from_reader](#method.from_reader), without checking for duplicates.
+    fn extend<I: IntoIterator<Item = W>>(&mut self, words: I) {
+        for word in words {
+            self.push(word.into());
+        }
+    }
+}
+
+impl From<Vec<String>> for Dictionary {
+    fn from(words: Vec<String>) -> Self {
+        Dictionary {
+            lookup: words.iter().cloned().collect(),
+            words,
+            ..Dictionary::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Algorithm, Threshold};
+
+    #[test]
+    fn length_partition_matches_linear_scan() {
+        let words = [
+            "a",
+            "ab",
+            "abc",
+            "abcd",
+            "abcde",
+            "b",
+            "bcd",
+            "caf\u{e9}",
+            "cafe",
+            "",
+        ];
+        let mut partitioned = Dictionary::from_iter(words).partition(Partition::Length);
+        let mut linear = Dictionary::from_iter(words);
+        partitioned.remove("abc");
+        linear.remove("abc");
+        partitioned.extend(["abc", "xyz"]);
+        linear.extend(["abc", "xyz"]);
+        assert_eq!(partitioned.words(), linear.words());
+
+        for algorithm in [Algorithm::Levenshtein, Algorithm::Lcs] {
+            for threshold in [Threshold::Similarity(0.5), Threshold::MaxDistance(1)] {
+                let corrector = Corrector::new(algorithm).threshold(threshold);
+                for input in ["", "a", "abd", "cafe", "bcda"] {
+                    assert_eq!(
+                        partitioned.suggest(&corrector, input, 10),
+                        linear.suggest(&corrector, input, 10),
+                        "{algorithm:?} {threshold:?} {input:?}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use crate::metrics::levenshtein::{levenshtein_ops, levenshtein_ops_linear, EditOp};
+
+/// # Enum: Segment
+/// A piece of the difference between the input and its correction, as returned by [diff](fn.diff.html).
+///
+/// * Kept: Text that is the same in both.
+/// * Inserted: Text that is only in the correction.
+/// * Deleted: Text that is only in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    Kept(String),
+    Inserted(String),
+    Deleted(String),
+}
+
+/// Splits the difference between the input and its correction into kept, inserted and deleted segments.
+/// The segments are based on the [edit ops](../metrics/levenshtein/fn.levenshtein_ops.html) between the two strings.
+/// Between two kept segments, all the deleted text comes first, followed by all the inserted text.
+///
+/// Putting the kept and deleted segments together gives back the input,
+/// and putting the kept and inserted segments together gives the correction.
+///
+/// # Arguments
+///
+/// * `input` - The word that was corrected.
+/// * `corrected` - The corrected word.
+///
+/// # Returns
+///
+/// `Vec<Segment>` - The segments, from the start of the strings to the end.
+///
+/// # Example
+/// ```
+/// use correct_word::diff::{diff, Segment};
+///
+/// let segments = diff("speling".to_string(), "spelling".to_string());
+/// assert_eq!(segments, vec![
+///     Segment::Kept("spe".to_string()),
+///     Segment::Inserted("l".to_string()),
+///     Segment::Kept("ling".to_string()),
+/// ]);
+///
+/// let segments = diff("recieve".to_string(), "receive".to_string());
+/// assert_eq!(segments, vec![
+///     Segment::Kept("rec".to_string()),
+///     Segment::Deleted("ie".to_string()),
+///     Segment::Inserted("ei".to_string()),
+///     Segment::Kept("ve".to_string()),
+/// ]);
+/// ```
+pub fn diff(input: String, corrected: String) -> Vec<Segment> {
+    let chars: Vec<char> = input.chars().collect();
+    segments(&chars, levenshtein_ops(input, corrected))
+}
+
+/// Splits the difference between two texts into segments like [diff](fn.diff.html),
+/// from the [edit ops](../metrics/levenshtein/fn.levenshtein_ops_linear.html) found with Hirschberg's algorithm,
+/// which take memory proportional to the shorter text instead of the product of their lengths.
+/// Use it for long texts, like a pasted document and its corrected version.
+/// When there are several ways to make the fewest edits, the segments may differ from the ones of [diff](fn.diff.html).
+///
+/// # Arguments
+///
+/// * `input` - The text that was corrected.
+/// * `corrected` - The corrected text.
+///
+/// # Returns
+///
+/// `Vec<Segment>` - The segments, from the start of the texts to the end.
+///
+/// # Example
+/// ```
+/// use correct_word::diff::{diff_linear, Segment};
+///
+/// let text = "We recieve your order. ".repeat(400);
+/// let corrected = text.replace("recieve", "receive");
+/// let segments = diff_linear(text, corrected);
+/// assert_eq!(segments.iter().filter(|segment| matches!(segment, Segment::Inserted(_))).count(), 400);
+/// ```
+pub fn diff_linear(input: String, corrected: String) -> Vec<Segment> {
+    let chars: Vec<char> = input.chars().collect();
+    segments(&chars, levenshtein_ops_linear(input, corrected))
+}
+
+/// Groups the edits that turn `chars` into the other string into segments.
+fn segments(chars: &[char], ops: Vec<EditOp>) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut kept = String::new();
+    let mut deleted = String::new();
+    let mut inserted = String::new();
+    let mut position = 0;
+
+    for op in ops {
+        let source = match op {
+            EditOp::Insert { source, .. } => source,
+            EditOp::Delete { source, .. } => source,
+            EditOp::Substitute { source, .. } => source,
+        };
+        if source > position {
+            flush_changes(&mut segments, &mut deleted, &mut inserted);
+            kept.extend(&chars[position..source]);
+            position = source;
+        }
+        if !kept.is_empty() {
+            segments.push(Segment::Kept(std::mem::take(&mut kept)));
+        }
+
+        match op {
+            EditOp::Insert { char, .. } => inserted.push(char),
+            EditOp::Delete { char, .. } => {
+                deleted.push(char);
+                position += 1;
+            }
+            EditOp::Substitute { from, to, .. } => {
+                deleted.push(from);
+                inserted.push(to);
+                position += 1;
+            }
+        }
+    }
+
+    flush_changes(&mut segments, &mut deleted, &mut inserted);
+    kept.extend(&chars[position..]);
+    if !kept.is_empty() {
+        segments.push(Segment::Kept(kept));
+    }
+    segments
+}
+
+fn flush_changes(segments: &mut Vec<Segment>, deleted: &mut String, inserted: &mut String) {
+    if !deleted.is_empty() {
+        segments.push(Segment::Deleted(std::mem::take(deleted)));
+    }
+    if !inserted.is_empty() {
+        segments.push(Segment::Inserted(std::mem::take(inserted)));
+    }
+}
+
+/// Renders segments as plain text, marking deleted text with `[-` and `-]` and inserted text with `{+` and `+}`.
+/// These are the same markers as `git diff --word-diff`, so they work anywhere, even without colors.
+///
+/// # Arguments
+///
+/// * `segments` - The segments to render, as returned by [diff](fn.diff.html).
+///
+/// # Returns
+///
+/// `String` - The rendered difference.
+///
+/// # Example
+/// ```
+/// use correct_word::diff::{diff, render_markers};
+///
+/// let segments = diff("recieve".to_string(), "receive".to_string());
+/// assert_eq!(render_markers(&segments), "rec[-ie-]{+ei+}ve");
+/// ```
+pub fn render_markers(segments: &[Segment]) -> String {
+    segments
+        .iter()
+        .map(|segment| match segment {
+            Segment::Kept(text) => text.clone(),
+            Segment::Inserted(text) => format!("{{+{text}+}}"),
+            Segment::Deleted(text) => format!("[-{text}-]"),
+        })
+        .collect()
+}
+
+/// Renders segments for a terminal, with deleted text in red and struck through, and inserted text in green.
+/// Needs the `ansi` feature.
+///
+/// # Arguments
+///
+/// * `segments` - The segments to render, as returned by [diff](fn.diff.html).
+///
+/// # Returns
+///
+/// `String` - The rendered difference, with ANSI escape codes.
+///
+/// # Example
+/// ```
+/// use correct_word::diff::{diff, render_ansi};
+///
+/// let segments = diff("helo".to_string(), "hello".to_string());
+/// assert_eq!(render_ansi(&segments), "he\x1b[32ml\x1b[0mlo");
+/// ```
+#[cfg(feature = "ansi")]
+pub fn render_ansi(segments: &[Segment]) -> String {
+    segments
+        .iter()
+        .map(|segment| match segment {
+            Segment::Kept(text) => text.clone(),
+            Segment::Inserted(text) => format!("\x1b[32m{text}\x1b[0m"),
+            Segment::Deleted(text) => format!("\x1b[31;9m{text}\x1b[0m"),
+        })
+        .collect()
+}
@@ -0,0 +1,71 @@
+use crate::{correct_word_n, Algorithm};
+
+/// Get a list of the best "good enough" matches for a word, the same way as Python's `difflib.get_close_matches`.
+/// This is meant as a drop-in replacement when porting Python code, so the arguments and the defaults are the same.
+///
+/// The options are scored with the [Ratcliff/Obershelp](../metrics/ratcliff_obershelp/index.html) ratio,
+/// with each possibility as the first sequence and the word as the second one, just like difflib does.
+///
+/// # Arguments
+///
+/// * `word` - The word to find close matches for.
+/// * `possibilities` - A list of strings to match the word against.
+/// * `n` - The maximum number of close matches to return. By default, it is 3.
+/// * `cutoff` - The minimum score, from 0 to 1, a possibility needs to be returned. By default, it is 0.6.
+///
+/// # Returns
+///
+/// `Vec<String>` - The best matches, sorted from the most similar to the least similar.
+/// Possibilities with the same score are sorted in reverse alphabetical order, like in difflib.
+///
+/// # Panics
+///
+/// Panics if `n` is 0 or if `cutoff` is not in the range from 0 to 1, where difflib raises a `ValueError`.
+///
+/// # Example
+/// ```
+/// use correct_word::difflib::get_close_matches;
+///
+/// let possibilities = vec!["ape", "apple", "peach", "puppy"].into_iter().map(String::from).collect();
+/// let matches = get_close_matches("appel".to_string(), possibilities, None, None);
+/// assert_eq!(matches, vec!["apple", "ape"]);
+///
+/// // Ties go to the possibility that sorts last, which drops "abd" here
+/// let possibilities = vec!["abd", "abe", "xbc", "abcd"].into_iter().map(String::from).collect();
+/// let matches = get_close_matches("abc".to_string(), possibilities, Some(3), Some(0.5));
+/// assert_eq!(matches, vec!["abcd", "xbc", "abe"]);
+/// ```
+pub fn get_close_matches(
+    word: String,
+    possibilities: Vec<String>,
+    n: Option<usize>,
+    cutoff: Option<f64>,
+) -> Vec<String> {
+    let n = n.unwrap_or(3);
+    let cutoff = cutoff.unwrap_or(0.6);
+    assert!(n > 0, "n must be > 0: {n}");
+    assert!(
+        (0.0..=1.0).contains(&cutoff),
+        "cutoff must be in [0.0, 1.0]: {cutoff}"
+    );
+
+    let count = possibilities.len();
+    let mut matches = correct_word_n(
+        Algorithm::RatcliffObershelp,
+        word,
+        possibilities,
+        count,
+        Some(cutoff),
+    );
+    matches.sort_by(|a, b| {
+        b.confidence
+            .total_cmp(&a.confidence)
+            .then_with(|| b.word.cmp(&a.word))
+    });
+
+    matches
+        .into_iter()
+        .take(n)
+        .filter_map(|correction| correction.word)
+        .collect()
+}
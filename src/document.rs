@@ -0,0 +1,330 @@
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::{Algorithm, CorrectWord, Dictionary, Scorer, SpellChecker};
+
+mod diagnostics;
+mod filters;
+mod session;
+
+pub use diagnostics::{Diagnostic, LineIndex, Position, PositionEncoding, TextEdit, TextRange};
+#[cfg(feature = "regex")]
+pub use filters::RegexFilter;
+pub use filters::{MarkdownFilter, SpanFilter, UrlFilter};
+pub use session::{CorrectionSession, Decision, Flagged};
+
+/// # Enum: Hyphens
+/// How a [TokenizerConfig](struct.TokenizerConfig.html) treats a hyphen between two letters, like in "well-known".
+///
+/// * Keep: The hyphenated word is one token. It is correct when the dictionary has it, or has every part of it,
+///   so "well-known" isn't corrected to another word when the dictionary only has "well" and "known".
+/// * Split: The hyphen separates two tokens, "well" and "known", which are checked on their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Hyphens {
+    #[default]
+    Keep,
+    Split,
+}
+
+/// # Struct: TokenizerConfig
+/// The rules that split a text into the words a [DocumentCorrector](struct.DocumentCorrector.html) checks.
+///
+/// A word is a run of letters and digits, with the combining accents of its letters.
+/// Hyphens and apostrophes are only part of a word when they are between two of its letters,
+/// so the quotes around a word and the dash after it never are:
+/// * `hyphens`: Whether "well-known" is one word or two, see [Hyphens](enum.Hyphens.html). By default, it is one.
+/// * `contractions`: Whether "don't" and "l'homme" are one word, with an ASCII or a typographic apostrophe. By default, they are.
+///   Otherwise, the apostrophe separates two words, for dictionaries without contractions.
+///
+/// # Example
+/// ```
+/// use correct_word::document::{Hyphens, TokenizerConfig};
+///
+/// let text = "It's a well-known 'quote' - isn't it?";
+/// let config = TokenizerConfig::new();
+/// let words: Vec<&str> = config.tokens(text).iter().map(|token| token.text).collect();
+/// assert_eq!(words, ["It's", "a", "well-known", "quote", "isn't", "it"]);
+///
+/// let config = config.hyphens(Hyphens::Split).contractions(false);
+/// let words: Vec<&str> = config.tokens(text).iter().map(|token| token.text).collect();
+/// assert_eq!(words, ["It", "s", "a", "well", "known", "quote", "isn", "t", "it"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenizerConfig {
+    pub(crate) hyphens: Hyphens,
+    pub(crate) contractions: bool,
+}
+
+impl Default for TokenizerConfig {
+    fn default() -> Self {
+        TokenizerConfig {
+            hyphens: Hyphens::default(),
+            contractions: true,
+        }
+    }
+}
+
+impl TokenizerConfig {
+    /// Creates the default rules, which keep hyphenated words and contractions together.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether a hyphen between two letters separates two words.
+    pub fn hyphens(mut self, hyphens: Hyphens) -> Self {
+        self.hyphens = hyphens;
+        self
+    }
+
+    /// Sets whether an apostrophe between two letters is part of the word.
+    pub fn contractions(mut self, enabled: bool) -> Self {
+        self.contractions = enabled;
+        self
+    }
+
+    /// Splits a text into its words, in the order they are in the text.
+    pub fn tokens<'a>(&self, text: &'a str) -> Vec<Token<'a>> {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        let mut tokens = Vec::new();
+        let mut start = None;
+        for (i, &(offset, c)) in chars.iter().enumerate() {
+            let accent = ('\u{300}'..='\u{36f}').contains(&c);
+            if c.is_alphanumeric() || (accent && start.is_some()) {
+                start.get_or_insert(offset);
+                continue;
+            }
+            let joins = start.is_some()
+                && self.joins(c)
+                && chars
+                    .get(i + 1)
+                    .is_some_and(|&(_, next)| next.is_alphanumeric());
+            if !joins {
+                if let Some(start) = start.take() {
+                    tokens.push(Token {
+                        text: &text[start..offset],
+                        span: start..offset,
+                    });
+                }
+            }
+        }
+        if let Some(start) = start {
+            tokens.push(Token {
+                text: &text[start..],
+                span: start..text.len(),
+            });
+        }
+        tokens
+    }
+
+    /// Whether the character is part of a word when it is between two letters.
+    fn joins(&self, c: char) -> bool {
+        match c {
+            '-' | '\u{2010}' | '\u{2011}' => self.hyphens == Hyphens::Keep,
+            '\'' | '\u{2019}' => self.contractions,
+            _ => false,
+        }
+    }
+}
+
+/// # Struct: Token
+/// A word of a text, with the byte range it is at in the text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub span: Range<usize>,
+}
+
+/// # Struct: Misspelling
+/// A word of a text that a [DocumentCorrector](struct.DocumentCorrector.html) didn't find in its dictionaries,
+/// with the byte range it is at in the text, and its correction. The word of the correction is None if there is none.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Misspelling {
+    pub span: Range<usize>,
+    pub word: String,
+    pub correction: CorrectWord,
+}
+
+/// # Struct: DocumentCorrector
+/// Corrects the misspelled words of a whole text, leaving everything around them as it was.
+///
+/// The text is split into words with a [TokenizerConfig](struct.TokenizerConfig.html),
+/// and every word is checked and corrected by a [SpellChecker](../struct.SpellChecker.html),
+/// so the words of its user dictionary and the ignored ones are correct too.
+/// A word is also correct when its lowercase form is, like a known word at the start of a sentence,
+/// and words with digits, like "2nd" or "v2", are never checked.
+///
+/// [Span filters](trait.SpanFilter.html) skip the parts of the text that aren't prose, like code, URLs and email addresses.
+/// There are none by default.
+///
+/// For a language server, [diagnostics](#method.diagnostics) returns them with line and character positions, and their fixes as edits.
+/// To go through the misspelled words one at a time, deciding what to do with each, start a [session](#method.session).
+///
+/// To write the corrections in the case of the misspelled words, give the checker a corrector with
+/// [preserve_case](../struct.Corrector.html#method.preserve_case).
+///
+/// # Example
+/// ```
+/// use correct_word::document::DocumentCorrector;
+/// use correct_word::Dictionary;
+///
+/// let dictionary = Dictionary::from_iter(["the", "answer", "is", "well", "known", "don't", "ask"]);
+/// let corrector = DocumentCorrector::new(dictionary);
+/// let text = "The answr is well-known, don't ask.";
+/// assert_eq!(corrector.correct(text), "The answer is well-known, don't ask.");
+///
+/// let misspellings = corrector.misspellings(text);
+/// assert_eq!(misspellings.len(), 1);
+/// assert_eq!(misspellings[0].span, 4..9);
+/// ```
+#[derive(Clone)]
+pub struct DocumentCorrector<S: Scorer = Algorithm> {
+    checker: SpellChecker<S>,
+    tokenizer: TokenizerConfig,
+    filters: Vec<Arc<dyn SpanFilter + Send + Sync>>,
+}
+
+impl DocumentCorrector {
+    /// Creates a corrector that checks words against a dictionary, with the default tokenizer rules.
+    pub fn new(dictionary: Dictionary) -> Self {
+        Self::with_checker(SpellChecker::new(dictionary))
+    }
+}
+
+impl<S: Scorer> DocumentCorrector<S> {
+    /// Creates a corrector that checks words with a spell checker, with the default tokenizer rules.
+    pub fn with_checker(checker: SpellChecker<S>) -> Self {
+        DocumentCorrector {
+            checker,
+            tokenizer: TokenizerConfig::default(),
+            filters: Vec::new(),
+        }
+    }
+
+    /// Sets the rules that split a text into words.
+    pub fn tokenizer(mut self, tokenizer: TokenizerConfig) -> Self {
+        self.tokenizer = tokenizer;
+        self
+    }
+
+    /// Adds a filter of the parts of the text not to correct. A word is skipped when any of the filters skips part of it.
+    pub fn filter(mut self, filter: impl SpanFilter + Send + Sync + 'static) -> Self {
+        self.filters.push(Arc::new(filter));
+        self
+    }
+
+    /// The spell checker the words are checked with.
+    pub fn checker(&self) -> &SpellChecker<S> {
+        &self.checker
+    }
+
+    /// The spell checker the words are checked with, to add words to it or ignore them.
+    pub fn checker_mut(&mut self) -> &mut SpellChecker<S> {
+        &mut self.checker
+    }
+
+    /// Whether a word of a text is correct, in any of the ways the [DocumentCorrector](struct.DocumentCorrector.html) accepts.
+    pub fn is_correct(&self, word: &str) -> bool {
+        if word.chars().any(|c| c.is_numeric())
+            || self.checker.is_correct(word)
+            || self.checker.is_correct(&word.to_lowercase())
+        {
+            return true;
+        }
+        let mut parts = word.split(['-', '\u{2010}', '\u{2011}']);
+        word.contains(['-', '\u{2010}', '\u{2011}'])
+            && parts.all(|part| !part.is_empty() && self.is_correct(part))
+    }
+
+    /// The misspelled words of a text, in the order they are in the text, with their corrections.
+    pub fn misspellings(&self, text: &str) -> Vec<Misspelling> {
+        self.checked(text)
+            .into_iter()
+            .filter(|token| !self.is_correct(token.text))
+            .map(|token| Misspelling {
+                correction: self.checker.correct(token.text),
+                word: token.text.to_string(),
+                span: token.span,
+            })
+            .collect()
+    }
+
+    /// The words of a text that none of the filters skip.
+    fn checked<'a>(&self, text: &'a str) -> Vec<Token<'a>> {
+        let mut skipped: Vec<Range<usize>> = self
+            .filters
+            .iter()
+            .flat_map(|filter| filter.skipped(text))
+            .filter(|span| !span.is_empty())
+            .collect();
+        skipped.sort_by_key(|span| span.start);
+        // Merged, so the tokens and the skipped spans can be walked through together
+        let mut merged: Vec<Range<usize>> = Vec::with_capacity(skipped.len());
+        for span in skipped {
+            match merged.last_mut() {
+                Some(last) if span.start <= last.end => last.end = last.end.max(span.end),
+                _ => merged.push(span),
+            }
+        }
+        let mut spans = merged.into_iter().peekable();
+        self.tokenizer
+            .tokens(text)
+            .into_iter()
+            .filter(|token| {
+                while spans.next_if(|span| span.end <= token.span.start).is_some() {}
+                spans.peek().is_none_or(|span| span.start >= token.span.end)
+            })
+            .collect()
+    }
+
+    /// Corrects a text, replacing every misspelled word that has a correction with it.
+    pub fn correct(&self, text: &str) -> String {
+        let mut corrected = String::with_capacity(text.len());
+        let mut end = 0;
+        for misspelling in self.misspellings(text) {
+            if let Some(word) = &misspelling.correction.word {
+                corrected.push_str(&text[end..misspelling.span.start]);
+                corrected.push_str(word);
+                end = misspelling.span.end;
+            }
+        }
+        corrected.push_str(&text[end..]);
+        corrected
+    }
+}
+
+impl<S: Scorer + fmt::Debug> fmt::Debug for DocumentCorrector<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DocumentCorrector")
+            .field("checker", &self.checker)
+            .field("tokenizer", &self.tokenizer)
+            .field("filters", &self.filters.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_keep_their_spans() {
+        let text = "«Café» rock’n’roll -dash- o'  x-\u{301} e\u{301}t\u{301}e\u{301}'";
+        let tokens = TokenizerConfig::new().tokens(text);
+        let words: Vec<&str> = tokens.iter().map(|token| token.text).collect();
+        assert_eq!(
+            words,
+            [
+                "Café",
+                "rock’n’roll",
+                "dash",
+                "o",
+                "x",
+                "e\u{301}t\u{301}e\u{301}"
+            ]
+        );
+        for token in tokens {
+            assert_eq!(&text[token.span], token.text);
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use std::ops::Range;
+
+use super::DocumentCorrector;
+use crate::Scorer;
+
+/// # Enum: PositionEncoding
+/// What the `character` of a [Position](struct.Position.html) counts, like the position encodings of the Language Server Protocol.
+///
+/// * Utf8: Bytes.
+/// * Utf16: UTF-16 code units, so a character outside the Basic Multilingual Plane, like an emoji, counts twice.
+///   It is the default, and the only encoding every LSP client supports.
+/// * Utf32: Characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PositionEncoding {
+    Utf8,
+    #[default]
+    Utf16,
+    Utf32,
+}
+
+/// # Struct: Position
+/// A position in a text, as a line and an offset in it, both starting at 0, like an LSP `Position`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub line: u32,
+    pub character: u32,
+}
+
+/// # Struct: TextRange
+/// The part of a text between two [Position](struct.Position.html)s, with the end excluded, like an LSP `Range`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextRange {
+    pub start: Position,
+    pub end: Position,
+}
+
+/// # Struct: TextEdit
+/// A replacement of a part of a text, like an LSP `TextEdit`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub range: TextRange,
+    pub new_text: String,
+}
+
+/// # Struct: Diagnostic
+/// A misspelled word of a text, with where it is and the edits that correct it, best first,
+/// returned by [DocumentCorrector::diagnostics](struct.DocumentCorrector.html#method.diagnostics).
+/// It maps onto an LSP `Diagnostic`, and its edits onto the code actions that fix it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub range: TextRange,
+    pub word: String,
+    pub message: String,
+    pub edits: Vec<TextEdit>,
+}
+
+/// # Struct: LineIndex
+/// The start of every line of a text, to turn byte offsets into [Position](struct.Position.html)s
+/// without going through the whole text each time.
+/// A line ends at a `\n`, a `\r\n` or a lone `\r`, like it does for LSP.
+///
+/// # Example
+/// ```
+/// use correct_word::document::{LineIndex, Position, PositionEncoding};
+///
+/// let text = "héllo\r\n🦀 wrld";
+/// let index = LineIndex::new(text, PositionEncoding::Utf16);
+/// assert_eq!(index.position(text.find("wrld").unwrap()), Position { line: 1, character: 3 });
+///
+/// let index = LineIndex::new(text, PositionEncoding::Utf8);
+/// assert_eq!(index.position(text.find("wrld").unwrap()), Position { line: 1, character: 5 });
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineIndex<'a> {
+    text: &'a str,
+    encoding: PositionEncoding,
+    starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    /// Finds the lines of a text, for positions in the given encoding.
+    pub fn new(text: &'a str, encoding: PositionEncoding) -> Self {
+        let bytes = text.as_bytes();
+        let mut starts = vec![0];
+        for (i, &b) in bytes.iter().enumerate() {
+            if b == b'\n' || (b == b'\r' && bytes.get(i + 1) != Some(&b'\n')) {
+                starts.push(i + 1);
+            }
+        }
+        LineIndex {
+            text,
+            encoding,
+            starts,
+        }
+    }
+
+    /// The position of a byte offset of the text, which has to be at the boundary of a character.
+    pub fn position(&self, offset: usize) -> Position {
+        let line = self.starts.partition_point(|&start| start <= offset) - 1;
+        let before = &self.text[self.starts[line]..offset];
+        let character = match self.encoding {
+            PositionEncoding::Utf8 => before.len(),
+            PositionEncoding::Utf16 => before.encode_utf16().count(),
+            PositionEncoding::Utf32 => before.chars().count(),
+        };
+        Position {
+            line: line as u32,
+            character: character as u32,
+        }
+    }
+
+    /// The range of a byte range of the text.
+    pub fn range(&self, span: Range<usize>) -> TextRange {
+        TextRange {
+            start: self.position(span.start),
+            end: self.position(span.end),
+        }
+    }
+}
+
+impl<S: Scorer> DocumentCorrector<S> {
+    /// The misspelled words of a text as [Diagnostic](struct.Diagnostic.html)s, in the order they are in the text,
+    /// with an edit for each of up to `suggestions` of their suggestions, and positions in the given encoding.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::document::{DocumentCorrector, Position, PositionEncoding};
+    /// use correct_word::Dictionary;
+    ///
+    /// let corrector = DocumentCorrector::new(Dictionary::from_iter(["hello", "help", "world"]));
+    /// let diagnostics = corrector.diagnostics("hello\nwrld, helo", 2, PositionEncoding::Utf16);
+    /// assert_eq!(diagnostics.len(), 2);
+    /// assert_eq!(diagnostics[1].range.start, Position { line: 1, character: 6 });
+    /// assert_eq!(diagnostics[1].range.end, Position { line: 1, character: 10 });
+    ///
+    /// let fixes: Vec<&str> = diagnostics[1].edits.iter().map(|edit| edit.new_text.as_str()).collect();
+    /// assert_eq!(fixes, ["hello", "help"]);
+    /// ```
+    pub fn diagnostics(
+        &self,
+        text: &str,
+        suggestions: usize,
+        encoding: PositionEncoding,
+    ) -> Vec<Diagnostic> {
+        let index = LineIndex::new(text, encoding);
+        self.checked(text)
+            .into_iter()
+            .filter(|token| !self.is_correct(token.text))
+            .map(|token| {
+                let range = index.range(token.span);
+                let edits = self
+                    .checker
+                    .suggest(token.text, suggestions)
+                    .into_iter()
+                    .filter_map(|suggestion| suggestion.word)
+                    .map(|new_text| TextEdit { range, new_text })
+                    .collect();
+                Diagnostic {
+                    range,
+                    message: format!("unknown word \"{}\"", token.text),
+                    word: token.text.to_string(),
+                    edits,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_follow_every_line_ending() {
+        let text = "a\nb\r\nc\rd𝄞e\n";
+        let utf16 = LineIndex::new(text, PositionEncoding::Utf16);
+        let utf32 = LineIndex::new(text, PositionEncoding::Utf32);
+        let at = |index: &LineIndex, c: &str| {
+            let position = index.position(text.find(c).unwrap());
+            (position.line, position.character)
+        };
+        assert_eq!(at(&utf16, "a"), (0, 0));
+        assert_eq!(at(&utf16, "b"), (1, 0));
+        assert_eq!(at(&utf16, "\r\n"), (1, 1));
+        assert_eq!(at(&utf16, "c"), (2, 0));
+        assert_eq!(at(&utf16, "d"), (3, 0));
+        assert_eq!(at(&utf16, "e"), (3, 3));
+        assert_eq!(at(&utf32, "e"), (3, 2));
+        assert_eq!(
+            utf16.position(text.len()),
+            Position {
+                line: 4,
+                character: 0
+            }
+        );
+    }
+}
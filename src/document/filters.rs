@@ -0,0 +1,294 @@
+use std::ops::Range;
+
+/// # Trait: SpanFilter
+/// Finds the parts of a text a [DocumentCorrector](struct.DocumentCorrector.html) shouldn't correct,
+/// like code, URLs or email addresses, added to it with [filter](struct.DocumentCorrector.html#method.filter).
+/// No word that overlaps one of them is checked.
+///
+/// The crate has a few filters to start with:
+/// * [MarkdownFilter](struct.MarkdownFilter.html): The code blocks, inline code and link destinations of Markdown.
+/// * [UrlFilter](struct.UrlFilter.html): URLs and email addresses.
+/// * [RegexFilter](struct.RegexFilter.html): The matches of a list of regular expressions, with the `regex` feature.
+///
+/// Any closure that takes the text and returns the byte ranges to skip is a filter too.
+///
+/// # Example
+/// ```
+/// use correct_word::document::{DocumentCorrector, MarkdownFilter};
+/// use correct_word::Dictionary;
+///
+/// let dictionary = Dictionary::from_iter(["call", "the", "function", "to", "print", "it"]);
+/// let text = "Call the `prnt` funtion to print it.\n\n```\nprnt(it)\n```\n";
+/// let corrector = DocumentCorrector::new(dictionary).filter(MarkdownFilter);
+/// assert_eq!(corrector.correct(text), "Call the `prnt` function to print it.\n\n```\nprnt(it)\n```\n");
+///
+/// // Skips every word in parentheses
+/// let corrector = corrector.filter(|text: &str| {
+///     text.match_indices('(').filter_map(|(start, _)| Some(start..start + text[start..].find(')')?)).collect()
+/// });
+/// assert_eq!(corrector.correct("Call the funtion (funtion)"), "Call the function (funtion)");
+/// ```
+pub trait SpanFilter {
+    /// The byte ranges of the text to skip, in any order. They can overlap.
+    fn skipped(&self, text: &str) -> Vec<Range<usize>>;
+}
+
+impl<F: Fn(&str) -> Vec<Range<usize>>> SpanFilter for F {
+    fn skipped(&self, text: &str) -> Vec<Range<usize>> {
+        self(text)
+    }
+}
+
+/// # Struct: MarkdownFilter
+/// A [SpanFilter](trait.SpanFilter.html) that skips the code of a Markdown text, and the destinations of its links:
+/// * Fenced code blocks, from a line starting with three or more backticks or tildes to the line that closes them,
+///   or to the end of the text.
+/// * Inline code, between two runs of as many backticks.
+/// * The destination of a link or an image, like the URL in `[text](https://example.com)`.
+///   The text of the link is still checked.
+///
+/// Indented code blocks aren't skipped, since an indented line can just as well be the paragraph of a list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MarkdownFilter;
+
+impl SpanFilter for MarkdownFilter {
+    fn skipped(&self, text: &str) -> Vec<Range<usize>> {
+        let mut skipped = Vec::new();
+        // The start of the text outside of a fenced block that hasn't been searched for inline code yet
+        let mut prose = 0;
+        let mut fence: Option<(usize, u8, usize)> = None;
+        let mut start = 0;
+        for line in text.split_inclusive('\n') {
+            let end = start + line.len();
+            let indent = line.len() - line.trim_start_matches(' ').len();
+            let marker = &line.as_bytes()[indent..];
+            let run = |c: u8| marker.iter().take_while(|&&b| b == c).count();
+            let closes = |c: u8, length: usize| {
+                indent < 4 && run(c) >= length && line[indent + run(c)..].trim().is_empty()
+            };
+            match fence {
+                Some((open, c, length)) if closes(c, length) => {
+                    skipped.push(open..end);
+                    fence = None;
+                    prose = end;
+                }
+                Some(_) => {}
+                None if indent < 4 => {
+                    for c in [b'`', b'~'] {
+                        let length = run(c);
+                        // A backtick fence can't have backticks after it, or it is inline code
+                        if length >= 3 && (c == b'~' || !line[indent + length..].contains('`')) {
+                            inline(text, prose..start, &mut skipped);
+                            fence = Some((start, c, length));
+                        }
+                    }
+                }
+                None => {}
+            }
+            start = end;
+        }
+        match fence {
+            Some((open, ..)) => skipped.push(open..text.len()),
+            None => inline(text, prose..text.len(), &mut skipped),
+        }
+        skipped
+    }
+}
+
+/// Adds the inline code and the link destinations of a part of a text outside of code blocks.
+fn inline(text: &str, range: Range<usize>, skipped: &mut Vec<Range<usize>>) {
+    let bytes = text.as_bytes();
+    let mut i = range.start;
+    while i < range.end {
+        if bytes[i] == b'`' {
+            let length = bytes[i..range.end]
+                .iter()
+                .take_while(|&&b| b == b'`')
+                .count();
+            let run = |j: usize| {
+                bytes[j..range.end]
+                    .iter()
+                    .take_while(|&&b| b == b'`')
+                    .count()
+            };
+            let closing =
+                (i + length..range.end).find(|&j| bytes[j - 1] != b'`' && run(j) == length);
+            match closing {
+                Some(j) => {
+                    skipped.push(i..j + length);
+                    i = j + length;
+                }
+                // A run of backticks without a closing run is just backticks
+                None => i += length,
+            }
+        } else if bytes[i..range.end].starts_with(b"](") {
+            let destination = i + 2;
+            match text[destination..range.end].find([')', '\n']) {
+                Some(end) if text.as_bytes()[destination + end] == b')' => {
+                    skipped.push(destination..destination + end);
+                    i = destination + end;
+                }
+                _ => i = destination,
+            }
+        } else {
+            i += 1;
+        }
+    }
+}
+
+/// # Struct: UrlFilter
+/// A [SpanFilter](trait.SpanFilter.html) that skips URLs and email addresses, which are never words of a dictionary:
+/// * A URL starts with a scheme, like `https://` or `ftp://`, or with `www.`, and goes on to the next whitespace,
+///   quote or angle bracket, without the punctuation that ends a sentence after it.
+/// * An email address is made of the letters, digits and `._%+-` before an `@`,
+///   and of a domain with a dot in it after it.
+///
+/// # Example
+/// ```
+/// use correct_word::document::{SpanFilter, UrlFilter};
+///
+/// let text = "Mail jo.doe@example.com or see https://example.com/dcos.";
+/// let skipped: Vec<&str> = UrlFilter.skipped(text).into_iter().map(|span| &text[span]).collect();
+/// assert_eq!(skipped, ["https://example.com/dcos", "jo.doe@example.com"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UrlFilter;
+
+impl SpanFilter for UrlFilter {
+    fn skipped(&self, text: &str) -> Vec<Range<usize>> {
+        let bytes = text.as_bytes();
+        let mut skipped = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let starts_word = i == 0 || !bytes[i - 1].is_ascii_alphanumeric();
+            if !starts_word || !bytes[i].is_ascii_alphabetic() {
+                i += 1;
+                continue;
+            }
+            let scheme = bytes[i..]
+                .iter()
+                .take_while(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'.' | b'-'))
+                .count();
+            let is_url = bytes[i + scheme..].starts_with(b"://")
+                || bytes[i..]
+                    .get(..4)
+                    .is_some_and(|w| w.eq_ignore_ascii_case(b"www."));
+            if !is_url {
+                i += scheme;
+                continue;
+            }
+            let mut end = i + bytes[i..]
+                .iter()
+                .take_while(|b| {
+                    !b.is_ascii_whitespace() && !matches!(b, b'"' | b'\'' | b'<' | b'>' | b'`')
+                })
+                .count();
+            while end > i
+                && matches!(
+                    bytes[end - 1],
+                    b'.' | b',' | b';' | b':' | b'!' | b'?' | b')'
+                )
+            {
+                end -= 1;
+            }
+            skipped.push(i..end);
+            i = end.max(i + 1);
+        }
+
+        let local =
+            |b: &u8| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'%' | b'+' | b'-');
+        let domain = |b: &u8| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-');
+        for (at, _) in text.match_indices('@') {
+            let start = at - bytes[..at].iter().rev().take_while(|b| local(b)).count();
+            let mut end = at + 1 + bytes[at + 1..].iter().take_while(|b| domain(b)).count();
+            while end > at + 1 && matches!(bytes[end - 1], b'.' | b'-') {
+                end -= 1;
+            }
+            if start < at && text[at + 1..end].contains('.') {
+                skipped.push(start..end);
+            }
+        }
+        skipped
+    }
+}
+
+/// # Struct: RegexFilter
+/// A [SpanFilter](trait.SpanFilter.html) that skips the matches of a list of regular expressions,
+/// for anything the other filters don't know about, like ticket numbers, hashes or template placeholders.
+///
+/// # Example
+/// ```
+/// use correct_word::document::{DocumentCorrector, RegexFilter};
+/// use correct_word::Dictionary;
+///
+/// let dictionary = Dictionary::from_iter(["hello", "world", "project"]);
+/// let filter = RegexFilter::new([r"\{\{[^}]*\}\}", r"\b[A-Z]+-\d+\b"]).unwrap();
+/// let corrector = DocumentCorrector::new(dictionary).filter(filter);
+/// assert_eq!(corrector.correct("helo {{wrold}}, wrold PROJ-42"), "hello {{wrold}}, world PROJ-42");
+/// ```
+#[cfg(feature = "regex")]
+#[derive(Debug, Clone)]
+pub struct RegexFilter {
+    patterns: Vec<regex::Regex>,
+}
+
+#[cfg(feature = "regex")]
+impl RegexFilter {
+    /// Creates a filter for the matches of every pattern, or the error of the first pattern that isn't a valid regular expression.
+    pub fn new<I: IntoIterator<Item = P>, P: AsRef<str>>(
+        patterns: I,
+    ) -> Result<Self, regex::Error> {
+        let patterns = patterns
+            .into_iter()
+            .map(|pattern| regex::Regex::new(pattern.as_ref()))
+            .collect::<Result<_, _>>()?;
+        Ok(RegexFilter { patterns })
+    }
+}
+
+#[cfg(feature = "regex")]
+impl SpanFilter for RegexFilter {
+    fn skipped(&self, text: &str) -> Vec<Range<usize>> {
+        self.patterns
+            .iter()
+            .flat_map(|pattern| pattern.find_iter(text).map(|found| found.range()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn skipped(filter: impl SpanFilter, text: &str) -> Vec<&str> {
+        filter
+            .skipped(text)
+            .into_iter()
+            .map(|span| &text[span])
+            .collect()
+    }
+
+    #[test]
+    fn markdown_code_is_skipped() {
+        let text = "a `b` ``c ` d`` e ```f\n~~~rust\ng\n~~~~\nh [i](j.md) `k\n````\nl\n```\nm\n";
+        assert_eq!(
+            skipped(MarkdownFilter, text),
+            [
+                "`b`",
+                "``c ` d``",
+                "~~~rust\ng\n~~~~\n",
+                "j.md",
+                "````\nl\n```\nm\n"
+            ]
+        );
+    }
+
+    #[test]
+    fn urls_and_emails_are_skipped() {
+        let text = "(see http://a.b/c?d=e), www.f.g! h@i j@k.l. mailto:m.n@o-p.q";
+        assert_eq!(
+            skipped(UrlFilter, text),
+            ["http://a.b/c?d=e", "www.f.g", "j@k.l", "m.n@o-p.q"]
+        );
+    }
+}
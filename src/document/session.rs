@@ -0,0 +1,161 @@
+use std::ops::Range;
+
+use super::{DocumentCorrector, Token};
+use crate::{Algorithm, CorrectWord, Scorer};
+
+/// # Enum: Decision
+/// What the user of a [CorrectionSession](struct.CorrectionSession.html) decided to do with a flagged word.
+///
+/// * Accept: Replaces the word with its best suggestion. Without suggestions, it is the same as Reject.
+/// * Reject: Keeps the word as it is, here only. The next time it is in the text, it is flagged again.
+/// * Replace: Replaces the word with the given text, like a suggestion other than the best one, or the user's own fix.
+/// * IgnoreAll: Keeps the word as it is, and doesn't flag it again for the rest of the session,
+///   with [SpellChecker::ignore_word](../struct.SpellChecker.html#method.ignore_word).
+/// * AddToDictionary: Keeps the word as it is, and adds it to the user dictionary,
+///   with [SpellChecker::add_word](../struct.SpellChecker.html#method.add_word), so it is never flagged again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Decision {
+    Accept,
+    Reject,
+    Replace(String),
+    IgnoreAll,
+    AddToDictionary,
+}
+
+/// # Struct: Flagged
+/// A misspelled word a [CorrectionSession](struct.CorrectionSession.html) waits for a decision on,
+/// with the byte range it is at in the original text, and its suggestions, best first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Flagged {
+    pub span: Range<usize>,
+    pub word: String,
+    pub suggestions: Vec<CorrectWord>,
+}
+
+/// # Struct: CorrectionSession
+/// An interactive correction of a text, one misspelled word at a time, for the "Change", "Ignore" and "Add to dictionary"
+/// buttons of a spell checking dialog, started with [DocumentCorrector::session](struct.DocumentCorrector.html#method.session).
+///
+/// [next_flagged](#method.next_flagged) finds the next misspelled word, and [decide](#method.decide) applies a
+/// [Decision](enum.Decision.html) to it, building the corrected text as it goes.
+/// The words are checked when they are reached, not when the session starts, so a word added to the dictionary
+/// or ignored isn't flagged again further down. The words added to the dictionary stay in the
+/// [SpellChecker](../struct.SpellChecker.html) of the corrector once the session is over.
+///
+/// # Example
+/// ```
+/// use correct_word::document::{Decision, DocumentCorrector};
+/// use correct_word::Dictionary;
+///
+/// let dictionary = Dictionary::from_iter(["the", "cat", "sat", "on", "a", "mat"]);
+/// let mut corrector = DocumentCorrector::new(dictionary);
+/// let mut session = corrector.session("thw cat sat on thw matt", 3);
+///
+/// let flagged = session.next_flagged().unwrap();
+/// assert_eq!((flagged.word.as_str(), flagged.span), ("thw", 0..3));
+/// assert_eq!(flagged.suggestions[0].word.as_deref(), Some("the"));
+/// session.decide(Decision::Accept);
+///
+/// assert_eq!(session.next_flagged().unwrap().word, "thw");
+/// session.decide(Decision::Replace("a".to_string()));
+///
+/// assert_eq!(session.next_flagged().unwrap().word, "matt");
+/// session.decide(Decision::AddToDictionary);
+///
+/// assert_eq!(session.next_flagged(), None);
+/// assert_eq!(session.finish(), "the cat sat on a matt");
+/// assert!(corrector.checker().is_correct("matt"));
+/// ```
+pub struct CorrectionSession<'a, S: Scorer = Algorithm> {
+    corrector: &'a mut DocumentCorrector<S>,
+    text: &'a str,
+    tokens: Vec<Token<'a>>,
+    suggestions: usize,
+    /// The index of the next token to check
+    next: usize,
+    pending: Option<Flagged>,
+    output: String,
+    /// The end of the part of the text that is in the output already
+    end: usize,
+}
+
+impl<S: Scorer> DocumentCorrector<S> {
+    /// Starts an interactive correction of a text, with up to `suggestions` suggestions for each misspelled word.
+    /// See [CorrectionSession](struct.CorrectionSession.html).
+    pub fn session<'a>(
+        &'a mut self,
+        text: &'a str,
+        suggestions: usize,
+    ) -> CorrectionSession<'a, S> {
+        CorrectionSession {
+            tokens: self.checked(text),
+            corrector: self,
+            text,
+            suggestions,
+            next: 0,
+            pending: None,
+            output: String::with_capacity(text.len()),
+            end: 0,
+        }
+    }
+}
+
+impl<S: Scorer> CorrectionSession<'_, S> {
+    /// The next misspelled word, or None when the rest of the text is correct.
+    /// Until there is a [decision](#method.decide) on it, it is the same word every time.
+    pub fn next_flagged(&mut self) -> Option<Flagged> {
+        if self.pending.is_none() {
+            while let Some(token) = self.tokens.get(self.next) {
+                self.next += 1;
+                if !self.corrector.is_correct(token.text) {
+                    let checker = self.corrector.checker();
+                    self.pending = Some(Flagged {
+                        span: token.span.clone(),
+                        word: token.text.to_string(),
+                        suggestions: checker.suggest(token.text, self.suggestions),
+                    });
+                    break;
+                }
+            }
+        }
+        self.pending.clone()
+    }
+
+    /// Applies a decision to the word [next_flagged](#method.next_flagged) returned.
+    /// Returns false, without doing anything, if there is no such word.
+    pub fn decide(&mut self, decision: Decision) -> bool {
+        let Some(flagged) = self.pending.take() else {
+            return false;
+        };
+        let replacement = match decision {
+            Decision::Accept => flagged
+                .suggestions
+                .into_iter()
+                .next()
+                .and_then(|suggestion| suggestion.word),
+            Decision::Replace(replacement) => Some(replacement),
+            Decision::Reject => None,
+            Decision::IgnoreAll => {
+                self.corrector.checker_mut().ignore_word(flagged.word);
+                None
+            }
+            Decision::AddToDictionary => {
+                self.corrector.checker_mut().add_word(flagged.word);
+                None
+            }
+        };
+        if let Some(replacement) = replacement {
+            self.output
+                .push_str(&self.text[self.end..flagged.span.start]);
+            self.output.push_str(&replacement);
+            self.end = flagged.span.end;
+        }
+        true
+    }
+
+    /// The corrected text, with the decisions so far. The words still to be decided on are left as they are.
+    pub fn finish(mut self) -> String {
+        self.output.push_str(&self.text[self.end..]);
+        self.output
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{Algorithm, Corrector, Normalizer};
+
+/// The domains of common email providers, for [correct_email_domain](fn.correct_email_domain.html).
+/// Add the domains your users have to them, or use your own list.
+pub const COMMON_DOMAINS: &[&str] = &[
+    "gmail.com",
+    "googlemail.com",
+    "yahoo.com",
+    "yahoo.co.uk",
+    "yahoo.fr",
+    "hotmail.com",
+    "hotmail.co.uk",
+    "hotmail.fr",
+    "outlook.com",
+    "live.com",
+    "msn.com",
+    "icloud.com",
+    "me.com",
+    "mac.com",
+    "aol.com",
+    "protonmail.com",
+    "proton.me",
+    "zoho.com",
+    "gmx.com",
+    "gmx.de",
+    "web.de",
+    "mail.com",
+    "yandex.ru",
+    "mail.ru",
+    "qq.com",
+    "163.com",
+    "orange.fr",
+    "free.fr",
+    "libero.it",
+    "comcast.net",
+    "verizon.net",
+    "att.net",
+    "btinternet.com",
+];
+
+/// Corrects the domain of an email address, like "gmial.com" to "gmail.com", leaving the part before the `@` as it is.
+/// It is meant to suggest a fix in a signup form, where a wrong correction is worse than none, so it is conservative:
+/// * A domain that is one of the known domains, in any case, is never corrected.
+/// * The domain is compared to the known domains with [Algorithm::Osa](../enum.Algorithm.html), so a swap of two letters
+///   is one edit, and needs a similarity of 0.85: one edit in a domain of 7 characters or more, or two in one of 14 or more.
+///   A short domain one edit away from a known one, like "xe.com" from "me.com", can just as well be a real domain.
+/// * When two known domains are as close to the domain, neither is suggested.
+///
+/// # Arguments
+///
+/// * `address` - The email address to correct.
+/// * `known_domains` - The domains to correct to, like [COMMON_DOMAINS](constant.COMMON_DOMAINS.html).
+///
+/// # Returns
+///
+/// `Option<String>` - The address with the corrected domain, or None if the domain is known, has no correction,
+/// or the address has no `@`.
+///
+/// # Example
+/// ```
+/// use correct_word::email::{correct_email_domain, COMMON_DOMAINS};
+///
+/// assert_eq!(correct_email_domain("jane.doe@gmial.com", COMMON_DOMAINS).as_deref(), Some("jane.doe@gmail.com"));
+/// assert_eq!(correct_email_domain("Jane@Hotmal.com", COMMON_DOMAINS).as_deref(), Some("Jane@hotmail.com"));
+/// assert_eq!(correct_email_domain("jane@GMAIL.COM", COMMON_DOMAINS), None);
+/// assert_eq!(correct_email_domain("jane@xe.com", COMMON_DOMAINS), None);
+/// assert_eq!(correct_email_domain("jane@example.org", COMMON_DOMAINS), None);
+/// ```
+pub fn correct_email_domain<S: AsRef<str>>(address: &str, known_domains: &[S]) -> Option<String> {
+    let (local, domain) = address.rsplit_once('@')?;
+    if local.is_empty() || domain.is_empty() {
+        return None;
+    }
+    let lowercase = domain.to_lowercase();
+    if known_domains
+        .iter()
+        .any(|known| known.as_ref().to_lowercase() == lowercase)
+    {
+        return None;
+    }
+    let corrector = Corrector::new(Algorithm::Osa)
+        .threshold(0.85)
+        .normalizer(Normalizer::new().lowercase());
+    let corrections = corrector.correct_n(domain, known_domains, 2);
+    match corrections.as_slice() {
+        [best, rest @ ..] if rest.iter().all(|other| other.confidence < best.confidence) => {
+            Some(format!("{local}@{}", best.word.as_deref()?))
+        }
+        _ => None,
+    }
+}
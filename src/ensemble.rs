@@ -0,0 +1,239 @@
+use crate::{Algorithm, Granularity, Scorer, Threshold};
+
+/// # Enum: Combination
+/// Decides how an [Ensemble](struct.Ensemble.html) turns the similarities of its scorers into one.
+///
+/// * Mean: The weighted mean of the similarities. This is the default.
+/// * Max: The highest similarity, so an option only has to be close by one of the scorers. The weights are ignored.
+/// * Min: The lowest similarity, so an option has to be close by all of the scorers. The weights are ignored.
+/// * Vote: The share of the weight of the scorers whose similarity is at least the given one.
+///   Many options end up with the same share, and the [tie break](../struct.Corrector.html#method.tie_break) decides between them.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Combination {
+    #[default]
+    Mean,
+    Max,
+    Min,
+    Vote(f64),
+}
+
+/// # Struct: Ensemble
+/// A [Scorer](../trait.Scorer.html) that blends the similarities of several algorithms, or other scorers,
+/// which is usually more robust than any one of them: Lcs forgives missing characters, while Damerau forgives swapped ones.
+///
+/// Every scorer has a weight, and by default the similarity is their weighted mean,
+/// renormalized by the total weight, so the weights don't have to add up to 1.
+/// See [Combination](enum.Combination.html) for the other ways to combine them.
+/// The distance is combined the same way, as the rounded weighted mean by default,
+/// the lowest distance for `Max` and the highest for `Min`.
+///
+/// An ensemble without scorers, or whose weights are all 0, only finds equal strings similar.
+///
+/// # Example
+/// ```
+/// use correct_word::ensemble::{Combination, Ensemble};
+/// use correct_word::{Algorithm, Corrector, Scorer};
+///
+/// let ensemble = Ensemble::new()
+///     .with(Algorithm::DamerauLevenshtein, 0.6)
+///     .with(Algorithm::Lcs, 0.4);
+/// let damerau = Algorithm::DamerauLevenshtein.similarity("hlelo", "hello");
+/// let lcs = Algorithm::Lcs.similarity("hlelo", "hello");
+/// assert!((ensemble.similarity("hlelo", "hello") - (0.6 * damerau + 0.4 * lcs)).abs() < 1e-9);
+///
+/// let corrector = Corrector::new(ensemble);
+/// assert_eq!(corrector.correct("hlelo", &["halo", "hello"]).word.unwrap(), "hello");
+///
+/// let strict = Ensemble::from_iter([(Algorithm::Levenshtein, 1.0), (Algorithm::Cosine, 1.0)]).combination(Combination::Min);
+/// assert_eq!(strict.similarity("hlelo", "hello"), Algorithm::Cosine.similarity("hlelo", "hello"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ensemble<S: Scorer = Algorithm> {
+    scorers: Vec<(S, f64)>,
+    combination: Combination,
+}
+
+impl<S: Scorer> Default for Ensemble<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Scorer> Ensemble<S> {
+    /// Creates an ensemble without scorers, which takes the weighted mean of their similarities.
+    pub fn new() -> Self {
+        Ensemble {
+            scorers: Vec::new(),
+            combination: Combination::default(),
+        }
+    }
+
+    /// Adds a scorer with the given weight. A negative weight is 0, and a scorer with a weight of 0 is left out of every combination.
+    pub fn with(mut self, scorer: S, weight: f64) -> Self {
+        self.scorers.push((scorer, weight.max(0.0)));
+        self
+    }
+
+    /// Sets how the similarities are combined. By default, with their weighted mean.
+    pub fn combination(mut self, combination: Combination) -> Self {
+        self.combination = combination;
+        self
+    }
+
+    /// The scorers and their weights, in the order they were added.
+    pub fn scorers(&self) -> &[(S, f64)] {
+        &self.scorers
+    }
+
+    /// The scorers with a weight above 0, which are the only ones that count.
+    fn weighted(&self) -> impl Iterator<Item = (&S, f64)> {
+        self.scorers
+            .iter()
+            .filter(|(_, weight)| *weight > 0.0)
+            .map(|(scorer, weight)| (scorer, *weight))
+    }
+
+    /// Combines the similarities of the scorers.
+    fn combine(&self, a: &str, b: &str, similarity: impl Fn(&S) -> f64) -> f64 {
+        let total: f64 = self.scorers.iter().map(|(_, weight)| weight).sum();
+        if total <= 0.0 {
+            return if a == b { 1.0 } else { 0.0 };
+        }
+        // A NaN from a custom scorer counts as 0, like it does for a Corrector
+        let similarities = self.weighted().map(|(scorer, weight)| {
+            let similarity = similarity(scorer);
+            let similarity = if similarity.is_nan() { 0.0 } else { similarity };
+            (similarity, weight)
+        });
+        match self.combination {
+            Combination::Mean => {
+                similarities
+                    .map(|(similarity, weight)| similarity * weight)
+                    .sum::<f64>()
+                    / total
+            }
+            Combination::Max => similarities
+                .map(|(similarity, _)| similarity)
+                .fold(0.0, f64::max),
+            Combination::Min => similarities
+                .map(|(similarity, _)| similarity)
+                .fold(1.0, f64::min),
+            Combination::Vote(cutoff) => {
+                let votes: f64 = similarities
+                    .filter(|&(similarity, _)| similarity >= cutoff)
+                    .map(|(_, weight)| weight)
+                    .sum();
+                votes / total
+            }
+        }
+    }
+
+    /// Combines the distances of the scorers.
+    fn combine_distances(&self, a: &str, b: &str, distance: impl Fn(&S) -> u16) -> u16 {
+        let total: f64 = self.scorers.iter().map(|(_, weight)| weight).sum();
+        if total <= 0.0 {
+            return if a == b { 0 } else { u16::MAX };
+        }
+        let distances = self
+            .weighted()
+            .map(|(scorer, weight)| (distance(scorer), weight));
+        match self.combination {
+            Combination::Mean | Combination::Vote(_) => {
+                let mean = distances
+                    .map(|(distance, weight)| distance as f64 * weight)
+                    .sum::<f64>()
+                    / total;
+                mean.round() as u16
+            }
+            Combination::Max => distances.map(|(distance, _)| distance).min().unwrap_or(0),
+            Combination::Min => distances.map(|(distance, _)| distance).max().unwrap_or(0),
+        }
+    }
+}
+
+impl<S: Scorer> Scorer for Ensemble<S> {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        self.combine(a, b, |scorer| scorer.similarity(a, b))
+    }
+
+    fn distance(&self, a: &str, b: &str) -> u16 {
+        self.combine_distances(a, b, |scorer| scorer.distance(a, b))
+    }
+
+    fn similarity_with(&self, a: &str, b: &str, granularity: Granularity) -> f64 {
+        self.combine(a, b, |scorer| scorer.similarity_with(a, b, granularity))
+    }
+
+    fn distance_with(&self, a: &str, b: &str, granularity: Granularity) -> u16 {
+        self.combine_distances(a, b, |scorer| scorer.distance_with(a, b, granularity))
+    }
+
+    /// Only known for `Max`, where one of the scorers has to clear the threshold,
+    /// and for `Min`, where all of them have to.
+    fn max_levenshtein(&self, input: &str, threshold: Threshold) -> Option<u16> {
+        let mut bounds = self
+            .weighted()
+            .map(|(scorer, _)| scorer.max_levenshtein(input, threshold));
+        match self.combination {
+            Combination::Max => bounds.try_fold(0, |max, bound| Some(max.max(bound?))),
+            Combination::Min => bounds.flatten().min(),
+            Combination::Mean | Combination::Vote(_) => None,
+        }
+    }
+}
+
+impl<S: Scorer> FromIterator<(S, f64)> for Ensemble<S> {
+    fn from_iter<I: IntoIterator<Item = (S, f64)>>(scorers: I) -> Self {
+        scorers
+            .into_iter()
+            .fold(Ensemble::new(), |ensemble, (scorer, weight)| {
+                ensemble.with(scorer, weight)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edge_cases_follow_the_scorer_rules() {
+        let empty: Ensemble = Ensemble::new();
+        assert_eq!(empty.similarity("abc", "abc"), 1.0);
+        assert_eq!(empty.similarity("abc", "abd"), 0.0);
+        assert_eq!(empty.distance("abc", "abc"), 0);
+
+        // A scorer with no weight doesn't loosen the bound of the others
+        let nan = |_: &str, _: &str| f64::NAN;
+        let ensemble = Ensemble::new()
+            .with(Box::new(nan) as Box<dyn Fn(&str, &str) -> f64>, 0.0)
+            .with(Box::new(|a: &str, b: &str| (a == b) as u8 as f64), 1.0)
+            .combination(Combination::Max);
+        assert_eq!(ensemble.similarity("abc", "abc"), 1.0);
+
+        let ensemble = Ensemble::new()
+            .with(Algorithm::Levenshtein, 1.0)
+            .with(Algorithm::Lcs, 1.0);
+        let threshold = Threshold::Similarity(0.5);
+        assert_eq!(ensemble.max_levenshtein("hello", threshold), None);
+        // Lcs lets an option be twice as far away as Levenshtein does
+        let max = ensemble.clone().combination(Combination::Max);
+        assert_eq!(max.max_levenshtein("hello", threshold), Some(10));
+        let min = ensemble.combination(Combination::Min);
+        assert_eq!(min.max_levenshtein("hello", threshold), Some(5));
+        assert_eq!(
+            Ensemble::from_iter([(Algorithm::Levenshtein, 1.0), (Algorithm::Cosine, 1.0)])
+                .combination(Combination::Max)
+                .max_levenshtein("hello", threshold),
+            None
+        );
+        assert_eq!(
+            Ensemble::new()
+                .with(Algorithm::Levenshtein, 1.0)
+                .with(Algorithm::Cosine, 0.0)
+                .combination(Combination::Max)
+                .max_levenshtein("hello", threshold),
+            Some(5)
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use std::fmt;
+
+/// # Enum: Correction Error
+/// The ways a correction can fail, as returned by [try_correct_word](fn.try_correct_word.html).
+///
+/// * EmptyOptions: There are no options to correct the word to.
+/// * EmptyInput: The word to correct is an empty string.
+/// * InvalidThreshold: The threshold is not a similarity between 0 and 1. The invalid value is included.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CorrectionError {
+    EmptyOptions,
+    EmptyInput,
+    InvalidThreshold(f64),
+}
+
+impl fmt::Display for CorrectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CorrectionError::EmptyOptions => {
+                write!(f, "there are no options to correct the word to")
+            }
+            CorrectionError::EmptyInput => write!(f, "the word to correct is empty"),
+            CorrectionError::InvalidThreshold(threshold) => {
+                write!(f, "the threshold {threshold} is not between 0 and 1")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CorrectionError {}
+
+/// # Struct: Unknown Algorithm
+/// The error returned when parsing an [Algorithm](enum.Algorithm.html) from a name that isn't one.
+/// It holds the name that couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownAlgorithm(pub String);
+
+impl fmt::Display for UnknownAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown algorithm {}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownAlgorithm {}
+
+/// # Struct: Cancelled
+/// The error returned by a cancellable lookup, like [Corrector::correct_n_cancellable](struct.Corrector.html#method.correct_n_cancellable),
+/// when its flag was set before it was done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the lookup was cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
@@ -0,0 +1,247 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use crate::{CandidateSource, Corrector, Scorer};
+
+/// # Struct: Misspelling
+/// A misspelled word of a [Corpus](struct.Corpus.html) and the words it may have been meant as.
+/// Most misspellings have one correction, but some, like "wich", could be several words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Misspelling {
+    pub misspelling: String,
+    pub corrections: Vec<String>,
+}
+
+/// # Struct: Corpus
+/// A list of real misspellings and their corrections, to measure how well a [Corrector](../struct.Corrector.html) fixes them
+/// with [evaluate](fn.evaluate.html).
+///
+/// The crate embeds a [sample](#method.sample) of common English misspellings, and reads the two usual formats of the full corpora:
+/// * [Wikipedia's list of common misspellings](https://en.wikipedia.org/wiki/Wikipedia:Lists_of_common_misspellings/For_machines),
+///   with one `misspelling->correction` per line, and several corrections separated by commas.
+///   Read it with [from_wikipedia](#method.from_wikipedia).
+/// * The [Birkbeck spelling error corpus](https://www.dcs.bbk.ac.uk/~ROGER/corpora.html), where a `$correction` line
+///   is followed by the misspellings of that word, one per line, with `_` for spaces.
+///   Read it with [from_birkbeck](#method.from_birkbeck).
+///
+/// Blank lines and lines that don't fit the format are skipped.
+///
+/// # Example
+/// ```
+/// use correct_word::eval::Corpus;
+///
+/// let corpus = Corpus::from_wikipedia("recieve->receive\nwich->which, witch\n".as_bytes()).unwrap();
+/// assert_eq!(corpus.len(), 2);
+/// assert_eq!(corpus.cases()[1].corrections, ["which", "witch"]);
+///
+/// let corpus = Corpus::from_birkbeck("$receive\nrecieve\nreceve\n$a_lot\nalot\n".as_bytes()).unwrap();
+/// assert_eq!(corpus.cases()[2].misspelling, "alot");
+/// assert_eq!(corpus.cases()[2].corrections, ["a lot"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Corpus {
+    cases: Vec<Misspelling>,
+}
+
+impl Corpus {
+    /// A few dozen of the most common English misspellings, from Wikipedia's list, embedded in the crate.
+    /// It is small enough for a quick check, but use a full corpus to compare algorithms or thresholds.
+    pub fn sample() -> Self {
+        Self::from_wikipedia(include_str!("eval/sample.txt").as_bytes())
+            .expect("the sample corpus is valid")
+    }
+
+    /// Reads a corpus in the format of Wikipedia's list of common misspellings, with one `misspelling->correction` per line.
+    pub fn from_wikipedia<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut cases = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            let Some((misspelling, corrections)) = line.split_once("->") else {
+                continue;
+            };
+            let misspelling = misspelling.trim();
+            let corrections: Vec<String> = corrections
+                .split(',')
+                .map(str::trim)
+                .filter(|correction| !correction.is_empty())
+                .map(str::to_string)
+                .collect();
+            if !misspelling.is_empty() && !corrections.is_empty() {
+                cases.push(Misspelling {
+                    misspelling: misspelling.to_string(),
+                    corrections,
+                });
+            }
+        }
+        Ok(Corpus { cases })
+    }
+
+    /// Reads a corpus in the format of the Birkbeck corpus, where a `$correction` line is followed by its misspellings.
+    pub fn from_birkbeck<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut cases = Vec::new();
+        let mut correction: Option<String> = None;
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if let Some(word) = line.strip_prefix('$') {
+                correction = Some(word.replace('_', " "));
+            } else if let (Some(correction), false) = (&correction, line.is_empty()) {
+                cases.push(Misspelling {
+                    misspelling: line.replace('_', " "),
+                    corrections: vec![correction.clone()],
+                });
+            }
+        }
+        Ok(Corpus { cases })
+    }
+
+    /// Reads a corpus from a file in the format of Wikipedia's list, like [from_wikipedia](#method.from_wikipedia).
+    pub fn from_wikipedia_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_wikipedia(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads a corpus from a file in the format of the Birkbeck corpus, like [from_birkbeck](#method.from_birkbeck).
+    pub fn from_birkbeck_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_birkbeck(BufReader::new(File::open(path)?))
+    }
+
+    /// The misspellings, in the order they were read.
+    pub fn cases(&self) -> &[Misspelling] {
+        &self.cases
+    }
+
+    /// The number of misspellings.
+    pub fn len(&self) -> usize {
+        self.cases.len()
+    }
+
+    /// Whether the corpus has no misspellings.
+    pub fn is_empty(&self) -> bool {
+        self.cases.is_empty()
+    }
+
+    /// Every correction of every misspelling, to make sure the dictionary under test has them.
+    /// A word that corrects several misspellings comes up several times.
+    pub fn corrections(&self) -> impl Iterator<Item = &str> {
+        self.cases
+            .iter()
+            .flat_map(|case| case.corrections.iter().map(String::as_str))
+    }
+}
+
+/// # Struct: Report
+/// How well a corrector did on a [Corpus](struct.Corpus.html), from [evaluate](fn.evaluate.html).
+///
+/// * `cases`: The number of misspellings.
+/// * `top_1`: The number of misspellings whose best suggestion was one of their corrections.
+/// * `top_n`: The number of misspellings with one of their corrections in the first `n` suggestions.
+/// * `n`: The number of suggestions that were looked at for `top_n`.
+///
+/// Its `Display` shows the accuracies, like `accuracy@1: 85.0%, accuracy@5: 95.0% (20 cases)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Report {
+    pub cases: usize,
+    pub top_1: usize,
+    pub top_n: usize,
+    pub n: usize,
+}
+
+impl Report {
+    /// The share of the misspellings that were fixed by the best suggestion, from 0 to 1. An empty corpus has an accuracy of 0.
+    pub fn accuracy_at_1(&self) -> f64 {
+        accuracy(self.top_1, self.cases)
+    }
+
+    /// The share of the misspellings with a correction in the first `n` suggestions, from 0 to 1.
+    pub fn accuracy_at_n(&self) -> f64 {
+        accuracy(self.top_n, self.cases)
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "accuracy@1: {:.1}%, accuracy@{}: {:.1}% ({} cases)",
+            self.accuracy_at_1() * 100.0,
+            self.n,
+            self.accuracy_at_n() * 100.0,
+            self.cases
+        )
+    }
+}
+
+fn accuracy(hits: usize, cases: usize) -> f64 {
+    if cases == 0 {
+        return 0.0;
+    }
+    hits as f64 / cases as f64
+}
+
+/// Corrects every misspelling of the corpus from the words of the source, and counts how often a correction was suggested,
+/// to pick the algorithm and the threshold on real data.
+///
+/// The words are looked up like [Corrector::correct_n_from](../struct.Corrector.html#method.correct_n_from).
+/// The source should be a realistic dictionary that has the corrections,
+/// since correcting from the corrections alone is much easier than from a whole language.
+///
+/// # Arguments
+///
+/// * `corrector` - The corrector to evaluate.
+/// * `corpus` - The misspellings and their corrections.
+/// * `source` - The words to correct the misspellings from, like a [Dictionary](../struct.Dictionary.html).
+/// * `n` - The number of suggestions to look at for the accuracy@N. A `n` of 0 is 1.
+///
+/// # Returns
+///
+/// `Report` - The number of misspellings fixed by the first suggestion and by the first `n`.
+///
+/// # Example
+/// ```
+/// use correct_word::eval::{evaluate, Corpus};
+/// use correct_word::{Algorithm, Corrector, Dictionary};
+///
+/// let corpus = Corpus::sample();
+/// let mut dictionary = Dictionary::from_iter(["accept", "believer", "calendars", "tongues", "seperately"]);
+/// dictionary.extend(corpus.corrections());
+///
+/// let report = evaluate(&Corrector::new(Algorithm::DamerauLevenshtein), &corpus, &dictionary, 3);
+/// assert_eq!(report.cases, corpus.len());
+/// assert!(report.accuracy_at_1() > 0.8);
+/// assert!(report.accuracy_at_n() >= report.accuracy_at_1());
+/// println!("{report}");
+/// ```
+pub fn evaluate<S: Scorer, C: CandidateSource + ?Sized>(
+    corrector: &Corrector<S>,
+    corpus: &Corpus,
+    source: &C,
+    n: usize,
+) -> Report {
+    let n = n.max(1);
+    let mut report = Report {
+        cases: corpus.len(),
+        top_1: 0,
+        top_n: 0,
+        n,
+    };
+    for case in corpus.cases() {
+        let suggestions = corrector.correct_n_from(&case.misspelling, source, n);
+        let position = suggestions.iter().position(|suggestion| {
+            suggestion
+                .word
+                .as_ref()
+                .is_some_and(|word| case.corrections.contains(word))
+        });
+        match position {
+            Some(0) => {
+                report.top_1 += 1;
+                report.top_n += 1;
+            }
+            Some(_) => report.top_n += 1,
+            None => {}
+        }
+    }
+    report
+}
@@ -0,0 +1,195 @@
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+
+use crate::{Algorithm, Corrector, Scorer};
+
+/// The Levenshtein algorithm.
+pub const CORRECT_WORD_LEVENSHTEIN: c_int = 0;
+/// The longest common subsequence algorithm.
+pub const CORRECT_WORD_LCS: c_int = 1;
+/// The Ratcliff/Obershelp algorithm, like Python's difflib.
+pub const CORRECT_WORD_RATCLIFF_OBERSHELP: c_int = 2;
+/// The partial similarity, for finding a word inside a longer string.
+pub const CORRECT_WORD_PARTIAL: c_int = 3;
+/// The optimal string alignment distance, Levenshtein with swaps of adjacent characters.
+pub const CORRECT_WORD_OSA: c_int = 4;
+/// The true Damerau-Levenshtein distance.
+pub const CORRECT_WORD_DAMERAU_LEVENSHTEIN: c_int = 5;
+/// Matches the input as an abbreviation of the option, like fuzzy finders do.
+pub const CORRECT_WORD_SUBSEQUENCE: c_int = 6;
+/// The cosine similarity of the character bigrams.
+pub const CORRECT_WORD_COSINE: c_int = 7;
+/// The Jaccard index of the sets of character bigrams.
+pub const CORRECT_WORD_JACCARD: c_int = 8;
+/// A linear time approximation of the edit distance.
+pub const CORRECT_WORD_SIFT4: c_int = 9;
+
+/// The result of `correct_word_correct`.
+///
+/// `word` is NULL when no option cleared the threshold, or when the arguments were invalid.
+/// Otherwise, it must be freed with `correct_word_free_string`.
+/// `confidence` is the similarity of the best option, from 0 to 1, or -1 when the arguments were invalid.
+#[repr(C)]
+pub struct CorrectWordResult {
+    pub word: *mut c_char,
+    pub confidence: f64,
+}
+
+/// Calculates the distance between two strings with the given algorithm.
+///
+/// Returns -1 when a string is NULL or not valid UTF-8, or the algorithm is unknown.
+///
+/// # Safety
+///
+/// `string1` and `string2` must be NULL or point to NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn correct_word_distance(
+    string1: *const c_char,
+    string2: *const c_char,
+    algorithm: c_int,
+) -> i32 {
+    match (to_str(string1), to_str(string2), to_algorithm(algorithm)) {
+        (Some(string1), Some(string2), Some(algorithm)) => {
+            algorithm.distance(string1, string2) as i32
+        }
+        _ => -1,
+    }
+}
+
+/// Calculates the similarity, from 0 to 1, between two strings with the given algorithm.
+///
+/// Returns -1 when a string is NULL or not valid UTF-8, or the algorithm is unknown.
+///
+/// # Safety
+///
+/// `string1` and `string2` must be NULL or point to NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn correct_word_similarity(
+    string1: *const c_char,
+    string2: *const c_char,
+    algorithm: c_int,
+) -> f64 {
+    match (to_str(string1), to_str(string2), to_algorithm(algorithm)) {
+        (Some(string1), Some(string2), Some(algorithm)) => algorithm.similarity(string1, string2),
+        _ => -1.0,
+    }
+}
+
+/// Corrects a word from an array of `options_len` options, returning the best option that clears the threshold.
+/// The threshold is the minimum similarity, from 0 to 1.
+///
+/// Options that are NULL or not valid UTF-8 are skipped.
+/// Invalid arguments give a result with a NULL word and a confidence of -1.
+///
+/// # Safety
+///
+/// `input` must be NULL or point to a NUL-terminated string.
+/// `options` must be NULL or point to `options_len` pointers, each NULL or pointing to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn correct_word_correct(
+    input: *const c_char,
+    options: *const *const c_char,
+    options_len: usize,
+    algorithm: c_int,
+    threshold: f64,
+) -> CorrectWordResult {
+    let invalid = CorrectWordResult {
+        word: ptr::null_mut(),
+        confidence: -1.0,
+    };
+    let (Some(input), Some(algorithm)) = (to_str(input), to_algorithm(algorithm)) else {
+        return invalid;
+    };
+    if options.is_null() && options_len > 0 {
+        return invalid;
+    }
+
+    let options: Vec<&str> = (0..options_len)
+        .filter_map(|i| to_str(*options.add(i)))
+        .collect();
+    let correction = Corrector::new(algorithm)
+        .threshold(threshold)
+        .correct(input, &options);
+
+    CorrectWordResult {
+        word: correction
+            .word
+            .and_then(|word| CString::new(word).ok())
+            .map_or(ptr::null_mut(), CString::into_raw),
+        confidence: correction.confidence,
+    }
+}
+
+/// Frees a word returned by this library. Passing NULL does nothing.
+///
+/// # Safety
+///
+/// `string` must be NULL or a word returned by this library that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn correct_word_free_string(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+unsafe fn to_str<'a>(string: *const c_char) -> Option<&'a str> {
+    if string.is_null() {
+        return None;
+    }
+    CStr::from_ptr(string).to_str().ok()
+}
+
+fn to_algorithm(algorithm: c_int) -> Option<Algorithm> {
+    match algorithm {
+        CORRECT_WORD_LEVENSHTEIN => Some(Algorithm::Levenshtein),
+        CORRECT_WORD_LCS => Some(Algorithm::Lcs),
+        CORRECT_WORD_RATCLIFF_OBERSHELP => Some(Algorithm::RatcliffObershelp),
+        CORRECT_WORD_PARTIAL => Some(Algorithm::Partial),
+        CORRECT_WORD_OSA => Some(Algorithm::Osa),
+        CORRECT_WORD_DAMERAU_LEVENSHTEIN => Some(Algorithm::DamerauLevenshtein),
+        CORRECT_WORD_SUBSEQUENCE => Some(Algorithm::Subsequence),
+        CORRECT_WORD_COSINE => Some(Algorithm::Cosine),
+        CORRECT_WORD_JACCARD => Some(Algorithm::Jaccard),
+        CORRECT_WORD_SIFT4 => Some(Algorithm::Sift4),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correct_and_free() {
+        let input = CString::new("helo").unwrap();
+        let options = [
+            CString::new("hello").unwrap(),
+            CString::new("world").unwrap(),
+        ];
+        let mut pointers: Vec<*const c_char> =
+            options.iter().map(|option| option.as_ptr()).collect();
+        pointers.push(ptr::null());
+
+        unsafe {
+            let result = correct_word_correct(
+                input.as_ptr(),
+                pointers.as_ptr(),
+                pointers.len(),
+                CORRECT_WORD_LEVENSHTEIN,
+                0.5,
+            );
+            assert_eq!(CStr::from_ptr(result.word).to_str(), Ok("hello"));
+            assert_eq!(result.confidence, 0.8);
+            correct_word_free_string(result.word);
+
+            let result =
+                correct_word_correct(ptr::null(), pointers.as_ptr(), 2, CORRECT_WORD_LCS, 0.5);
+            assert!(result.word.is_null());
+            assert_eq!(result.confidence, -1.0);
+            assert_eq!(
+                correct_word_distance(input.as_ptr(), input.as_ptr(), 42),
+                -1
+            );
+        }
+    }
+}
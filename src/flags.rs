@@ -0,0 +1,136 @@
+use std::fmt;
+
+use crate::{Algorithm, Corrector, Normalizer};
+
+/// # Struct: FlagSuggestion
+/// The known flag a mistyped command-line argument was meant to be, returned by [correct_flag](fn.correct_flag.html),
+/// with the value it was given, so the argument can be put back together.
+///
+/// * `flag`: The known flag, with its dashes, like "--format".
+/// * `value`: What was after the first `=` of the argument, as it was typed, or None if there was no `=`.
+/// * `confidence`: The similarity between the names of the flags, without their dashes, from 0 to 1.
+///
+/// It displays as the corrected argument, like "--format=json".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlagSuggestion<'a> {
+    pub flag: &'a str,
+    pub value: Option<&'a str>,
+    pub confidence: f64,
+}
+
+impl FlagSuggestion<'_> {
+    /// The corrected argument, the known flag followed by the value, like "--format=json".
+    pub fn argument(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for FlagSuggestion<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value {
+            Some(value) => write!(f, "{}={value}", self.flag),
+            None => f.write_str(self.flag),
+        }
+    }
+}
+
+/// Corrects a mistyped command-line flag, like "--fromat=json" to "--format=json", for a "did you mean" message.
+///
+/// Only the name of the flag is corrected: the argument is split on its first `=`, and the value is kept as it is,
+/// since it is usually a path or a free-form string that has nothing to do with the flags.
+/// The names are compared without their leading dashes with [Algorithm::Osa](../enum.Algorithm.html),
+/// so a swap of two letters is one edit, and "-verbose" is corrected to "--verbose".
+/// They need a similarity of 0.7, and when two known flags are as close, neither is suggested.
+/// A flag of one letter, like "-x", is never corrected, since every other letter is as far from it.
+///
+/// # Arguments
+///
+/// * `argument` - The argument, like "--fromat=json".
+/// * `known_flags` - The flags of the command, with their dashes, like "--format".
+///
+/// # Returns
+///
+/// `Option<FlagSuggestion>` - The flag the argument was meant to be, or None if it is a known flag,
+/// doesn't start with a dash, or isn't close enough to one.
+///
+/// # Example
+/// ```
+/// use correct_word::flags::correct_flag;
+///
+/// let flags = ["--format", "--force", "--verbose", "-o"];
+/// let suggestion = correct_flag("--fromat=a=b.json", &flags).unwrap();
+/// assert_eq!((suggestion.flag, suggestion.value), ("--format", Some("a=b.json")));
+/// assert_eq!(suggestion.to_string(), "--format=a=b.json");
+///
+/// assert_eq!(correct_flag("-verbos", &flags).unwrap().argument(), "--verbose");
+/// assert_eq!(correct_flag("--format=json", &flags), None);
+/// assert_eq!(correct_flag("-x", &flags), None);
+/// assert_eq!(correct_flag("fromat", &flags), None);
+/// ```
+pub fn correct_flag<'a, S: AsRef<str>>(
+    argument: &'a str,
+    known_flags: &'a [S],
+) -> Option<FlagSuggestion<'a>> {
+    let (name, value) = match argument.split_once('=') {
+        Some((name, value)) => (name, Some(value)),
+        None => (argument, None),
+    };
+    let bare = name.trim_start_matches('-');
+    if bare.len() == name.len()
+        || bare.chars().count() < 2
+        || known_flags.iter().any(|flag| flag.as_ref() == name)
+    {
+        return None;
+    }
+    let corrector = Corrector::new(Algorithm::Osa)
+        .threshold(0.7)
+        .normalizer(Normalizer::new().map(|flag: &str| flag.trim_start_matches('-').to_string()));
+    let mut best: Option<FlagSuggestion> = None;
+    let mut tied = false;
+    for (_, flag, confidence) in corrector.score_iter(name, known_flags) {
+        match best {
+            Some(best) if confidence < best.confidence => {}
+            Some(best) if confidence == best.confidence => tied = true,
+            _ => {
+                best = Some(FlagSuggestion {
+                    flag,
+                    value,
+                    confidence,
+                });
+                tied = false;
+            }
+        }
+    }
+    best.filter(|_| !tied)
+}
+
+/// Corrects every mistyped flag of a command line with [correct_flag](fn.correct_flag.html),
+/// up to a `--`, after which the arguments are never flags.
+///
+/// # Returns
+///
+/// `Vec<(usize, FlagSuggestion)>` - The index of every argument that has a correction, with its correction.
+///
+/// # Example
+/// ```
+/// use correct_word::flags::correct_args;
+///
+/// let flags = ["--output", "--verbose"];
+/// let args = ["build", "--ouptut=dist", "--verbose", "--", "--verbos"];
+/// let corrections: Vec<(usize, String)> = correct_args(&args, &flags)
+///     .into_iter()
+///     .map(|(index, suggestion)| (index, suggestion.to_string()))
+///     .collect();
+/// assert_eq!(corrections, [(1, "--output=dist".to_string())]);
+/// ```
+pub fn correct_args<'a, A: AsRef<str>, S: AsRef<str>>(
+    args: &'a [A],
+    known_flags: &'a [S],
+) -> Vec<(usize, FlagSuggestion<'a>)> {
+    args.iter()
+        .map(AsRef::as_ref)
+        .take_while(|&arg| arg != "--")
+        .enumerate()
+        .filter_map(|(index, arg)| Some((index, correct_flag(arg, known_flags)?)))
+        .collect()
+}
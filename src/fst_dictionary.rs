@@ -0,0 +1,211 @@
+use std::io;
+
+use fst::{Automaton, IntoStreamer, Set, Streamer};
+
+use crate::automaton::LevenshteinAutomaton;
+use crate::instrument;
+
+/// # Struct: FstDictionary
+/// A large, static dictionary stored as a finite state transducer with the [fst](https://docs.rs/fst) crate.
+/// The words share their prefixes and suffixes, so it is usually much smaller than the list of words,
+/// and looking up the words within a distance walks a [Levenshtein automaton](automaton/struct.LevenshteinAutomaton.html) through it,
+/// instead of comparing the input to every word.
+///
+/// It is built once, with [from_words](#method.from_words), and can't be changed after that.
+/// Its bytes can be saved with [as_bytes](#method.as_bytes), and loaded back, or memory-mapped, with [new](#method.new).
+///
+/// As a [CandidateSource](trait.CandidateSource.html), only the words that might clear the threshold are scored,
+/// when the [Scorer](trait.Scorer.html) knows which ones they are.
+/// The words are kept in sorted order, so when several corrections are equally good, the first in sorted order wins,
+/// not the first one added.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector, FstDictionary};
+///
+/// let dictionary = FstDictionary::from_words(["hello", "help", "world", "word"]);
+/// assert_eq!(dictionary.search("wrd", 1), vec!["word"]);
+///
+/// let corrector = Corrector::new(Algorithm::Levenshtein);
+/// let result = corrector.correct_n_from("helo", &dictionary, 2);
+/// assert_eq!(result[0].word.as_deref(), Some("hello"));
+/// assert_eq!(result[1].word.as_deref(), Some("help"));
+/// ```
+#[derive(Clone)]
+pub struct FstDictionary<D = Vec<u8>> {
+    set: Set<D>,
+}
+
+impl FstDictionary {
+    /// Builds a dictionary from any words, in any order. Duplicates are only kept once.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn from_words<W: AsRef<str>>(words: impl IntoIterator<Item = W>) -> Self {
+        let mut words: Vec<W> = words.into_iter().collect();
+        words.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+        words.dedup_by(|a, b| a.as_ref() == b.as_ref());
+        let set = Set::from_iter(words.iter().map(|word| word.as_ref()))
+            .expect("the words are sorted and unique");
+        instrument::debug!(
+            words = set.len(),
+            bytes = set.as_fst().size(),
+            "built an FST"
+        );
+        FstDictionary { set }
+    }
+}
+
+impl<D: AsRef<[u8]>> FstDictionary<D> {
+    /// Loads a dictionary from the bytes of [as_bytes](#method.as_bytes), or of any `fst::Set` of UTF-8 words.
+    /// Anything that is cheap to see as bytes works, like a `Vec<u8>`, a `&'static [u8]`, or a memory map.
+    ///
+    /// # Errors
+    ///
+    /// Fails with `InvalidData` if the bytes are not an FST.
+    pub fn new(bytes: D) -> io::Result<Self> {
+        let set =
+            Set::new(bytes).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        Ok(FstDictionary { set })
+    }
+
+    /// The bytes of the FST, to save the dictionary and load it with [new](#method.new).
+    pub fn as_bytes(&self) -> &[u8] {
+        self.set.as_fst().as_bytes()
+    }
+
+    /// The number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.set.len()
+    }
+
+    /// Whether the dictionary has no words.
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
+
+    /// Whether the dictionary has exactly this word.
+    pub fn contains(&self, word: &str) -> bool {
+        self.set.contains(word)
+    }
+
+    /// The words within `max_distance` Levenshtein edits of the input, in sorted order.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The word to look up.
+    /// * `max_distance` - The largest number of edits a word can be away from the input.
+    ///
+    /// # Returns
+    ///
+    /// `Vec<String>` - The words, in sorted order.
+    pub fn search(&self, input: &str, max_distance: u16) -> Vec<String> {
+        self.within(input, max_distance)
+    }
+
+    /// The words within the distance, or all of them when it is `u16::MAX`, like the distance of a scorer that doesn't know it.
+    pub(crate) fn within(&self, input: &str, max_distance: u16) -> Vec<String> {
+        match max_distance {
+            u16::MAX => self.collect(fst::automaton::AlwaysMatch),
+            distance => self.collect(LevenshteinAutomaton::new(input, distance)),
+        }
+    }
+
+    fn collect<A: Automaton>(&self, automaton: A) -> Vec<String> {
+        let mut words = Vec::new();
+        let mut stream = self.set.search(automaton).into_stream();
+        while let Some(word) = stream.next() {
+            words.push(String::from_utf8_lossy(word).into_owned());
+        }
+        words
+    }
+}
+
+impl<D: AsRef<[u8]>> std::fmt::Debug for FstDictionary<D> {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("FstDictionary")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use super::*;
+    use crate::metrics::levenshtein::levenshtein_distance;
+    use crate::{Algorithm, Corrector, Query, Threshold};
+
+    #[test]
+    fn search_matches_brute_force() {
+        let words = [
+            "",
+            "a",
+            "ab",
+            "abc",
+            "hello",
+            "help",
+            "helm",
+            "world",
+            "word",
+            "sword",
+            "caf\u{e9}",
+            "cafe",
+        ];
+        let dictionary = FstDictionary::from_words(words);
+        let copy = FstDictionary::new(dictionary.as_bytes().to_vec()).unwrap();
+        assert_eq!(copy.len(), words.len());
+
+        for input in ["", "a", "helo", "wrd", "cafe", "xyz", "abcdefgh"] {
+            for max_distance in [0, 1, 2, 3, 10, u16::MAX] {
+                let mut expected: Vec<&str> = words
+                    .into_iter()
+                    .filter(|word| {
+                        levenshtein_distance(input.to_string(), word.to_string()) <= max_distance
+                    })
+                    .collect();
+                expected.sort();
+                assert_eq!(
+                    copy.search(input, max_distance),
+                    expected,
+                    "{input:?} {max_distance}"
+                );
+            }
+        }
+
+        let invalid = FstDictionary::new(vec![1, 2, 3]).unwrap_err();
+        assert_eq!(invalid.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn corrects_like_a_sorted_list() {
+        let mut words = vec!["hello", "help", "helm", "world", "word", "sword", "yellow"];
+        let dictionary = FstDictionary::from_words(&words);
+        words.sort();
+        let cancel = AtomicBool::new(false);
+        let query = Query::new().min_confidence(0.7);
+        for threshold in [Threshold::Similarity(0.5), Threshold::MaxDistance(1)] {
+            for algorithm in [Algorithm::Levenshtein, Algorithm::Jaccard] {
+                let corrector = Corrector::new(algorithm).threshold(threshold);
+                for input in ["helo", "wrd", "hello", "xyz"] {
+                    assert_eq!(
+                        corrector.correct_from(input, &dictionary),
+                        corrector.correct(input, &words)
+                    );
+                    assert_eq!(
+                        corrector.correct_n_from_cancellable(input, &dictionary, 3, &cancel),
+                        Ok(corrector.correct_n(input, &words, 3))
+                    );
+                    assert_eq!(
+                        corrector.trace_from(input, &dictionary).result,
+                        corrector.correct(input, &words)
+                    );
+                    assert_eq!(
+                        corrector.query_from(input, &dictionary, &query),
+                        corrector.query(input, &words, &query)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::{Algorithm, Corrector, Scorer};
+
+/// # Struct: FuzzyMap
+/// A map with string keys that tolerates typos when looking them up.
+///
+/// [get](#method.get) only finds a key that is exactly the same, like a `HashMap`.
+/// [get_fuzzy](#method.get_fuzzy) finds the key that is most similar to the one asked for,
+/// as long as it clears the threshold of the map's [Corrector](struct.Corrector.html).
+/// This is handy for routing commands or config keys to their handlers.
+///
+/// An exact match is always the best match. Otherwise, when several keys are equally good,
+/// the [tie break](struct.Corrector.html#method.tie_break) of the corrector decides, and by default the one inserted first wins.
+///
+/// # Example
+/// ```
+/// use correct_word::FuzzyMap;
+///
+/// let mut commands = FuzzyMap::new();
+/// commands.insert("status", 1);
+/// commands.insert("commit", 2);
+///
+/// assert_eq!(commands.get("comit"), None);
+/// assert_eq!(commands.get_fuzzy("comit"), Some(("commit", &2)));
+/// assert_eq!(commands.get_fuzzy("push"), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FuzzyMap<V, S: Scorer = Algorithm> {
+    corrector: Corrector<S>,
+    keys: Vec<String>,
+    values: Vec<V>,
+    index: HashMap<String, usize>,
+}
+
+impl<V> FuzzyMap<V> {
+    /// Creates an empty map, which looks keys up with Levenshtein and the default threshold.
+    pub fn new() -> Self {
+        Self::with_corrector(Corrector::new(Algorithm::Levenshtein))
+    }
+}
+
+impl<V> Default for FuzzyMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, S: Scorer> FuzzyMap<V, S> {
+    /// Creates an empty map, which looks keys up with the given corrector.
+    pub fn with_corrector(corrector: Corrector<S>) -> Self {
+        FuzzyMap {
+            corrector,
+            keys: Vec::new(),
+            values: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// Inserts a value for a key. If the key was already there, its value is replaced and the old one returned.
+    pub fn insert(&mut self, key: impl Into<String>, value: V) -> Option<V> {
+        let key = key.into();
+        if let Some(&position) = self.index.get(&key) {
+            return Some(std::mem::replace(&mut self.values[position], value));
+        }
+        self.index.insert(key.clone(), self.keys.len());
+        self.keys.push(key);
+        self.values.push(value);
+        None
+    }
+
+    /// Returns the value of exactly this key.
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.index.get(key).map(|&position| &self.values[position])
+    }
+
+    /// Returns the key that best matches the one asked for, and its value.
+    /// None if no key clears the threshold.
+    pub fn get_fuzzy(&self, key: &str) -> Option<(&str, &V)> {
+        if let Some(&position) = self.index.get(key) {
+            return Some(self.entry(position));
+        }
+
+        let mut best: Option<(usize, f64)> = None;
+        for (position, known) in self.keys.iter().enumerate() {
+            let Some((confidence, accepted)) = self.corrector.score(key, known) else {
+                continue;
+            };
+            let better = |(best, best_confidence): (usize, f64)| {
+                self.corrector
+                    .rank((known, confidence), (&self.keys[best], best_confidence))
+                    == Ordering::Less
+            };
+            if accepted && best.is_none_or(better) {
+                best = Some((position, confidence));
+            }
+        }
+        best.map(|(position, _)| self.entry(position))
+    }
+
+    /// Returns up to `n` of the keys that clear the threshold, and their values, best first.
+    /// Keys that match equally well are ordered by the tie break, and by default keep the order they were inserted in.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::FuzzyMap;
+    ///
+    /// let settings: FuzzyMap<u32> = [("colour", 1), ("color", 2), ("cooler", 3)].into_iter().collect();
+    /// let keys: Vec<&str> = settings.get_fuzzy_n("color", 2).into_iter().map(|(key, _)| key).collect();
+    /// assert_eq!(keys, vec!["color", "colour"]);
+    /// ```
+    pub fn get_fuzzy_n(&self, key: &str, n: usize) -> Vec<(&str, &V)> {
+        let mut matches: Vec<(usize, f64)> = self
+            .keys
+            .iter()
+            .enumerate()
+            .filter_map(|(position, known)| {
+                if known == key {
+                    return Some((position, f64::INFINITY));
+                }
+                let (confidence, accepted) = self.corrector.score(key, known)?;
+                accepted.then_some((position, confidence))
+            })
+            .collect();
+
+        matches.sort_by(|a, b| {
+            self.corrector
+                .rank((&self.keys[a.0], a.1), (&self.keys[b.0], b.1))
+        });
+        matches.truncate(n);
+        matches
+            .into_iter()
+            .map(|(position, _)| self.entry(position))
+            .collect()
+    }
+
+    /// The keys of the map, in the order they were inserted.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.keys.iter().map(String::as_str)
+    }
+
+    /// The number of keys in the map.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Whether the map has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    fn entry(&self, position: usize) -> (&str, &V) {
+        (&self.keys[position], &self.values[position])
+    }
+}
+
+impl<K: Into<String>, V> FromIterator<(K, V)> for FuzzyMap<V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(entries: I) -> Self {
+        let mut map = FuzzyMap::new();
+        for (key, value) in entries {
+            map.insert(key, value);
+        }
+        map
+    }
+}
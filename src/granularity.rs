@@ -0,0 +1,49 @@
+/// # Enum: Granularity
+/// Decides what counts as a single unit when comparing two strings.
+///
+/// * Bytes: Every byte is a unit. Fast, but a non-ASCII character counts as several edits.
+/// * Chars: Every Unicode scalar value is a unit. This is the default.
+/// * Graphemes: Every user-perceived character is a unit, so "é" written as "e" plus a combining accent is one unit.
+///   Needs the `unicode-segmentation` feature.
+/// * Words: Every whitespace-separated word is a unit, so the distance is the number of words to insert, delete or replace.
+///   Use this to match multi-word phrases, like command descriptions or song titles.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::levenshtein::levenshtein_distance_with;
+/// use correct_word::Granularity;
+///
+/// let distance = levenshtein_distance_with("the quick brown fox".to_string(), "the quick red fox".to_string(), Granularity::Words);
+/// assert_eq!(distance, 1);
+///
+/// let distance = levenshtein_distance_with("naïve".to_string(), "naive".to_string(), Granularity::Bytes);
+/// assert_eq!(distance, 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Granularity {
+    Bytes,
+    #[default]
+    Chars,
+    #[cfg(feature = "unicode-segmentation")]
+    Graphemes,
+    Words,
+}
+
+impl Granularity {
+    /// Splits a string into the units to compare. Every unit is kept as its bytes, so all the modes compare the same way.
+    pub(crate) fn tokens<'a>(&self, string: &'a str) -> Vec<&'a [u8]> {
+        match self {
+            Granularity::Bytes => string.as_bytes().chunks(1).collect(),
+            Granularity::Chars => string
+                .char_indices()
+                .map(|(i, c)| &string.as_bytes()[i..i + c.len_utf8()])
+                .collect(),
+            #[cfg(feature = "unicode-segmentation")]
+            Granularity::Graphemes => {
+                use unicode_segmentation::UnicodeSegmentation;
+                string.graphemes(true).map(str::as_bytes).collect()
+            }
+            Granularity::Words => string.split_whitespace().map(str::as_bytes).collect(),
+        }
+    }
+}
@@ -0,0 +1,564 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+use crate::{instrument, Dictionary};
+
+/// # Struct: HunspellDictionary
+/// A dictionary in the hunspell format: a `.dic` file of stems with affix flags, and an `.aff` file with the rules
+/// the flags stand for, like "walk/DS" with a rule that adds "ed" for D and "s" for S.
+/// Thousands of these dictionaries exist for nearly every language, and they are much smaller than the list of every form.
+///
+/// The dictionary keeps the stems and generates the affixed forms lazily with [forms](#method.forms),
+/// or all at once into a [Dictionary](../struct.Dictionary.html) with [expand](#method.expand), to correct against.
+/// [contains](#method.contains) and [stems](#method.stems) go the other way, and strip the affixes of a word to find its stems
+/// without generating anything.
+///
+/// The `.aff` directives that are read are:
+/// * `SET`: The encoding of both files, `UTF-8` (the default), `ISO8859-1` or `ISO8859-15`. Other encodings give an `InvalidData` error.
+/// * `FLAG`: How flags are written, as single characters (the default, or `UTF-8`), as pairs of characters with `long`,
+///   or as comma separated numbers with `num`.
+/// * `AF`: Aliases for sets of flags, which the `.dic` file refers to by their number.
+/// * `PFX` and `SFX`: The prefix and suffix rules, with their strip, their affix and their condition.
+///   A prefix and a suffix are combined when both allow cross products.
+/// * `NEEDAFFIX` and `FORBIDDENWORD`: Stems that are only valid with an affix, and words that are never valid.
+///
+/// Everything else is skipped, including compounding, twofold affixes from continuation classes and morphological fields,
+/// so a dictionary that relies on them covers fewer forms than hunspell would.
+///
+/// # Example
+/// ```
+/// use correct_word::hunspell::HunspellDictionary;
+/// use correct_word::{Algorithm, Corrector};
+///
+/// let aff = "SET UTF-8\n\
+///            PFX U Y 1\n\
+///            PFX U 0 un .\n\
+///            SFX D Y 2\n\
+///            SFX D 0 ed [^y]\n\
+///            SFX D y ied y\n";
+/// let dic = "3\nlock/UD\ncarry/D\nopen\n";
+/// let hunspell = HunspellDictionary::from_readers(dic.as_bytes(), aff.as_bytes()).unwrap();
+///
+/// let forms: Vec<String> = hunspell.forms().collect();
+/// assert_eq!(forms, ["lock", "locked", "unlock", "unlocked", "carry", "carried", "open"]);
+/// assert!(hunspell.contains("unlocked"));
+/// assert_eq!(hunspell.stems("carried"), ["carry"]);
+///
+/// let dictionary = hunspell.expand();
+/// let corrector = Corrector::new(Algorithm::DamerauLevenshtein);
+/// assert_eq!(corrector.correct_from("carreid", &dictionary).word.as_deref(), Some("carried"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HunspellDictionary {
+    stems: Vec<Stem>,
+    /// The positions of the stems with each spelling, since a stem can be listed more than once with different flags.
+    lookup: HashMap<String, Vec<usize>>,
+    prefixes: Vec<Affix>,
+    suffixes: Vec<Affix>,
+    need_affix: Option<Flag>,
+    forbidden: Option<Flag>,
+}
+
+type Flag = u64;
+
+#[derive(Debug, Clone)]
+struct Stem {
+    word: String,
+    flags: Vec<Flag>,
+}
+
+/// A prefix or suffix rule: `strip` is removed from the stem and `add` is put in its place, when the stem fits `condition`.
+#[derive(Debug, Clone)]
+struct Affix {
+    flag: Flag,
+    cross_product: bool,
+    strip: String,
+    add: String,
+    condition: Vec<Pattern>,
+}
+
+/// One character of a condition, which is a bracket expression, a `.` or a plain character.
+#[derive(Debug, Clone)]
+enum Pattern {
+    Any,
+    OneOf(Vec<char>),
+    NoneOf(Vec<char>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FlagType {
+    Char,
+    Long,
+    Num,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Encoding {
+    Utf8,
+    Latin1,
+    Latin9,
+}
+
+impl HunspellDictionary {
+    /// Loads a dictionary from the contents of its `.dic` file and its `.aff` file.
+    /// Fails with `InvalidData` when the encoding is not supported, or the files don't fit it.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn from_readers<D: Read, A: Read>(mut dic: D, mut aff: A) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        aff.read_to_end(&mut bytes)?;
+        let encoding = encoding(&bytes)?;
+        let aff = decode(bytes, encoding)?;
+
+        let mut bytes = Vec::new();
+        dic.read_to_end(&mut bytes)?;
+        let dic = decode(bytes, encoding)?;
+
+        let mut dictionary = HunspellDictionary::default();
+        let (flag_type, aliases) = dictionary.read_aff(&aff);
+        dictionary.read_dic(&dic, flag_type, &aliases);
+        instrument::debug!(
+            stems = dictionary.stems.len(),
+            prefixes = dictionary.prefixes.len(),
+            suffixes = dictionary.suffixes.len(),
+            "loaded a hunspell dictionary"
+        );
+        Ok(dictionary)
+    }
+
+    /// Loads a dictionary from its `.dic` file and its `.aff` file, like [from_readers](#method.from_readers).
+    pub fn from_files<D: AsRef<Path>, A: AsRef<Path>>(dic: D, aff: A) -> io::Result<Self> {
+        Self::from_readers(fs::File::open(dic)?, fs::File::open(aff)?)
+    }
+
+    /// The number of stems in the `.dic` file.
+    pub fn len(&self) -> usize {
+        self.stems.len()
+    }
+
+    /// Whether the `.dic` file has no stems.
+    pub fn is_empty(&self) -> bool {
+        self.stems.is_empty()
+    }
+
+    /// Every valid word, generated as it is iterated: each stem, and then its suffixed, prefixed and cross product forms,
+    /// in the order of the rules. A word that several stems or rules generate comes up several times.
+    pub fn forms(&self) -> impl Iterator<Item = String> + '_ {
+        self.stems.iter().flat_map(move |stem| self.forms_of(stem))
+    }
+
+    /// Generates every valid word into a dictionary, without duplicates, to correct against.
+    pub fn expand(&self) -> Dictionary {
+        let mut dictionary = Dictionary::new();
+        for form in self.forms() {
+            dictionary.insert(form);
+        }
+        dictionary
+    }
+
+    /// Whether the word is valid, either as a stem or as one with its affixes, found by stripping the affixes of the word.
+    pub fn contains(&self, word: &str) -> bool {
+        !self.stems(word).is_empty()
+    }
+
+    /// The stems the word is a valid form of, in the order of the `.dic` file. Empty when the word is not valid.
+    pub fn stems(&self, word: &str) -> Vec<&str> {
+        let mut found: Vec<usize> = Vec::new();
+        let mut add = |position: usize| {
+            if !found.contains(&position) {
+                found.push(position);
+            }
+        };
+        for &position in self.lookup.get(word).into_iter().flatten() {
+            let flags = &self.stems[position].flags;
+            if !self.has(flags, self.need_affix) && !self.has(flags, self.forbidden) {
+                add(position);
+            }
+        }
+        for suffix in &self.suffixes {
+            if let Some(root) = suffix.unapply_suffix(word) {
+                for position in self.stems_with(&root, suffix.flag) {
+                    add(position);
+                }
+            }
+        }
+        for prefix in &self.prefixes {
+            let Some(rest) = prefix.unapply_prefix(word) else {
+                continue;
+            };
+            for position in self.stems_with(&rest, prefix.flag) {
+                add(position);
+            }
+            if !prefix.cross_product {
+                continue;
+            }
+            for suffix in self.suffixes.iter().filter(|suffix| suffix.cross_product) {
+                if let Some(root) = suffix.unapply_suffix(&rest) {
+                    for position in self.stems_with(&root, suffix.flag) {
+                        if self.stems[position].flags.contains(&prefix.flag) {
+                            add(position);
+                        }
+                    }
+                }
+            }
+        }
+        found.sort_unstable();
+        found
+            .into_iter()
+            .filter(|&position| !self.has(&self.stems[position].flags, self.forbidden))
+            .map(|position| self.stems[position].word.as_str())
+            .collect()
+    }
+
+    /// The positions of the stems spelled `root` that have the flag.
+    fn stems_with<'a>(&'a self, root: &str, flag: Flag) -> impl Iterator<Item = usize> + 'a {
+        self.lookup
+            .get(root)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(move |&position| self.stems[position].flags.contains(&flag))
+    }
+
+    fn has(&self, flags: &[Flag], flag: Option<Flag>) -> bool {
+        flag.is_some_and(|flag| flags.contains(&flag))
+    }
+
+    fn forms_of<'a>(&'a self, stem: &'a Stem) -> impl Iterator<Item = String> + 'a {
+        let forbidden = self.has(&stem.flags, self.forbidden);
+        let bare = !forbidden && !self.has(&stem.flags, self.need_affix);
+        let rules = move |affixes: &'a [Affix]| {
+            affixes
+                .iter()
+                .filter(move |affix| !forbidden && stem.flags.contains(&affix.flag))
+        };
+        let suffixed = rules(&self.suffixes).filter_map(|suffix| suffix.apply_suffix(&stem.word));
+        let prefixed = rules(&self.prefixes).filter_map(|prefix| prefix.apply_prefix(&stem.word));
+        // The prefix is added to the suffixed word, and its condition is checked against it
+        let crossed = rules(&self.prefixes)
+            .filter(|prefix| prefix.cross_product)
+            .flat_map(move |prefix| {
+                rules(&self.suffixes)
+                    .filter(|suffix| suffix.cross_product)
+                    .filter_map(|suffix| suffix.apply_suffix(&stem.word))
+                    .filter_map(|word| prefix.apply_prefix(&word))
+            });
+        bare.then(|| stem.word.clone())
+            .into_iter()
+            .chain(suffixed)
+            .chain(prefixed)
+            .chain(crossed)
+    }
+
+    /// Reads the rules of the `.aff` file, and returns how the flags are written and the flag aliases, for the `.dic` file.
+    fn read_aff(&mut self, aff: &str) -> (FlagType, Vec<Vec<Flag>>) {
+        let mut flag_type = FlagType::Char;
+        let mut aliases = Vec::new();
+        let mut counted_aliases = false;
+        // Whether each group of rules allows cross products, which only the header line of the group says
+        let mut cross_products: HashMap<(bool, Flag), bool> = HashMap::new();
+        for line in aff.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                ["FLAG", kind, ..] => {
+                    flag_type = match *kind {
+                        "long" => FlagType::Long,
+                        "num" => FlagType::Num,
+                        _ => FlagType::Char,
+                    };
+                }
+                // The first AF line is the number of aliases
+                ["AF", flags, ..] if counted_aliases => aliases.push(parse_flags(flags, flag_type)),
+                ["AF", ..] => counted_aliases = true,
+                ["NEEDAFFIX", flag, ..] => self.need_affix = parse_flag(flag, flag_type),
+                ["FORBIDDENWORD", flag, ..] => self.forbidden = parse_flag(flag, flag_type),
+                [kind @ ("PFX" | "SFX"), flag, cross, count] if count.parse::<usize>().is_ok() => {
+                    if let Some(flag) = parse_flag(flag, flag_type) {
+                        cross_products.insert((*kind == "PFX", flag), *cross == "Y");
+                    }
+                }
+                [kind @ ("PFX" | "SFX"), flag, strip, add, rest @ ..] => {
+                    let Some(flag) = parse_flag(flag, flag_type) else {
+                        continue;
+                    };
+                    let prefix = *kind == "PFX";
+                    let affix = Affix {
+                        flag,
+                        cross_product: cross_products
+                            .get(&(prefix, flag))
+                            .copied()
+                            .unwrap_or(false),
+                        strip: zero(strip).to_string(),
+                        // Continuation classes after a slash are not supported
+                        add: zero(add.split('/').next().unwrap_or_default()).to_string(),
+                        condition: parse_condition(rest.first().copied().unwrap_or(".")),
+                    };
+                    if prefix {
+                        self.prefixes.push(affix);
+                    } else {
+                        self.suffixes.push(affix);
+                    }
+                }
+                _ => {}
+            }
+        }
+        (flag_type, aliases)
+    }
+
+    /// Reads the stems of the `.dic` file, whose first line is their number.
+    fn read_dic(&mut self, dic: &str, flag_type: FlagType, aliases: &[Vec<Flag>]) {
+        let mut lines = dic.lines().peekable();
+        if lines
+            .peek()
+            .is_some_and(|line| line.trim().parse::<usize>().is_ok())
+        {
+            lines.next();
+        }
+        for line in lines {
+            // Morphological fields come after the word and its flags, separated by whitespace
+            let Some(entry) = line.split_whitespace().next() else {
+                continue;
+            };
+            let (word, flags) = split_flags(entry);
+            if word.is_empty() {
+                continue;
+            }
+            let flags = match flags {
+                None => Vec::new(),
+                Some(flags) if !aliases.is_empty() => flags
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|alias| aliases.get(alias.wrapping_sub(1)))
+                    .cloned()
+                    .unwrap_or_default(),
+                Some(flags) => parse_flags(flags, flag_type),
+            };
+            self.lookup
+                .entry(word.clone())
+                .or_default()
+                .push(self.stems.len());
+            self.stems.push(Stem { word, flags });
+        }
+    }
+}
+
+impl Affix {
+    /// The word with this suffix, if the stem fits the condition.
+    fn apply_suffix(&self, stem: &str) -> Option<String> {
+        let root = stem.strip_suffix(self.strip.as_str())?;
+        if root.is_empty() && self.add.is_empty() || !matches_end(&self.condition, stem) {
+            return None;
+        }
+        Some(format!("{root}{}", self.add))
+    }
+
+    /// The word with this prefix, if the stem fits the condition.
+    fn apply_prefix(&self, stem: &str) -> Option<String> {
+        let root = stem.strip_prefix(self.strip.as_str())?;
+        if root.is_empty() && self.add.is_empty() || !matches_start(&self.condition, stem) {
+            return None;
+        }
+        Some(format!("{}{root}", self.add))
+    }
+
+    /// The stem a word with this suffix comes from, if it has the suffix and the stem fits the condition.
+    fn unapply_suffix(&self, word: &str) -> Option<String> {
+        let root = word.strip_suffix(self.add.as_str())?;
+        if root.is_empty() {
+            return None;
+        }
+        let stem = format!("{root}{}", self.strip);
+        matches_end(&self.condition, &stem).then_some(stem)
+    }
+
+    /// The stem a word with this prefix comes from, if it has the prefix and the stem fits the condition.
+    fn unapply_prefix(&self, word: &str) -> Option<String> {
+        let root = word.strip_prefix(self.add.as_str())?;
+        if root.is_empty() {
+            return None;
+        }
+        let stem = format!("{}{root}", self.strip);
+        matches_start(&self.condition, &stem).then_some(stem)
+    }
+}
+
+impl Pattern {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Pattern::Any => true,
+            Pattern::OneOf(chars) => chars.contains(&c),
+            Pattern::NoneOf(chars) => !chars.contains(&c),
+        }
+    }
+}
+
+fn matches_start(condition: &[Pattern], word: &str) -> bool {
+    let mut chars = word.chars();
+    condition
+        .iter()
+        .all(|pattern| chars.next().is_some_and(|c| pattern.matches(c)))
+}
+
+fn matches_end(condition: &[Pattern], word: &str) -> bool {
+    let mut chars = word.chars().rev();
+    condition
+        .iter()
+        .rev()
+        .all(|pattern| chars.next().is_some_and(|c| pattern.matches(c)))
+}
+
+/// Parses a condition like `[^aeiou]y`, one pattern per character.
+fn parse_condition(condition: &str) -> Vec<Pattern> {
+    let mut patterns = Vec::new();
+    let mut chars = condition.chars();
+    while let Some(c) = chars.next() {
+        patterns.push(match c {
+            '.' => Pattern::Any,
+            '[' => {
+                let mut set: Vec<char> = chars.by_ref().take_while(|&c| c != ']').collect();
+                if set.first() == Some(&'^') {
+                    set.remove(0);
+                    Pattern::NoneOf(set)
+                } else {
+                    Pattern::OneOf(set)
+                }
+            }
+            c => Pattern::OneOf(vec![c]),
+        });
+    }
+    patterns
+}
+
+/// A strip or an affix, where `0` stands for nothing.
+fn zero(field: &str) -> &str {
+    if field == "0" {
+        ""
+    } else {
+        field
+    }
+}
+
+/// Splits a `.dic` entry into the word and its flags, at the first slash that isn't escaped with a backslash.
+fn split_flags(entry: &str) -> (String, Option<&str>) {
+    let mut word = String::new();
+    let mut chars = entry.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' if entry[index + 1..].starts_with('/') => {
+                word.push('/');
+                chars.next();
+            }
+            '/' => return (word, Some(&entry[index + 1..])),
+            c => word.push(c),
+        }
+    }
+    (word, None)
+}
+
+fn parse_flags(flags: &str, flag_type: FlagType) -> Vec<Flag> {
+    match flag_type {
+        FlagType::Char => flags.chars().map(|c| c as Flag).collect(),
+        FlagType::Long => {
+            let chars: Vec<char> = flags.chars().collect();
+            chars
+                .chunks(2)
+                .map(|pair| pair.iter().fold(0, |flag, &c| flag * 0x11_0000 + c as Flag))
+                .collect()
+        }
+        FlagType::Num => flags
+            .split(',')
+            .filter_map(|flag| flag.trim().parse().ok())
+            .collect(),
+    }
+}
+
+fn parse_flag(flag: &str, flag_type: FlagType) -> Option<Flag> {
+    parse_flags(flag, flag_type).first().copied()
+}
+
+/// The encoding of the `SET` line of an `.aff` file, which is written in ASCII whatever the encoding.
+fn encoding(aff: &[u8]) -> io::Result<Encoding> {
+    let set = aff
+        .split(|&byte| byte == b'\n')
+        .map(String::from_utf8_lossy)
+        .find_map(|line| {
+            let mut fields = line.split_whitespace();
+            (fields.next() == Some("SET")).then(|| fields.next().unwrap_or_default().to_string())
+        });
+    match set.as_deref().map(str::to_ascii_uppercase).as_deref() {
+        None | Some("UTF-8") => Ok(Encoding::Utf8),
+        Some("ISO8859-1" | "ISO-8859-1") => Ok(Encoding::Latin1),
+        Some("ISO8859-15" | "ISO-8859-15") => Ok(Encoding::Latin9),
+        Some(other) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unsupported encoding {other}"),
+        )),
+    }
+}
+
+fn decode(bytes: Vec<u8>, encoding: Encoding) -> io::Result<String> {
+    match encoding {
+        Encoding::Utf8 => String::from_utf8(bytes)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error)),
+        Encoding::Latin1 => Ok(bytes.into_iter().map(char::from).collect()),
+        // ISO 8859-15 only differs from ISO 8859-1 in eight characters
+        Encoding::Latin9 => Ok(bytes
+            .into_iter()
+            .map(|byte| match byte {
+                0xA4 => '\u{20ac}',
+                0xA6 => '\u{160}',
+                0xA8 => '\u{161}',
+                0xB4 => '\u{17d}',
+                0xB8 => '\u{17e}',
+                0xBC => '\u{152}',
+                0xBD => '\u{153}',
+                0xBE => '\u{178}',
+                byte => char::from(byte),
+            })
+            .collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stripping_agrees_with_generating() {
+        let aff = "FLAG long\n\
+                   AF 4\n\
+                   AF SsRe\n\
+                   AF Ss\n\
+                   AF NaSs\n\
+                   AF FbSs\n\
+                   NEEDAFFIX Na\n\
+                   FORBIDDENWORD Fb\n\
+                   PFX Re Y 1\n\
+                   PFX Re 0 re [^r]\n\
+                   SFX Ss Y 3\n\
+                   SFX Ss y ies [^aeiou]y\n\
+                   SFX Ss 0 s [aeiou]y\n\
+                   SFX Ss 0 s [^y]\n";
+        let dic = "5\ncopy/1\ntoy/2\nfly/3\nplay/4\n1\\/2/2\tpo:noun\n";
+        let hunspell = HunspellDictionary::from_readers(dic.as_bytes(), aff.as_bytes()).unwrap();
+        let forms: Vec<String> = hunspell.forms().collect();
+        assert_eq!(
+            forms,
+            ["copy", "copies", "recopy", "recopies", "toy", "toys", "flies", "1/2", "1/2s"]
+        );
+        for form in &forms {
+            assert!(hunspell.contains(form), "{form}");
+        }
+        for word in ["fly", "play", "plays", "retoy", "copys", "recopy/1", "ies"] {
+            assert!(!hunspell.contains(word), "{word}");
+        }
+        assert_eq!(hunspell.stems("recopies"), ["copy"]);
+
+        let latin1 =
+            HunspellDictionary::from_readers(&b"caf\xe9\n"[..], &b"SET ISO8859-1\n"[..]).unwrap();
+        assert!(latin1.contains("caf\u{e9}"));
+        let koi8 = HunspellDictionary::from_readers(&b""[..], &b"SET KOI8-R\n"[..]);
+        assert_eq!(koi8.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::metrics::levenshtein::alignment_cost;
+use crate::{Algorithm, Scorer};
+
+/// # Struct: IdentifierScorer
+/// A [Scorer](../trait.Scorer.html) for identifiers in code, like variable and function names,
+/// that doesn't care whether they are written in camelCase, snake_case or kebab-case.
+///
+/// Both strings are split into lowercase words with [split_identifier](fn.split_identifier.html), and scored in two ways:
+/// * Joined back together in snake_case with the inner scorer, so `getUser` and `get_user` are equal,
+///   and `getuser` is only a separator away from them.
+/// * Word by word, lining the words up with each other, so a missing word costs a whole word,
+///   and a misspelled one only part of it.
+///
+/// The similarity is the better of the two.
+///
+/// # Example
+/// ```
+/// use correct_word::identifiers::IdentifierScorer;
+/// use correct_word::{Algorithm, Corrector};
+///
+/// let options = ["get_user", "getUserName", "set_user"];
+/// let corrector = Corrector::new(IdentifierScorer::new(Algorithm::Levenshtein));
+/// assert_eq!(corrector.correct("getuser", &options).word.unwrap(), "get_user");
+/// assert_eq!(corrector.correct("get_user_name", &options).word.unwrap(), "getUserName");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct IdentifierScorer<S: Scorer = Algorithm> {
+    scorer: S,
+}
+
+impl<S: Scorer> IdentifierScorer<S> {
+    /// Creates an identifier scorer that compares words with the given algorithm, or scorer.
+    pub fn new(scorer: S) -> Self {
+        IdentifierScorer { scorer }
+    }
+}
+
+impl<S: Scorer> Scorer for IdentifierScorer<S> {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        let words1 = split_identifier(a);
+        let words2 = split_identifier(b);
+        let count = words1.len().max(words2.len());
+        if count == 0 {
+            return 1.0;
+        }
+
+        let joined = self.scorer.similarity(&words1.join("_"), &words2.join("_"));
+        let cost = alignment_cost(&words1, &words2, |a, b| 1.0 - self.scorer.similarity(a, b));
+        joined.max(1.0 - cost / count as f64)
+    }
+}
+
+/// Splits an identifier into its lowercase words.
+///
+/// A new word starts after anything that isn't a letter or a digit, like `_`, `-` or `.`,
+/// at a capital letter after a lowercase letter or a digit, and at the last capital of a run of capitals followed by a lowercase letter,
+/// so `HTTPServer` is `http` and `server`. Digits stay with the word before them.
+///
+/// # Arguments
+///
+/// * `identifier` - The identifier to split.
+///
+/// # Returns
+///
+/// `Vec<String>` - The words of the identifier, in lowercase.
+///
+/// # Example
+/// ```
+/// use correct_word::identifiers::split_identifier;
+///
+/// assert_eq!(split_identifier("getUserName"), vec!["get", "user", "name"]);
+/// assert_eq!(split_identifier("parse_HTTPResponse2"), vec!["parse", "http", "response2"]);
+/// assert_eq!(split_identifier("--dry-run"), vec!["dry", "run"]);
+/// ```
+pub fn split_identifier(identifier: &str) -> Vec<String> {
+    let chars: Vec<char> = identifier.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !word.is_empty() {
+            let previous = chars[i - 1];
+            let next_is_lowercase = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && next_is_lowercase)
+            {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
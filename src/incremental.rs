@@ -0,0 +1,228 @@
+use crate::{Match, Threshold};
+
+/// # Struct: IncrementalMatcher
+/// Matches an input against a list of words as it is typed, one character at a time, like the suggestions of a search box.
+///
+/// It keeps the rows of the Levenshtein DP of every word, one for each character of the input.
+/// Typing a character only calculates one new row per word, and deleting one drops the last row,
+/// instead of scoring every word from scratch on every keystroke.
+/// [set_input](#method.set_input) keeps the rows of the prefix the new input shares with the old one,
+/// so it also works with a text field that hands over its whole content.
+///
+/// The memory is a row of each word for every character of the input, so keep the inputs short, like search queries.
+///
+/// By default, the input is compared to the whole of each word, like [Algorithm::Levenshtein](enum.Algorithm.html).
+/// With [prefix](#method.prefix), it is compared to the start of each word that fits it best,
+/// since what has been typed so far is usually the start of a longer word.
+/// The similarity is then relative to the length of the input.
+///
+/// # Example
+/// ```
+/// use correct_word::IncrementalMatcher;
+///
+/// let mut matcher: IncrementalMatcher = ["hello", "help", "world", "helicopter"].into_iter().collect();
+/// matcher.set_input("helo");
+/// let words: Vec<&str> = matcher.matches(2).iter().map(|m| m.word).collect();
+/// assert_eq!(words, ["hello", "help"]);
+///
+/// // Prefixes of the words, as the input is typed
+/// let mut matcher = matcher.prefix(true).threshold(0.75);
+/// matcher.set_input("");
+/// for c in "helic".chars() {
+///     matcher.push(c);
+/// }
+/// assert_eq!(matcher.matches(1)[0].word, "helicopter");
+///
+/// matcher.pop();
+/// matcher.pop();
+/// assert_eq!(matcher.input(), "hel");
+/// assert_eq!(matcher.matches(10).len(), 3);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IncrementalMatcher {
+    words: Vec<String>,
+    chars: Vec<Vec<char>>,
+    /// The DP rows of every word, one after the other, from the row of the empty input to the row of the whole input.
+    rows: Vec<Vec<u16>>,
+    input: String,
+    threshold: Threshold,
+    prefix: bool,
+}
+
+impl IncrementalMatcher {
+    /// Creates a matcher for the words, with an empty input and the default threshold.
+    pub fn new<I: IntoIterator<Item = S>, S: Into<String>>(words: I) -> Self {
+        let mut matcher = Self::default();
+        for word in words {
+            let word = word.into();
+            let chars: Vec<char> = word.chars().collect();
+            // Turning the empty input into a prefix of the word takes one insertion per character
+            matcher.rows.push(
+                (0..=chars.len())
+                    .map(|j| j.min(u16::MAX as usize) as u16)
+                    .collect(),
+            );
+            matcher.chars.push(chars);
+            matcher.words.push(word);
+        }
+        matcher
+    }
+
+    /// Sets the threshold a word has to clear to be matched. A plain `f64` is a minimum similarity.
+    pub fn threshold(mut self, threshold: impl Into<Threshold>) -> Self {
+        self.threshold = threshold.into();
+        self
+    }
+
+    /// Sets whether the input is compared to the start of each word instead of the whole word. By default, the whole word.
+    pub fn prefix(mut self, enabled: bool) -> Self {
+        self.prefix = enabled;
+        self
+    }
+
+    /// The input typed so far.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The words, in the order they were given.
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.words.iter().map(String::as_str)
+    }
+
+    /// Adds a character to the end of the input, calculating one row of the DP of every word.
+    pub fn push(&mut self, c: char) {
+        self.input.push(c);
+        let i = self.input.chars().count();
+        for (row, chars) in self.rows.iter_mut().zip(&self.chars) {
+            let width = chars.len() + 1;
+            let start = row.len() - width;
+            row.push(i.min(u16::MAX as usize) as u16);
+            for (j, &other) in chars.iter().enumerate() {
+                let diagonal = row[start + j].saturating_add(if c == other { 0 } else { 1 });
+                let above = row[start + j + 1].saturating_add(1);
+                let left = row[start + width + j].saturating_add(1);
+                row.push(diagonal.min(above).min(left));
+            }
+        }
+    }
+
+    /// Removes the last character of the input and the rows it added. None if the input was empty.
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.input.pop()?;
+        for (row, chars) in self.rows.iter_mut().zip(&self.chars) {
+            row.truncate(row.len() - (chars.len() + 1));
+        }
+        Some(c)
+    }
+
+    /// Replaces the input, keeping the rows of the prefix it shares with the old input and calculating the others.
+    pub fn set_input(&mut self, input: &str) {
+        let shared: usize = self
+            .input
+            .chars()
+            .zip(input.chars())
+            .take_while(|(old, new)| old == new)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+        while self.input.len() > shared {
+            self.pop();
+        }
+        for c in input[shared..].chars() {
+            self.push(c);
+        }
+    }
+
+    /// The distance of the input to every word, in the order of the words.
+    /// It is the Levenshtein distance, or the distance to the closest prefix of the word with [prefix](#method.prefix).
+    pub fn distances(&self) -> impl Iterator<Item = (usize, &str, u16)> {
+        self.words
+            .iter()
+            .zip(&self.rows)
+            .zip(&self.chars)
+            .enumerate()
+            .map(|(index, ((word, row), chars))| {
+                // The last row of the word, for the whole input
+                let last = &row[row.len() - (chars.len() + 1)..];
+                let distance = if self.prefix {
+                    last.iter().copied().min().unwrap_or(0)
+                } else {
+                    last[chars.len()]
+                };
+                (index, word.as_str(), distance)
+            })
+    }
+
+    /// Up to `n` of the words that clear the threshold, best first, as [Match](struct.Match.html)es.
+    /// Words that match equally well keep the order they were given in.
+    pub fn matches(&self, n: usize) -> Vec<Match<'_>> {
+        let length = self.input.chars().count();
+        let mut matches: Vec<Match> = self
+            .distances()
+            .filter_map(|(index, word, distance)| {
+                let longest = if self.prefix {
+                    length
+                } else {
+                    length.max(self.chars[index].len())
+                };
+                let similarity = if longest == 0 {
+                    1.0
+                } else {
+                    1.0 - distance as f64 / longest as f64
+                };
+                let accepted = match self.threshold {
+                    Threshold::Similarity(threshold) => similarity >= threshold,
+                    Threshold::MaxDistance(max) => distance <= max,
+                };
+                accepted.then_some(Match {
+                    word,
+                    index,
+                    rank: 0,
+                    distance,
+                    similarity,
+                    confidence: similarity,
+                })
+            })
+            .collect();
+        matches.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        matches.truncate(n);
+        for (position, found) in matches.iter_mut().enumerate() {
+            found.rank = position + 1;
+        }
+        matches
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for IncrementalMatcher {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::levenshtein::levenshtein_distance;
+
+    #[test]
+    fn rows_agree_with_levenshtein() {
+        let words = ["hello", "help", "", "日本語", "yellow"];
+        let mut matcher: IncrementalMatcher = words.into_iter().collect();
+        for input in ["h", "hel", "helo", "he", "", "日本", "yelp", "yellowish"] {
+            matcher.set_input(input);
+            for (index, word, distance) in matcher.distances() {
+                assert_eq!(index, words.iter().position(|&w| w == word).unwrap());
+                assert_eq!(
+                    distance,
+                    levenshtein_distance(input.to_string(), word.to_string()),
+                    "{input:?} {word:?}"
+                );
+            }
+        }
+
+        let mut matcher = matcher.prefix(true);
+        matcher.set_input("yello");
+        let distances: Vec<u16> = matcher.distances().map(|(.., distance)| distance).collect();
+        assert_eq!(distances, [1, 3, 5, 5, 0]);
+    }
+}
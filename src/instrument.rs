@@ -0,0 +1,10 @@
+/// Emits a debug event for the `tracing` crate with the `tracing` feature, and does nothing without it.
+/// The fields are only evaluated with the feature, so they can count things for the event alone.
+macro_rules! debug {
+    ($($event:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($event)*);
+    };
+}
+
+pub(crate) use debug;
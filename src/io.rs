@@ -0,0 +1,194 @@
+use std::io::{self, BufRead, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Algorithm, Cancelled, Corrector, Scorer};
+
+/// # Struct: Jsonl Config
+/// The configuration of a [correct_jsonl](fn.correct_jsonl.html) batch.
+///
+/// It holds the [Corrector](../struct.Corrector.html) to use, the number of corrections to return for every record,
+/// and optionally a dictionary for the records that don't have their own options.
+///
+/// # Example
+/// ```
+/// use correct_word::io::JsonlConfig;
+/// use correct_word::{Algorithm, Corrector};
+///
+/// let dictionary = vec!["hello".to_string(), "world".to_string()];
+/// let config = JsonlConfig::new(Corrector::new(Algorithm::Levenshtein))
+///     .dictionary(dictionary)
+///     .top_n(3);
+/// ```
+pub struct JsonlConfig<S: Scorer = Algorithm> {
+    corrector: Corrector<S>,
+    dictionary: Option<Vec<String>>,
+    top_n: usize,
+}
+
+impl<S: Scorer> JsonlConfig<S> {
+    /// Creates a configuration returning the single best correction for every record.
+    pub fn new(corrector: Corrector<S>) -> Self {
+        JsonlConfig {
+            corrector,
+            dictionary: None,
+            top_n: 1,
+        }
+    }
+
+    /// Sets the options used for the records without an `options` field.
+    pub fn dictionary(mut self, dictionary: Vec<String>) -> Self {
+        self.dictionary = Some(dictionary);
+        self
+    }
+
+    /// Sets the maximum number of corrections returned for every record.
+    pub fn top_n(mut self, n: usize) -> Self {
+        self.top_n = n;
+        self
+    }
+}
+
+#[derive(Deserialize)]
+struct Record {
+    input: String,
+    options: Option<Vec<String>>,
+}
+
+#[derive(Serialize)]
+struct Output<'a> {
+    input: &'a str,
+    corrections: Vec<Correction>,
+}
+
+#[derive(Serialize)]
+struct Correction {
+    word: String,
+    confidence: f64,
+}
+
+/// Corrects newline-delimited JSON records, writing the corrections as JSON lines.
+/// Needs the `jsonl` feature.
+///
+/// Every line of the reader is a record like `{"input": "helo", "options": ["hello", "world"]}`.
+/// When a record has no `options`, the dictionary of the config is used instead. Blank lines are skipped.
+/// For every record, a line like `{"input":"helo","corrections":[{"word":"hello","confidence":0.8}]}` is written,
+/// with the corrections sorted from the best to the worst.
+///
+/// # Arguments
+///
+/// * `reader` - Where to read the records from.
+/// * `writer` - Where to write the corrections to.
+/// * `config` - The corrector, the number of corrections per record and the dictionary, as a [JsonlConfig](struct.JsonlConfig.html).
+///
+/// # Returns
+///
+/// `io::Result<usize>` - The number of records corrected.
+/// A line that is not a valid record, or a record without options when there is no dictionary,
+/// stops the batch with an `InvalidData` error naming the line.
+///
+/// # Example
+/// ```
+/// use correct_word::io::{correct_jsonl, JsonlConfig};
+/// use correct_word::{Algorithm, Corrector};
+///
+/// let input = r#"{"input": "helo", "options": ["hello", "world"]}
+/// {"input": "wrld"}"#;
+/// let config = JsonlConfig::new(Corrector::new(Algorithm::Levenshtein)).dictionary(vec!["world".to_string()]);
+///
+/// let mut output = Vec::new();
+/// let records = correct_jsonl(input.as_bytes(), &mut output, &config).unwrap();
+/// assert_eq!(records, 2);
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "{\"input\":\"helo\",\"corrections\":[{\"word\":\"hello\",\"confidence\":0.8}]}\n\
+///      {\"input\":\"wrld\",\"corrections\":[{\"word\":\"world\",\"confidence\":0.8}]}\n"
+/// );
+/// ```
+pub fn correct_jsonl<R: BufRead, W: Write, S: Scorer>(
+    reader: R,
+    writer: W,
+    config: &JsonlConfig<S>,
+) -> io::Result<usize> {
+    correct_jsonl_cancellable(reader, writer, config, &AtomicBool::new(false))
+}
+
+/// Corrects newline-delimited JSON records like [correct_jsonl](fn.correct_jsonl.html), giving up as soon as `cancel` is set.
+/// The flag is checked before every record, and before every one of its options is scored, so a long batch can be stopped from another thread.
+///
+/// # Returns
+///
+/// `io::Result<usize>` - The number of records corrected. Once the flag is set, the batch stops with an error of kind `Other`
+/// that holds a [Cancelled](../struct.Cancelled.html). The lines of the records corrected before are already written.
+///
+/// # Example
+/// ```
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use correct_word::io::{correct_jsonl_cancellable, JsonlConfig};
+/// use correct_word::{Algorithm, Cancelled, Corrector};
+///
+/// let config = JsonlConfig::new(Corrector::new(Algorithm::Levenshtein)).dictionary(vec!["world".to_string()]);
+/// let mut output = Vec::new();
+/// let error = correct_jsonl_cancellable(r#"{"input": "wrld"}"#.as_bytes(), &mut output, &config, &AtomicBool::new(true))
+///     .unwrap_err();
+/// assert_eq!(error.get_ref().unwrap().downcast_ref::<Cancelled>(), Some(&Cancelled));
+/// assert!(output.is_empty());
+/// ```
+pub fn correct_jsonl_cancellable<R: BufRead, W: Write, S: Scorer>(
+    reader: R,
+    mut writer: W,
+    config: &JsonlConfig<S>,
+    cancel: &AtomicBool,
+) -> io::Result<usize> {
+    let mut records = 0;
+    for (number, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if cancel.load(Ordering::Relaxed) {
+            return Err(io::Error::other(Cancelled));
+        }
+
+        let invalid = |message: String| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {message}", number + 1),
+            )
+        };
+        let record: Record =
+            serde_json::from_str(&line).map_err(|error| invalid(error.to_string()))?;
+        let options = record
+            .options
+            .as_ref()
+            .or(config.dictionary.as_ref())
+            .ok_or_else(|| {
+                invalid("the record has no options and there is no dictionary".to_string())
+            })?;
+
+        let corrections = config
+            .corrector
+            .correct_n_cancellable(&record.input, options, config.top_n, cancel)
+            .map_err(io::Error::other)?
+            .into_iter()
+            .filter_map(|correction| {
+                Some(Correction {
+                    word: correction.word?,
+                    confidence: correction.confidence,
+                })
+            })
+            .collect();
+        let output = Output {
+            input: &record.input,
+            corrections,
+        };
+
+        serde_json::to_writer(&mut writer, &output)?;
+        writer.write_all(b"\n")?;
+        records += 1;
+    }
+
+    writer.flush()?;
+    Ok(records)
+}
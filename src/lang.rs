@@ -0,0 +1,328 @@
+use std::collections::HashSet;
+
+/// # Enum: Lang
+/// The languages with a word list built into the crate, each behind its own feature:
+/// * `En` - English, with the `lang-en` feature.
+/// * `De` - German, with the `lang-de` feature. Nouns are capitalized, like they are written.
+///
+/// The lists have the common words of each language, each with its base form and all its forms,
+/// about 13 000 words for English and 11 000 for German. They are stored as stems with the flags of their regular forms,
+/// like "walk/V" for "walk walks walked walking", and the most frequent words come first,
+/// so a [Dictionary](struct.Dictionary.html) built from them breaks ties in favor of the more common word.
+/// They are a starting point for a spell checker, not a full dictionary: add the words of your domain to them.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector, Dictionary, Lang};
+///
+/// # #[cfg(feature = "lang-en")] {
+/// let dictionary = Dictionary::builtin(Lang::En);
+/// assert!(dictionary.contains("house"));
+///
+/// let suggestions = dictionary.suggest(&Corrector::new(Algorithm::Levenshtein), "hous", 1);
+/// assert_eq!(suggestions[0].word.as_deref(), Some("house"));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lang {
+    #[cfg(feature = "lang-en")]
+    En,
+    #[cfg(feature = "lang-de")]
+    De,
+}
+
+impl Lang {
+    /// The ISO 639-1 code of the language, like "en".
+    pub fn code(self) -> &'static str {
+        match self {
+            #[cfg(feature = "lang-en")]
+            Lang::En => "en",
+            #[cfg(feature = "lang-de")]
+            Lang::De => "de",
+        }
+    }
+
+    /// The words of the built-in list, most frequent first, with the forms of each word right after it.
+    /// Use it to fill other indexes, like a [TrieDictionary](struct.TrieDictionary.html).
+    pub fn words(self) -> impl Iterator<Item = String> {
+        let (list, inflect): (&str, Inflect) = match self {
+            #[cfg(feature = "lang-en")]
+            Lang::En => (include_str!("lang/en.txt"), english),
+            #[cfg(feature = "lang-de")]
+            Lang::De => (include_str!("lang/de.txt"), german),
+        };
+        let mut seen = HashSet::new();
+        let mut words = Vec::new();
+        for entry in list.split_whitespace() {
+            let (word, flags) = entry.split_once('/').unwrap_or((entry, ""));
+            // A stem that is only there for its forms, like the "größt" of "größte"
+            let (word, stem_only) = match word.strip_suffix('-') {
+                Some(stem) => (stem, true),
+                None => (word, false),
+            };
+            let base = (!stem_only).then(|| word.replace('|', ""));
+            let forms = flags.chars().flat_map(|flag| inflect(word, flag, flags));
+            for form in base.into_iter().chain(forms) {
+                if seen.insert(form.clone()) {
+                    words.push(form);
+                }
+            }
+        }
+        words.into_iter()
+    }
+}
+
+/// The forms of a word of the list for one of its flags, given all its flags.
+type Inflect = fn(&str, char, &str) -> Vec<String>;
+
+// The lists are compressed like the .dic files of hunspell: they are made of entries separated by whitespace,
+// each a word followed by the flags of its regular forms after a `/`, so "walk/V" stands for
+// "walk walks walked walking". The irregular forms are entries of their own, right after their word.
+// A word ending in `-` is a stem that only stands for its forms, like the "größt-/A" of "größte" and "größten".
+
+/// The forms of an English word for one of its flags:
+/// * `S`: The plural of a noun, or the third person of a verb: cats, boxes, cities.
+/// * `E`: The past of a verb: walked, liked, tried.
+/// * `G`: The present participle of a verb: walking, liking, dying.
+/// * `V`: All the forms of a regular verb, `S`, `E` and `G`.
+/// * `C`: The comparative and the superlative of an adjective: faster, fastest, nicer, happier.
+/// * `L`: The adverb of an adjective: quickly, happily, simply, basically.
+/// * `D`: Doubles the last consonant before the endings of `E`, `G` and `C`: stopped, running, bigger.
+#[cfg(feature = "lang-en")]
+fn english(word: &str, flag: char, flags: &str) -> Vec<String> {
+    let double = |ending: &str| {
+        let last = word.chars().last().unwrap_or_default();
+        format!("{word}{last}{ending}")
+    };
+    let doubled = flags.contains('D');
+    let consonant_y = word.ends_with('y') && !word[..word.len() - 1].ends_with(is_vowel);
+    let stem = &word[..word.len() - word.chars().last().map_or(0, char::len_utf8)];
+    match flag {
+        'S' => {
+            let form = if word.ends_with(['s', 'x', 'z'])
+                || word.ends_with("ch")
+                || word.ends_with("sh")
+            {
+                format!("{word}es")
+            } else if consonant_y {
+                format!("{stem}ies")
+            } else {
+                format!("{word}s")
+            };
+            vec![form]
+        }
+        'E' => vec![if doubled {
+            double("ed")
+        } else if word.ends_with('e') {
+            format!("{word}d")
+        } else if consonant_y {
+            format!("{stem}ied")
+        } else {
+            format!("{word}ed")
+        }],
+        'G' => vec![if doubled {
+            double("ing")
+        } else if let Some(stem) = word.strip_suffix("ie") {
+            format!("{stem}ying")
+        } else if word.len() > 2
+            && word.ends_with('e')
+            && !["ee", "ye", "oe"].iter().any(|end| word.ends_with(end))
+        {
+            format!("{stem}ing")
+        } else {
+            format!("{word}ing")
+        }],
+        'V' => ['S', 'E', 'G']
+            .into_iter()
+            .flat_map(|flag| english(word, flag, flags))
+            .collect(),
+        'C' => ["er", "est"]
+            .into_iter()
+            .map(|ending| {
+                if doubled {
+                    double(ending)
+                } else if word.ends_with('e') {
+                    format!("{word}{}", &ending[1..])
+                } else if consonant_y {
+                    format!("{stem}i{ending}")
+                } else {
+                    format!("{word}{ending}")
+                }
+            })
+            .collect(),
+        'L' => vec![if consonant_y {
+            format!("{stem}ily")
+        } else if word.ends_with("le") && !word[..word.len() - 2].ends_with(is_vowel) {
+            format!("{stem}y")
+        } else if word.ends_with("ll") {
+            format!("{word}y")
+        } else if word.ends_with("ic") {
+            format!("{word}ally")
+        } else {
+            format!("{word}ly")
+        }],
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(feature = "lang-en")]
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+/// The forms of a German word for one of its flags:
+/// * `W`: The forms of a weak verb: sage, sagst, sagt, sagte, sagtest, sagten, sagtet, gesagt.
+///   A `|` after the prefix of a separable verb puts the "ge" and the "zu" after it: vorgestellt, vorzustellen.
+///   A verb with an inseparable prefix, or ending in "ieren", has no "ge": erklärt, studiert.
+/// * `A`: The endings of an adjective: schöne, schönen, schönem, schöner, schönes.
+/// * `K`: The comparative and the superlative of an adjective, with their endings: schneller, schnellere, schnellsten.
+/// * `N`: The dative plural of a plural that doesn't end in "n" or "s": Kindern.
+/// * `G`: The genitive of a masculine or neuter noun: Jahres, Landes, Hauses.
+#[cfg(feature = "lang-de")]
+fn german(word: &str, flag: char, _flags: &str) -> Vec<String> {
+    const ENDINGS: [&str; 5] = ["e", "en", "em", "er", "es"];
+    match flag {
+        'W' => {
+            let (prefix, verb) = word.split_once('|').unwrap_or(("", word));
+            let stem = if verb.ends_with("eln") || verb.ends_with("ern") {
+                &verb[..verb.len() - 1]
+            } else {
+                verb.strip_suffix("en").unwrap_or(verb)
+            };
+            let before = stem.chars().rev().nth(1).unwrap_or_default();
+            let extra_e = stem.ends_with(['d', 't'])
+                || stem.ends_with("chn")
+                || (stem.ends_with(['m', 'n']) && !"aeiouäöülrmnh".contains(before));
+            let e = if extra_e { "e" } else { "" };
+            let du = if extra_e {
+                "est"
+            } else if stem.ends_with(['s', 'ß', 'x', 'z']) {
+                "t"
+            } else {
+                "st"
+            };
+            let ich = match verb.strip_suffix("eln") {
+                Some(base) => format!("{base}le"),
+                None => format!("{stem}e"),
+            };
+            let inseparable = [
+                "be", "emp", "ent", "er", "ge", "hinter", "miss", "über", "um", "unter", "ver",
+                "wider", "zer",
+            ]
+            .iter()
+            .any(|p| verb.starts_with(p) && verb.len() > p.len() + 3);
+            let ge = if inseparable || verb.ends_with("ieren") {
+                ""
+            } else {
+                "ge"
+            };
+            let mut forms: Vec<String> = [
+                ich,
+                format!("{stem}{du}"),
+                format!("{stem}{e}t"),
+                format!("{stem}{e}te"),
+                format!("{stem}{e}test"),
+                format!("{stem}{e}ten"),
+                format!("{stem}{e}tet"),
+            ]
+            .into_iter()
+            .map(|form| format!("{prefix}{form}"))
+            .collect();
+            forms.push(format!("{prefix}{ge}{stem}{e}t"));
+            if !prefix.is_empty() {
+                forms.push(format!("{prefix}zu{verb}"));
+            }
+            forms
+        }
+        'A' => {
+            let stem = adjective_stem(word);
+            ENDINGS
+                .iter()
+                .map(|ending| format!("{stem}{ending}"))
+                .collect()
+        }
+        'K' => {
+            let stem = adjective_stem(word);
+            let comparative = format!("{stem}er");
+            let base = word.strip_suffix('e').unwrap_or(word);
+            let superlative = if base
+                .ends_with(['d', 't', 's', 'ß', 'x', 'z', 'a', 'e', 'i', 'o', 'u'])
+                || base.ends_with("sch")
+            {
+                format!("{base}est")
+            } else {
+                format!("{base}st")
+            };
+            let mut forms = vec![comparative.clone()];
+            forms.extend(
+                ENDINGS
+                    .iter()
+                    .map(|ending| format!("{comparative}{ending}")),
+            );
+            forms.extend(
+                ENDINGS
+                    .iter()
+                    .map(|ending| format!("{superlative}{ending}")),
+            );
+            forms
+        }
+        'N' if !word.ends_with(['n', 's']) => vec![format!("{word}n")],
+        'G' if word.ends_with(['s', 'ß', 'x', 'z']) || word.ends_with("sch") => {
+            vec![format!("{word}es")]
+        }
+        'G' => vec![format!("{word}s")],
+        _ => Vec::new(),
+    }
+}
+
+/// The stem of a German adjective before its endings, without its last "e", or the "e" of "-el", "-euer" and "-auer":
+/// leis, dunkl, teur.
+#[cfg(feature = "lang-de")]
+fn adjective_stem(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix('e') {
+        stem.to_string()
+    } else if word.ends_with("el") || word.ends_with("euer") || word.ends_with("auer") {
+        format!("{}{}", &word[..word.len() - 2], &word[word.len() - 1..])
+    } else {
+        word.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::document::DocumentCorrector;
+    use crate::Dictionary;
+
+    #[cfg(feature = "lang-en")]
+    #[test]
+    fn english_sentences_are_left_as_they_are() {
+        use super::Lang;
+
+        let corrector = DocumentCorrector::new(Dictionary::builtin(Lang::En));
+        for text in [
+            "I understand that you found the movie beautiful and the music wonderful yesterday.",
+            "She opened the window, looked out at the garden, and wondered whether it would rain.",
+            "We hiked to the top of the mountain and slowly made our way back down before it got dark.",
+            "He couldn't remember where he had put his keys, so he searched the kitchen twice.",
+        ] {
+            assert_eq!(corrector.misspellings(text), []);
+            assert_eq!(corrector.correct(text), text);
+        }
+    }
+
+    #[cfg(feature = "lang-de")]
+    #[test]
+    fn german_sentences_are_left_as_they_are() {
+        use super::Lang;
+
+        let corrector = DocumentCorrector::new(Dictionary::builtin(Lang::De));
+        for text in [
+            "Gestern bin ich mit meiner Familie in die Stadt gefahren, weil wir neue Schuhe kaufen wollten.",
+            "Wir haben lange über das Problem gesprochen, aber wir konnten keine Lösung finden.",
+            "Meine Schwester arbeitet als Lehrerin und hat sich gestern bei uns vorgestellt.",
+        ] {
+            assert_eq!(corrector.misspellings(text), []);
+            assert_eq!(corrector.correct(text), text);
+        }
+    }
+}
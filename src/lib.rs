@@ -1,4 +1,102 @@
-pub mod levenshtein;
+mod algorithm_info;
+pub mod automaton;
+#[cfg(feature = "bench_support")]
+pub mod bench_support;
+#[cfg(feature = "serialize")]
+mod binary;
+mod cache;
+mod candidates;
+pub mod config;
+pub mod confusion;
+mod corrector;
+mod dictionary;
+pub mod diff;
+pub mod difflib;
+pub mod document;
+pub mod email;
+pub mod ensemble;
+mod error;
+#[cfg(feature = "eval")]
+pub mod eval;
+#[cfg(feature = "capi")]
+pub mod ffi;
+pub mod flags;
+#[cfg(feature = "fst")]
+mod fst_dictionary;
+mod fuzzy_map;
+mod granularity;
+#[cfg(feature = "hunspell")]
+pub mod hunspell;
+pub mod identifiers;
+mod incremental;
+mod instrument;
+#[cfg(feature = "jsonl")]
+pub mod io;
+#[cfg(any(feature = "lang-en", feature = "lang-de"))]
+mod lang;
+pub mod metrics;
+#[cfg(feature = "minhash")]
+pub mod minhash;
+#[cfg(feature = "mmap")]
+mod mmap;
+mod normalizer;
+pub mod numeric;
+mod partition;
+pub mod paths;
+#[cfg(any(
+    feature = "preset-countries",
+    feature = "preset-us-states",
+    feature = "preset-currencies"
+))]
+pub mod presets;
+#[cfg(feature = "python")]
+pub mod python;
+mod query;
+mod scorer;
+pub mod search;
+mod short_words;
+mod spell_checker;
+mod threshold;
+mod tie_break;
+mod trace;
+#[cfg(feature = "translit")]
+pub mod translit;
+mod trie;
+pub mod typosquat;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use algorithm_info::AlgorithmInfo;
+pub use cache::CachedCorrector;
+pub use candidates::CandidateSource;
+pub use corrector::Corrector;
+pub use dictionary::Dictionary;
+pub use error::{Cancelled, CorrectionError, UnknownAlgorithm};
+#[cfg(feature = "fst")]
+pub use fst_dictionary::FstDictionary;
+pub use fuzzy_map::FuzzyMap;
+pub use granularity::Granularity;
+pub use incremental::IncrementalMatcher;
+#[cfg(any(feature = "lang-en", feature = "lang-de"))]
+pub use lang::Lang;
+use levenshtein::{EditOp, LevenshteinState};
+// The metrics used to be modules of their own at the root
+pub use metrics::{
+    alignment, cosine, damerau, fuzz, jaccard, lcs, levenshtein, ratcliff_obershelp, sift4,
+    subsequence,
+};
+#[cfg(feature = "mmap")]
+pub use mmap::MmapDictionary;
+pub use normalizer::Normalizer;
+pub use partition::Partition;
+pub use query::Query;
+pub use scorer::Scorer;
+pub use short_words::ShortWords;
+pub use spell_checker::SpellChecker;
+pub use threshold::Threshold;
+pub use tie_break::TieBreak;
+pub use trace::{ScoringTrace, TraceEntry, TraceOutcome};
+pub use trie::TrieDictionary;
 
 /// # Struct: Correct Word
 /// A struct used to represent the result of the correct function.
@@ -6,9 +104,184 @@ pub mod levenshtein;
 /// The word is an optional string, because the function might not be able to correct the word, given the threshold.
 /// The confidence is the similarity between the input and the corrected word between 0 to 1.
 /// The higher the similarity, the better the correction.
+/// The ops are the [edits](metrics/levenshtein/enum.EditOp.html) that turn the input into the corrected word.
+/// They are only there when asked for with [Corrector::edit_ops](struct.Corrector.html#method.edit_ops), and there is a word.
+#[derive(Debug, Clone, PartialEq)]
 pub struct CorrectWord {
     pub word: Option<String>,
     pub confidence: f64,
+    pub ops: Option<Vec<EditOp>>,
+}
+
+/// # Struct: Correct Word Ref
+/// A [CorrectWord](struct.CorrectWord.html) whose word borrows from the options instead of being a copy of it,
+/// returned by [Corrector::correct_ref](struct.Corrector.html#method.correct_ref) and
+/// [Corrector::correct_n_ref](struct.Corrector.html#method.correct_n_ref).
+/// Correcting many inputs against the same options then allocates nothing for the words.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, CorrectWord, Corrector};
+///
+/// let options = vec!["hello".to_string(), "world".to_string()];
+/// let result = Corrector::new(Algorithm::Levenshtein).correct_ref("helo", &options);
+/// assert!(std::ptr::eq(result.word.unwrap(), options[0].as_str()));
+///
+/// let owned: CorrectWord = result.into_owned();
+/// assert_eq!(owned.word.as_deref(), Some("hello"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorrectWordRef<'a> {
+    pub word: Option<&'a str>,
+    pub confidence: f64,
+    pub ops: Option<Vec<EditOp>>,
+}
+
+impl CorrectWordRef<'_> {
+    /// Copies the word, to keep the correction around longer than the options.
+    pub fn into_owned(self) -> CorrectWord {
+        CorrectWord {
+            word: self.word.map(str::to_string),
+            confidence: self.confidence,
+            ops: self.ops,
+        }
+    }
+}
+
+impl From<CorrectWordRef<'_>> for CorrectWord {
+    fn from(correction: CorrectWordRef<'_>) -> Self {
+        correction.into_owned()
+    }
+}
+
+/// # Struct: Match
+/// An option that cleared the threshold, with every number the [Corrector](struct.Corrector.html) knows about it,
+/// returned by [Corrector::matches](struct.Corrector.html#method.matches) to tune thresholds without scoring anything again.
+///
+/// * `word`: The option, borrowed from the options.
+/// * `index`: The position of the option in the options.
+/// * `rank`: The position of the option among the matches, best first, from 1 for the correction.
+/// * `distance`: The distance of the scorer between the input and the option, like the number of Levenshtein edits.
+/// * `similarity`: The similarity of the scorer, from 0 to 1, before the [prefix bonus](struct.Corrector.html#method.prefix_bonus)
+///   or the [accents](struct.Corrector.html#method.ignore_accents) change it.
+/// * `confidence`: The score the options are ranked by and the threshold is checked against, like the confidence of a
+///   [CorrectWord](struct.CorrectWord.html).
+///
+/// The distance and the similarity are of the strings after the [normalizer](struct.Corrector.html#method.normalizer).
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector};
+///
+/// let options = ["world", "hello", "help"];
+/// let corrector = Corrector::new(Algorithm::Levenshtein).prefix_bonus(4, 0.1);
+/// let matches = corrector.matches("helo", &options, 2);
+///
+/// assert_eq!(matches[0].word, "hello");
+/// assert_eq!((matches[0].index, matches[0].rank), (1, 1));
+/// assert_eq!((matches[0].distance, matches[0].similarity), (1, 0.8));
+/// assert!(matches[0].confidence > matches[0].similarity);
+/// assert_eq!((matches[1].word, matches[1].rank, matches[1].distance), ("help", 2, 1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Match<'a> {
+    pub word: &'a str,
+    pub index: usize,
+    pub rank: usize,
+    pub distance: u16,
+    pub similarity: f64,
+    pub confidence: f64,
+}
+
+/// # Struct: Correct Payload
+/// A [CorrectWordRef](struct.CorrectWordRef.html) that also borrows the payload of the corrected option,
+/// returned by [Corrector::correct_payload](struct.Corrector.html#method.correct_payload) for options that are `(word, payload)` pairs.
+/// The payload is there exactly when the word is.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Command {
+///     Status,
+///     Commit,
+/// }
+///
+/// let commands = [("status", Command::Status), ("commit", Command::Commit)];
+/// let result = Corrector::new(Algorithm::Levenshtein).correct_payload("comit", &commands);
+/// assert_eq!(result.word, Some("commit"));
+/// assert_eq!(result.payload, Some(&Command::Commit));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorrectPayload<'a, T> {
+    pub word: Option<&'a str>,
+    pub payload: Option<&'a T>,
+    pub confidence: f64,
+    pub ops: Option<Vec<EditOp>>,
+}
+
+/// # Struct: Correct Payloads
+/// The result of correcting a word with [Corrector::correct_payloads](struct.Corrector.html#method.correct_payloads),
+/// like a [CorrectPayload](struct.CorrectPayload.html) with every payload of the corrected word,
+/// for options where the same word comes more than once. The payloads are empty when there is no correction.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector};
+///
+/// let aliases = [("rm", "remove"), ("ls", "list"), ("rm", "rmdir")];
+/// let result = Corrector::new(Algorithm::Levenshtein).threshold(0.5).correct_payloads("rn", &aliases);
+/// assert_eq!(result.word, Some("rm"));
+/// assert_eq!(result.payloads, [&"remove", &"rmdir"]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorrectPayloads<'a, T> {
+    pub word: Option<&'a str>,
+    pub payloads: Vec<&'a T>,
+    pub confidence: f64,
+    pub ops: Option<Vec<EditOp>>,
+}
+
+/// # Struct: Correct Bytes
+/// The result of correcting a byte string with [Corrector::correct_bytes](struct.Corrector.html#method.correct_bytes),
+/// like a [CorrectWord](struct.CorrectWord.html) whose word doesn't have to be valid UTF-8.
+/// There are no edit ops, since those count characters.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector};
+///
+/// let options: [&[u8]; 2] = [b"caf\xe9", b"bar"];
+/// let result = Corrector::new(Algorithm::Levenshtein).correct_bytes(b"cafe", &options);
+/// assert_eq!(result.word.as_deref(), Some(&b"caf\xe9"[..]));
+/// assert_eq!(result.confidence, 0.75);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorrectBytes {
+    pub word: Option<Vec<u8>>,
+    pub confidence: f64,
+}
+
+/// # Struct: Correct Os Str
+/// The result of correcting an `OsStr`, like a file name, with [Corrector::correct_os_str](struct.Corrector.html#method.correct_os_str),
+/// like a [CorrectWord](struct.CorrectWord.html) whose word is a copy of the option as it was,
+/// even when it isn't valid UTF-8.
+///
+/// # Example
+/// ```
+/// use correct_word::paths::correct_os_str;
+/// use std::ffi::OsStr;
+/// use std::path::{Path, PathBuf};
+///
+/// let candidates = vec![PathBuf::from("src/lib.rs"), PathBuf::from("src/bin/main.rs")];
+/// let result = correct_os_str(OsStr::new("src/lbi.rs"), &candidates);
+/// assert_eq!(result.word.as_deref(), Some(Path::new("src/lib.rs").as_os_str()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorrectOsStr {
+    pub word: Option<std::ffi::OsString>,
+    pub confidence: f64,
 }
 
 /// # Enum: Algorithm
@@ -16,6 +289,15 @@ pub struct CorrectWord {
 ///
 /// Currently, the following algorithms are supported:
 /// * Levenshtein: A simple algorithm that calculates the distance between two strings. The lower the distance, the better the correction.
+/// * Lcs: Uses the longest common subsequence of the two strings. More forgiving of inserted characters, like "colour" and "color".
+/// * RatcliffObershelp: The gestalt pattern matching used by Python's difflib. Gives the same scores as `SequenceMatcher.ratio()`.
+/// * Partial: Finds the input inside the option, or the option inside the input, without penalising the extra characters. See [partial_similarity](metrics/fuzz/fn.partial_similarity.html).
+/// * Osa: Levenshtein, where swapping two adjacent characters is a single edit, but a swapped pair is not edited again. See [osa_distance](metrics/damerau/fn.osa_distance.html).
+/// * DamerauLevenshtein: The true Damerau-Levenshtein distance, which allows editing between swapped characters. See [damerau_levenshtein_distance](metrics/damerau/fn.damerau_levenshtein_distance.html).
+/// * Subsequence: Matches the input as an abbreviation of the option, like "gcm" for "git commit message". See [subsequence_similarity](metrics/subsequence/fn.subsequence_similarity.html).
+/// * Cosine: The cosine similarity of the character bigrams, which ignores their order and scales to long strings like titles. See [cosine_similarity](metrics/cosine/fn.cosine_similarity.html).
+/// * Jaccard: The Jaccard index of the sets of character bigrams, for finding near-duplicates. See [jaccard_similarity](metrics/jaccard/fn.jaccard_similarity.html).
+/// * Sift4: A linear time approximation of the edit distance, for matching as the user types. See [sift4_distance](metrics/sift4/fn.sift4_distance.html).
 ///
 /// # Example
 /// This enum is used as an argument to the correct function.
@@ -26,8 +308,64 @@ pub struct CorrectWord {
 /// let result = correct_word(Algorithm::Levenshtein, "hilo".to_string(), vec!["hello".to_string(), "world".to_string()], None);
 /// assert_eq!(result.word.unwrap(), "hello");
 /// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Algorithm {
     Levenshtein,
+    Lcs,
+    RatcliffObershelp,
+    Partial,
+    Osa,
+    DamerauLevenshtein,
+    Subsequence,
+    Cosine,
+    Jaccard,
+    Sift4,
+}
+
+impl Algorithm {
+    /// Every algorithm, in the order they are listed in, to go through them at runtime.
+    /// Their names and properties are in [AlgorithmInfo](trait.AlgorithmInfo.html).
+    pub const ALL: [Algorithm; 10] = [
+        Algorithm::Levenshtein,
+        Algorithm::Lcs,
+        Algorithm::RatcliffObershelp,
+        Algorithm::Partial,
+        Algorithm::Osa,
+        Algorithm::DamerauLevenshtein,
+        Algorithm::Subsequence,
+        Algorithm::Cosine,
+        Algorithm::Jaccard,
+        Algorithm::Sift4,
+    ];
+}
+
+impl std::str::FromStr for Algorithm {
+    type Err = UnknownAlgorithm;
+
+    /// Parses an algorithm from its name in kebab case, like "levenshtein" or "ratcliff-obershelp".
+    /// The case doesn't matter, and underscores work as well as dashes.
+    ///
+    /// ```
+    /// use correct_word::Algorithm;
+    ///
+    /// assert_eq!("ratcliff_obershelp".parse(), Ok(Algorithm::RatcliffObershelp));
+    /// assert!("soundex".parse::<Algorithm>().is_err());
+    /// ```
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_lowercase().replace('_', "-").as_str() {
+            "levenshtein" => Ok(Algorithm::Levenshtein),
+            "lcs" => Ok(Algorithm::Lcs),
+            "ratcliff-obershelp" => Ok(Algorithm::RatcliffObershelp),
+            "partial" => Ok(Algorithm::Partial),
+            "osa" => Ok(Algorithm::Osa),
+            "damerau-levenshtein" => Ok(Algorithm::DamerauLevenshtein),
+            "subsequence" => Ok(Algorithm::Subsequence),
+            "cosine" => Ok(Algorithm::Cosine),
+            "jaccard" => Ok(Algorithm::Jaccard),
+            "sift4" => Ok(Algorithm::Sift4),
+            _ => Err(UnknownAlgorithm(name.to_string())),
+        }
+    }
 }
 
 /// Correct a word from a list of options.
@@ -37,13 +375,13 @@ pub enum Algorithm {
 /// * `algorithm` - The algorithm to use to correct the word. The algorithm is an enum, as defined in the [Algorithm](enum.Algorithm.html) enum.
 /// * `input` - The word to correct.
 /// * `options` - A list of options to correct the word to.
-/// * `threshold` - The maximum distance between the input and the corrected word. If the distance is greater than the threshold, the function will return None.
+/// * `threshold` - The minimum similarity between the input and the corrected word. If the best option is less similar than the threshold, the function will return None.
 ///
 /// # Returns
 ///
-/// `[CorrectWord](type.CorrectWord.html) = (Option<String>, f64))`
+/// `[CorrectWord](struct.CorrectWord.html)`
 ///
-/// This function returns a tuple of an optional string and a f64. The string is the corrected word, and the f64 is the similary between the input and the corrected word in the range from 0 to 1
+/// This function returns the corrected word, if any, and the similarity between the input and the corrected word in the range from 0 to 1.
 ///
 /// # Example
 /// ```
@@ -56,43 +394,328 @@ pub enum Algorithm {
 ///
 /// # Note
 ///
-/// All the algorithms used in this crate return a distance between the input and the corrected word.
-/// The lower the distance, the better the correction.
+/// All the algorithms used in this crate are turned into a similarity between the input and the corrected word.
+/// The higher the similarity, the better the correction.
 /// You can use the threshold to make sure that the function doesn't return a word that is too different from the input.
-/// By default, the threshold is 0.5.
 /// So, it is up to you to choose the level of tolerance you want.
 /// Usually, a threshold of 0.5 is a good value which is the default.
+///
+/// To limit the number of edits instead, like "at most 2 typos", use a [Corrector](struct.Corrector.html)
+/// with a [Threshold::MaxDistance](enum.Threshold.html).
 pub fn correct_word(
     algorithm: Algorithm,
     input: String,
     options: Vec<String>,
     threshold: Option<f64>,
 ) -> CorrectWord {
-    let mut best = String::new();
-    let mut best_now = 0.0;
-    options.iter().for_each(|option| {
-        let distance = match algorithm {
-            Algorithm::Levenshtein => {
-                levenshtein::levenshtein_similarity(input.to_string(), option.to_string())
+    Corrector::new(algorithm)
+        .threshold(threshold.unwrap_or(0.5))
+        .correct(&input, &options)
+}
+
+/// Correct a word from a list of options, using your own [Scorer](trait.Scorer.html) instead of one of the algorithms.
+/// Works like the [correct_word](fn.correct_word.html) function in every other way.
+///
+/// # Arguments
+/// * `scorer` - The scorer to use to correct the word. Any `Fn(&str, &str) -> f64` closure is a scorer too.
+/// * `input` - The word to correct.
+/// * `options` - A list of options to correct the word to.
+/// * `threshold` - The minimum similarity between the input and the corrected word. By default, the threshold is 0.5.
+///
+/// # Returns
+///
+/// `[CorrectWord](struct.CorrectWord.html)`
+///
+/// # Example
+/// ```
+/// use correct_word::correct_word_with;
+///
+/// let shared_prefix = |a: &str, b: &str| {
+///     let shared = a.chars().zip(b.chars()).take_while(|(a, b)| a == b).count();
+///     shared as f64 / a.chars().count().max(b.chars().count()) as f64
+/// };
+/// let result = correct_word_with(shared_prefix, "hel".to_string(), vec!["help".to_string(), "shell".to_string()], None);
+/// assert_eq!(result.word.unwrap(), "help");
+/// ```
+pub fn correct_word_with<S: Scorer>(
+    scorer: S,
+    input: String,
+    options: Vec<String>,
+    threshold: Option<f64>,
+) -> CorrectWord {
+    Corrector::new(scorer)
+        .threshold(threshold.unwrap_or(0.5))
+        .correct(&input, &options)
+}
+
+/// Correct a word from a list of options, checking the arguments first.
+/// Works like the [correct_word](fn.correct_word.html) function, but returns an error
+/// instead of a meaningless result when the arguments don't make sense.
+///
+/// # Arguments
+/// * `algorithm` - The algorithm to use to correct the word, as defined in the [Algorithm](enum.Algorithm.html) enum.
+/// * `input` - The word to correct. It can't be empty.
+/// * `options` - A list of options to correct the word to. It can't be empty.
+/// * `threshold` - The minimum similarity, from 0 to 1, the corrected word needs. By default, the threshold is 0.5.
+///
+/// # Returns
+///
+/// `Result<CorrectWord, CorrectionError>` - The correction, or the [CorrectionError](enum.CorrectionError.html) describing what is wrong with the arguments.
+/// A correction without a word, because no option cleared the threshold, is still `Ok`.
+///
+/// # Example
+/// ```
+/// use correct_word::{try_correct_word, Algorithm, CorrectionError};
+///
+/// let result = try_correct_word(Algorithm::Levenshtein, "hilo".to_string(), vec!["hello".to_string()], None);
+/// assert_eq!(result.unwrap().word.unwrap(), "hello");
+///
+/// let result = try_correct_word(Algorithm::Levenshtein, "hilo".to_string(), vec![], None);
+/// assert_eq!(result.err(), Some(CorrectionError::EmptyOptions));
+///
+/// let result = try_correct_word(Algorithm::Levenshtein, "hilo".to_string(), vec!["hello".to_string()], Some(7.3));
+/// assert_eq!(result.err(), Some(CorrectionError::InvalidThreshold(7.3)));
+/// ```
+pub fn try_correct_word(
+    algorithm: Algorithm,
+    input: String,
+    options: Vec<String>,
+    threshold: Option<f64>,
+) -> Result<CorrectWord, CorrectionError> {
+    Corrector::new(algorithm)
+        .threshold(threshold.unwrap_or(0.5))
+        .try_correct(&input, &options)
+}
+
+/// Correct a word from a list of options, returning up to `n` of the best options.
+/// Works like the [correct_word](fn.correct_word.html) function, but instead of only the best option,
+/// it returns every option that clears the threshold, best first, capped at `n` results.
+///
+/// # Arguments
+/// * `algorithm` - The algorithm to use to correct the word, as defined in the [Algorithm](enum.Algorithm.html) enum.
+/// * `input` - The word to correct.
+/// * `options` - A list of options to correct the word to.
+/// * `n` - The maximum number of corrections to return.
+/// * `threshold` - The minimum similarity an option needs to be returned. By default, the threshold is 0.5.
+///
+/// # Returns
+///
+/// `Vec<CorrectWord>` - The corrections, sorted from the highest confidence to the lowest.
+/// Options with the same confidence keep the order they had in `options`.
+/// Every returned [CorrectWord](struct.CorrectWord.html) has a word.
+///
+/// # Example
+/// ```
+/// use correct_word::correct_word_n;
+/// use correct_word::Algorithm;
+///
+/// let options = vec!["hello".to_string(), "help".to_string(), "world".to_string()];
+/// let result = correct_word_n(Algorithm::Levenshtein, "helo".to_string(), options, 2, None);
+/// assert_eq!(result.len(), 2);
+/// assert_eq!(result[0].word.as_deref(), Some("hello"));
+/// assert_eq!(result[1].word.as_deref(), Some("help"));
+/// ```
+pub fn correct_word_n(
+    algorithm: Algorithm,
+    input: String,
+    options: Vec<String>,
+    n: usize,
+    threshold: Option<f64>,
+) -> Vec<CorrectWord> {
+    Corrector::new(algorithm)
+        .threshold(threshold.unwrap_or(0.5))
+        .correct_n(&input, &options, n)
+}
+
+/// Correct a word from a list of options, returning every option that clears the threshold.
+/// Works like [correct_word_n](fn.correct_word_n.html) without a cap on the number of corrections,
+/// for record linkage and other workflows that pick between the matches themselves.
+///
+/// # Arguments
+/// * `algorithm` - The algorithm to use to correct the word, as defined in the [Algorithm](enum.Algorithm.html) enum.
+/// * `input` - The word to correct.
+/// * `options` - A list of options to correct the word to.
+/// * `threshold` - The minimum similarity an option needs to be returned. By default, the threshold is 0.5.
+///
+/// # Returns
+///
+/// `Vec<CorrectWord>` - The corrections, sorted from the highest confidence to the lowest.
+/// Options with the same confidence keep the order they had in `options`.
+///
+/// # Example
+/// ```
+/// use correct_word::correct_word_all;
+/// use correct_word::Algorithm;
+///
+/// let options = vec!["hello".to_string(), "help".to_string(), "world".to_string(), "hell".to_string()];
+/// let result = correct_word_all(Algorithm::Levenshtein, "helo".to_string(), options, None);
+/// let words: Vec<_> = result.iter().filter_map(|correction| correction.word.as_deref()).collect();
+/// assert_eq!(words, vec!["hello", "help", "hell"]);
+/// ```
+pub fn correct_word_all(
+    algorithm: Algorithm,
+    input: String,
+    options: Vec<String>,
+    threshold: Option<f64>,
+) -> Vec<CorrectWord> {
+    Corrector::new(algorithm)
+        .threshold(threshold.unwrap_or(0.5))
+        .all_matches(&input, &options)
+}
+
+/// Scores every option lazily with the given algorithm, yielding its index, the option and its similarity to the input,
+/// in the order of `options`.
+/// Nothing is filtered or ranked, so you can combine it with your own filtering, sorting or early exit.
+/// Use [Corrector::score_iter](struct.Corrector.html#method.score_iter) for the options that clear a threshold.
+///
+/// # Arguments
+/// * `input` - The word to correct.
+/// * `options` - A list of options to correct the word to.
+/// * `algorithm` - The algorithm to score the options with.
+///
+/// # Returns
+///
+/// `impl Iterator<Item = (usize, &str, f64)>` - The index, the option and the similarity of every option.
+///
+/// # Example
+/// ```
+/// use correct_word::{score_iter, Algorithm};
+///
+/// let options = ["hello", "world", "help"];
+/// let mut scores = score_iter("helo", &options, Algorithm::Levenshtein);
+/// assert_eq!(scores.next(), Some((0, "hello", 0.8)));
+///
+/// let close: Vec<usize> = score_iter("helo", &options, Algorithm::Levenshtein)
+///     .filter(|&(_, _, similarity)| similarity >= 0.75)
+///     .map(|(index, _, _)| index)
+///     .collect();
+/// assert_eq!(close, vec![0, 2]);
+/// ```
+pub fn score_iter<'a, 'b, O: AsRef<str>>(
+    input: &'b str,
+    options: &'a [O],
+    algorithm: Algorithm,
+) -> impl Iterator<Item = (usize, &'a str, f64)> + 'b
+where
+    'a: 'b,
+{
+    options.iter().enumerate().map(move |(index, option)| {
+        let option = option.as_ref();
+        (index, option, algorithm.similarity(input, option))
+    })
+}
+
+impl Algorithm {
+    pub(crate) fn similarity_with_state(
+        &self,
+        state: &mut LevenshteinState,
+        input: &str,
+        option: &str,
+    ) -> f64 {
+        use metrics::common::chars;
+        match self {
+            Algorithm::Levenshtein => state.similarity(input, option),
+            Algorithm::Lcs => lcs::lcs_similarity_slice(&chars(input), &chars(option)),
+            // Same argument order as difflib's get_close_matches: the option is `a` and the input is `b`
+            Algorithm::RatcliffObershelp => {
+                ratcliff_obershelp::ratio(&chars(option), &chars(input))
             }
-        };
-        if distance > best_now {
-            best = option.to_string();
-            best_now = distance;
+            Algorithm::Partial => fuzz::best_window(&chars(input), &chars(option)).0,
+            Algorithm::Osa => damerau::osa_similarity_slice(&chars(input), &chars(option)),
+            Algorithm::DamerauLevenshtein => {
+                damerau::damerau_levenshtein_similarity_slice(&chars(input), &chars(option))
+            }
+            Algorithm::Subsequence => {
+                subsequence::SubsequenceScorer::default().similarity(input, option)
+            }
+            Algorithm::Cosine => cosine::CosineScorer::new(2).similarity(input, option),
+            Algorithm::Jaccard => {
+                jaccard::JaccardScorer::new(jaccard::Shingles::Chars(2)).similarity(input, option)
+            }
+            Algorithm::Sift4 => sift4::Sift4Scorer::default().similarity(input, option),
         }
-    });
+    }
 
-    if best_now < threshold.unwrap_or(0.5) {
-        CorrectWord {
-            word: None,
-            confidence: best_now,
-        }
-    } else {
-        CorrectWord {
-            word: Some(best),
-            confidence: best_now,
+    pub(crate) fn distance_with_state(
+        &self,
+        state: &mut LevenshteinState,
+        input: &str,
+        option: &str,
+    ) -> u16 {
+        use metrics::common::chars;
+        let distance = match self {
+            Algorithm::Levenshtein => return state.distance(input, option),
+            Algorithm::Lcs => lcs::lcs_distance_slice(&chars(input), &chars(option)),
+            Algorithm::RatcliffObershelp => {
+                ratcliff_obershelp::unmatched(&chars(option), &chars(input))
+            }
+            Algorithm::Partial => fuzz::best_window(&chars(input), &chars(option)).1,
+            Algorithm::Osa => damerau::osa_distance_slice(&chars(input), &chars(option)),
+            Algorithm::DamerauLevenshtein => {
+                damerau::damerau_levenshtein_distance_slice(&chars(input), &chars(option))
+            }
+            Algorithm::Subsequence => return subsequence::unmatched(&chars(input), &chars(option)),
+            Algorithm::Cosine => return cosine::CosineScorer::new(2).distance(input, option),
+            Algorithm::Jaccard => {
+                return jaccard::JaccardScorer::new(jaccard::Shingles::Chars(2))
+                    .distance(input, option)
+            }
+            Algorithm::Sift4 => return sift4::Sift4Scorer::default().distance(input, option),
+        };
+        distance as u16
+    }
+
+    /// Like `similarity_with_state`, over strings already split into the units of a [Granularity](enum.Granularity.html).
+    pub(crate) fn similarity_tokens(
+        &self,
+        state: &mut LevenshteinState,
+        input: &[&[u8]],
+        option: &[&[u8]],
+    ) -> f64 {
+        match self {
+            Algorithm::Levenshtein => metrics::common::similarity(
+                state.distance_slice(input, option),
+                std::cmp::max(input.len(), option.len()),
+            ),
+            Algorithm::Lcs => lcs::lcs_similarity_slice(input, option),
+            Algorithm::RatcliffObershelp => ratcliff_obershelp::ratio(option, input),
+            Algorithm::Partial => fuzz::best_window(input, option).0,
+            Algorithm::Osa => damerau::osa_similarity_slice(input, option),
+            Algorithm::DamerauLevenshtein => {
+                damerau::damerau_levenshtein_similarity_slice(input, option)
+            }
+            Algorithm::Subsequence => {
+                subsequence::SubsequenceScorer::default().similarity_slices(input, option)
+            }
+            Algorithm::Cosine => cosine::similarity_slices(input, option, 2),
+            Algorithm::Jaccard => jaccard::similarity_slices(input, option, 2),
+            Algorithm::Sift4 => sift4::similarity_slices(input, option, 5),
         }
     }
+
+    /// Like `distance_with_state`, over strings already split into the units of a [Granularity](enum.Granularity.html).
+    pub(crate) fn distance_tokens(
+        &self,
+        state: &mut LevenshteinState,
+        input: &[&[u8]],
+        option: &[&[u8]],
+    ) -> u16 {
+        let distance = match self {
+            Algorithm::Levenshtein => state.distance_slice(input, option),
+            Algorithm::Lcs => lcs::lcs_distance_slice(input, option),
+            Algorithm::RatcliffObershelp => ratcliff_obershelp::unmatched(option, input),
+            Algorithm::Partial => fuzz::best_window(input, option).1,
+            Algorithm::Osa => damerau::osa_distance_slice(input, option),
+            Algorithm::DamerauLevenshtein => {
+                damerau::damerau_levenshtein_distance_slice(input, option)
+            }
+            Algorithm::Subsequence => return subsequence::unmatched(input, option),
+            Algorithm::Cosine => cosine::distance_slices(input, option, 2),
+            Algorithm::Jaccard => jaccard::distance_slices(input, option, 2),
+            Algorithm::Sift4 => sift4::distance_slices(input, option, 5),
+        };
+        distance as u16
+    }
 }
 
 #[cfg(test)]
@@ -109,4 +732,161 @@ mod tests {
         );
         assert_eq!(result.word.unwrap(), "hi");
     }
+
+    #[test]
+    fn lcs_test() {
+        let result = correct_word(
+            Algorithm::Lcs,
+            "colr".to_string(),
+            vec!["colour".to_string(), "cool".to_string(), "car".to_string()],
+            None,
+        );
+        assert_eq!(result.word.unwrap(), "colour");
+    }
+
+    #[test]
+    fn partial_test() {
+        let options = vec!["yellow".to_string(), "oh hello there".to_string()];
+        let result = correct_word(
+            Algorithm::Partial,
+            "hello".to_string(),
+            options.clone(),
+            None,
+        );
+        assert_eq!(result.word.unwrap(), "oh hello there");
+
+        let result = correct_word(Algorithm::Levenshtein, "hello".to_string(), options, None);
+        assert_eq!(result.word.unwrap(), "yellow");
+    }
+
+    #[test]
+    fn subsequence_test() {
+        let options = vec![
+            "magic".to_string(),
+            "git checkout main".to_string(),
+            "git commit message".to_string(),
+        ];
+        let result = correct_word_n(Algorithm::Subsequence, "gcm".to_string(), options, 3, None);
+        let words: Vec<_> = result.iter().map(|c| c.word.as_deref().unwrap()).collect();
+        assert_eq!(words, vec!["git commit message", "git checkout main"]);
+    }
+
+    #[test]
+    fn old_metric_paths_test() {
+        let distance =
+            crate::levenshtein::levenshtein_distance("hilo".to_string(), "hello".to_string());
+        assert_eq!(
+            distance,
+            metrics::levenshtein::levenshtein_distance("hilo".to_string(), "hello".to_string())
+        );
+        assert_eq!(
+            crate::damerau::osa_distance("teh".to_string(), "the".to_string()),
+            1
+        );
+    }
+
+    #[test]
+    fn similarity_edges_test() {
+        let algorithms = [
+            Algorithm::Levenshtein,
+            Algorithm::Lcs,
+            Algorithm::RatcliffObershelp,
+            Algorithm::Partial,
+            Algorithm::Osa,
+            Algorithm::DamerauLevenshtein,
+            Algorithm::Subsequence,
+            Algorithm::Cosine,
+            Algorithm::Jaccard,
+            Algorithm::Sift4,
+        ];
+        let granularities = [Granularity::Bytes, Granularity::Chars, Granularity::Words];
+        for algorithm in algorithms {
+            for granularity in granularities {
+                let similarity = |a, b| algorithm.similarity_with(a, b, granularity);
+                assert_eq!(similarity("", ""), 1.0, "{algorithm:?} {granularity:?}");
+                assert_eq!(similarity("", "abc"), 0.0, "{algorithm:?} {granularity:?}");
+                assert_eq!(similarity("abc", ""), 0.0, "{algorithm:?} {granularity:?}");
+                for (a, b) in [("abc", "abc"), ("kitten", "sitting"), ("a b", "c d e f")] {
+                    let similarity = similarity(a, b);
+                    assert!(
+                        (0.0..=1.0).contains(&similarity),
+                        "{algorithm:?} {a:?} {b:?}"
+                    );
+                }
+            }
+        }
+
+        assert_eq!(fuzz::token_sort_ratio("".to_string(), "".to_string()), 1.0);
+        assert_eq!(fuzz::token_set_ratio("".to_string(), "!".to_string()), 1.0);
+        assert_eq!(fuzz::token_set_ratio("".to_string(), "a".to_string()), 0.0);
+    }
+
+    #[test]
+    fn tie_break_test() {
+        // Every option has a Levenshtein similarity of 0.5 to "ab"
+        let options = ["abzz", "abcd", "xb", "ac"];
+        let words = |tie_break| -> Vec<String> {
+            Corrector::new(Algorithm::Levenshtein)
+                .tie_break(tie_break)
+                .correct_n("ab", &options, 4)
+                .into_iter()
+                .filter_map(|result| result.word)
+                .collect()
+        };
+        assert_eq!(words(TieBreak::First), ["abzz", "abcd", "xb", "ac"]);
+        assert_eq!(words(TieBreak::Shorter), ["xb", "ac", "abzz", "abcd"]);
+        assert_eq!(words(TieBreak::Lexicographic), ["abcd", "abzz", "ac", "xb"]);
+
+        for tie_break in [TieBreak::First, TieBreak::Shorter, TieBreak::Lexicographic] {
+            let corrector = Corrector::new(Algorithm::Levenshtein).tie_break(tie_break);
+            assert_eq!(
+                corrector.correct("ab", &options).word,
+                words(tie_break).first().cloned()
+            );
+        }
+    }
+
+    #[test]
+    fn granularity_words_test() {
+        let corrector = Corrector::new(Algorithm::Levenshtein)
+            .granularity(Granularity::Words)
+            .threshold(Threshold::MaxDistance(1));
+        let options = ["play the next song", "play the previous song", "stop"];
+        let result = corrector.correct("play next song", &options);
+        assert_eq!(result.word.unwrap(), "play the next song");
+    }
+
+    #[test]
+    fn correct_compound_test() {
+        let words = ["where", "is", "the", "love", "a", "cat", "sat", "on", "mat"];
+        let corrector = Corrector::new(Algorithm::Levenshtein);
+        let correct = |input| corrector.correct_compound(input, &words[..]).word;
+        assert_eq!(correct("  the cat  sat ").as_deref(), Some("the cat sat"));
+        assert_eq!(
+            correct("thecatsat onthe mat").as_deref(),
+            Some("the cat sat on the mat")
+        );
+        assert_eq!(
+            correct("wher is th lvoe").as_deref(),
+            Some("where is the love")
+        );
+        let transposed =
+            Corrector::new(Algorithm::Osa).correct_compound("wher is teh love", &words[..]);
+        assert_eq!(transposed.word.as_deref(), Some("where is the love"));
+        // Words without a correction are kept
+        assert_eq!(correct("the cat 42").as_deref(), Some("the cat 42"));
+        assert_eq!(correct(" \t").as_deref(), None);
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn granularity_graphemes_test() {
+        // "e" followed by a combining acute accent is one grapheme but two chars
+        let distance = levenshtein::levenshtein_distance_with(
+            "cafe\u{301}".to_string(),
+            "cafe".to_string(),
+            Granularity::Graphemes,
+        );
+        assert_eq!(distance, 1);
+    }
 }
@@ -0,0 +1,11 @@
+pub mod alignment;
+pub(crate) mod common;
+pub mod cosine;
+pub mod damerau;
+pub mod fuzz;
+pub mod jaccard;
+pub mod lcs;
+pub mod levenshtein;
+pub mod ratcliff_obershelp;
+pub mod sift4;
+pub mod subsequence;
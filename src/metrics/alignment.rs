@@ -0,0 +1,575 @@
+use std::ops::Range;
+
+use crate::metrics::common::{chars, trivial_similarity, Matrix};
+use crate::Scorer;
+
+/// Aligns two strings from end to end with the [Needleman-Wunsch](https://en.wikipedia.org/wiki/Needleman%E2%80%93Wunsch_algorithm) algorithm,
+/// scoring 1 for a match, -1 for a mismatch and -1 for a gap.
+/// Use a [NeedlemanWunsch](struct.NeedlemanWunsch.html) scorer for other scores.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to align.
+/// * `string2` - The second string to align.
+///
+/// # Returns
+///
+/// `Alignment` - The score of the best alignment, and the aligned strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::alignment::needleman_wunsch;
+///
+/// let alignment = needleman_wunsch("GCATGCG".to_string(), "GATTACA".to_string());
+/// assert_eq!(alignment.score, 0);
+/// assert_eq!(alignment.aligned1, "GCA-TGCG");
+/// assert_eq!(alignment.aligned2, "G-ATTACA");
+/// ```
+pub fn needleman_wunsch(string1: String, string2: String) -> Alignment {
+    NeedlemanWunsch::default().align(&string1, &string2)
+}
+
+/// Finds the most similar parts of two strings with the [Smith-Waterman](https://en.wikipedia.org/wiki/Smith%E2%80%93Waterman_algorithm) algorithm,
+/// scoring 1 for a match, -1 for a mismatch and -1 for a gap.
+/// Use a [SmithWaterman](struct.SmithWaterman.html) scorer for other scores.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to align.
+/// * `string2` - The second string to align.
+///
+/// # Returns
+///
+/// `Alignment` - The score of the best local alignment, the aligned parts and where they are.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::alignment::smith_waterman;
+///
+/// let alignment = smith_waterman("the quick brown fox".to_string(), "a quick fox".to_string());
+/// assert_eq!(alignment.aligned1, " quick ");
+/// assert_eq!(alignment.range2, 1..8);
+/// ```
+pub fn smith_waterman(string1: String, string2: String) -> Alignment {
+    SmithWaterman::default().align(&string1, &string2)
+}
+
+/// # Struct: Alignment
+/// The best alignment of two strings, as found by [NeedlemanWunsch](struct.NeedlemanWunsch.html)
+/// or [SmithWaterman](struct.SmithWaterman.html).
+///
+/// The aligned strings have the same number of characters, one column per character,
+/// with a `-` where a character of the other string is aligned to a gap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alignment {
+    /// The sum of the scores of the matches, mismatches and gaps of the alignment.
+    pub score: i32,
+    /// The aligned part of the first string, with gaps.
+    pub aligned1: String,
+    /// The aligned part of the second string, with gaps.
+    pub aligned2: String,
+    /// The characters of the first string that are aligned, as indices of `chars()`. The whole string for a global alignment.
+    pub range1: Range<usize>,
+    /// The characters of the second string that are aligned, as indices of `chars()`. The whole string for a global alignment.
+    pub range2: Range<usize>,
+}
+
+/// # Struct: NeedlemanWunsch
+/// Global alignment: aligns every character of both strings, like the Levenshtein distance,
+/// but with scores instead of costs, so matches can be rewarded and gaps can cost more than mismatches.
+///
+/// As a [Scorer](../../trait.Scorer.html), the similarity is the score over the score of aligning the longer string with itself,
+/// from 0 to 1, and the distance is the number of columns of the alignment that are not matches.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::alignment::NeedlemanWunsch;
+/// use correct_word::Scorer;
+///
+/// // Gaps cost twice as much as mismatches
+/// let scorer = NeedlemanWunsch::default().scores(2, -1, -2);
+/// let alignment = scorer.align("kitten", "sitting");
+/// assert_eq!(alignment.aligned1, "kitten-");
+/// assert_eq!(alignment.aligned2, "sitting");
+/// assert_eq!(alignment.score, 4);
+/// assert_eq!(scorer.distance("kitten", "sitting"), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NeedlemanWunsch {
+    scores: Scores,
+}
+
+/// # Struct: SmithWaterman
+/// Local alignment: finds the parts of the two strings that align best, and ignores the rest,
+/// like a passage copied into a longer text.
+///
+/// As a [Scorer](../../trait.Scorer.html), the similarity is the score over the score of aligning the shorter string with itself,
+/// from 0 to 1, so a string found whole inside the other has a similarity of 1.
+/// The distance is the number of columns of the local alignment that are not matches.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::alignment::SmithWaterman;
+///
+/// let alignment = SmithWaterman::default().scores(3, -3, -2).align("TGTTACGG", "GGTTGACTA");
+/// assert_eq!(alignment.score, 13);
+/// assert_eq!(alignment.aligned1, "GTT-AC");
+/// assert_eq!(alignment.aligned2, "GTTGAC");
+/// assert_eq!(alignment.range1, 1..6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SmithWaterman {
+    scores: Scores,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Scores {
+    pub(crate) matched: i32,
+    pub(crate) mismatch: i32,
+    pub(crate) gap: i32,
+}
+
+impl Scores {
+    /// The scores that make the best global alignment the one with the fewest Levenshtein edits.
+    pub(crate) const LEVENSHTEIN: Scores = Scores {
+        matched: 0,
+        mismatch: -1,
+        gap: -1,
+    };
+
+    fn pair<T: PartialEq>(&self, a: &T, b: &T) -> i32 {
+        if a == b {
+            self.matched
+        } else {
+            self.mismatch
+        }
+    }
+}
+
+impl Default for Scores {
+    fn default() -> Self {
+        Scores {
+            matched: 1,
+            mismatch: -1,
+            gap: -1,
+        }
+    }
+}
+
+impl NeedlemanWunsch {
+    /// Sets the score of a match, of a mismatch and of a gap. A match should score more than 0, and the others less.
+    pub fn scores(mut self, matched: i32, mismatch: i32, gap: i32) -> Self {
+        self.scores = Scores {
+            matched,
+            mismatch,
+            gap,
+        };
+        self
+    }
+
+    /// Aligns the two strings from end to end.
+    /// When several alignments have the best score, matches and mismatches come before gaps,
+    /// and gaps in the second string before gaps in the first.
+    ///
+    /// This keeps the whole DP matrix, so it uses memory proportional to the product of the string lengths.
+    pub fn align(&self, string1: &str, string2: &str) -> Alignment {
+        let chars1 = chars(string1);
+        let chars2 = chars(string2);
+        align(&chars1, &chars2, self.scores, false).0
+    }
+
+    /// Aligns the two strings from end to end like [align](#method.align), with
+    /// [Hirschberg's algorithm](https://en.wikipedia.org/wiki/Hirschberg%27s_algorithm),
+    /// which keeps two rows of the DP matrix at a time instead of the whole matrix.
+    /// It uses memory proportional to the length of the shorter string, besides the alignment itself,
+    /// so it can align long texts, in about twice the time of [align](#method.align).
+    ///
+    /// The score is always the same as the score of [align](#method.align),
+    /// but when several alignments have the best score, it may pick another one.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::metrics::alignment::NeedlemanWunsch;
+    ///
+    /// let scorer = NeedlemanWunsch::default();
+    /// let alignment = scorer.align_linear("GCATGCG", "GATTACA");
+    /// assert_eq!(alignment.score, scorer.align("GCATGCG", "GATTACA").score);
+    /// assert_eq!(alignment.aligned1.replace('-', ""), "GCATGCG");
+    /// assert_eq!(alignment.aligned2.replace('-', ""), "GATTACA");
+    /// ```
+    pub fn align_linear(&self, string1: &str, string2: &str) -> Alignment {
+        let chars1 = chars(string1);
+        let chars2 = chars(string2);
+        let mut alignment = Alignment {
+            score: 0,
+            aligned1: String::new(),
+            aligned2: String::new(),
+            range1: 0..chars1.len(),
+            range2: 0..chars2.len(),
+        };
+        for column in hirschberg(&chars1, &chars2, self.scores) {
+            let (c1, c2) = match column {
+                Column::Aligned(i, j) => {
+                    alignment.score += self.scores.pair(&chars1[i], &chars2[j]);
+                    (chars1[i], chars2[j])
+                }
+                Column::Deleted(i) => {
+                    alignment.score += self.scores.gap;
+                    (chars1[i], '-')
+                }
+                Column::Inserted(j) => {
+                    alignment.score += self.scores.gap;
+                    ('-', chars2[j])
+                }
+            };
+            alignment.aligned1.push(c1);
+            alignment.aligned2.push(c2);
+        }
+        alignment
+    }
+}
+
+impl SmithWaterman {
+    /// Sets the score of a match, of a mismatch and of a gap. A match should score more than 0, and the others less.
+    pub fn scores(mut self, matched: i32, mismatch: i32, gap: i32) -> Self {
+        self.scores = Scores {
+            matched,
+            mismatch,
+            gap,
+        };
+        self
+    }
+
+    /// Finds the parts of the two strings that align best.
+    /// When several parts have the best score, the one that ends first in the first string wins.
+    /// Strings without a single match have an empty alignment with a score of 0.
+    ///
+    /// This keeps the whole DP matrix, so it uses memory proportional to the product of the string lengths.
+    pub fn align(&self, string1: &str, string2: &str) -> Alignment {
+        let chars1 = chars(string1);
+        let chars2 = chars(string2);
+        align(&chars1, &chars2, self.scores, true).0
+    }
+}
+
+impl Scorer for NeedlemanWunsch {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        let a = chars(a);
+        let b = chars(b);
+        let best = self.scores.matched as f64 * a.len().max(b.len()) as f64;
+        similarity(&a, &b, best, || align(&a, &b, self.scores, false).0.score)
+    }
+
+    /// The number of mismatches and gaps in the alignment.
+    fn distance(&self, a: &str, b: &str) -> u16 {
+        let a = chars(a);
+        let b = chars(b);
+        align(&a, &b, self.scores, false).1 as u16
+    }
+}
+
+impl Scorer for SmithWaterman {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        let a = chars(a);
+        let b = chars(b);
+        let best = self.scores.matched as f64 * a.len().min(b.len()) as f64;
+        similarity(&a, &b, best, || align(&a, &b, self.scores, true).0.score)
+    }
+
+    /// The number of mismatches and gaps in the local alignment.
+    fn distance(&self, a: &str, b: &str) -> u16 {
+        let a = chars(a);
+        let b = chars(b);
+        align(&a, &b, self.scores, true).1 as u16
+    }
+}
+
+/// The score over the best score possible, from 0 to 1, with the usual empty strings.
+fn similarity(a: &[char], b: &[char], best: f64, score: impl FnOnce() -> i32) -> f64 {
+    if let Some(similarity) = trivial_similarity(a, b) {
+        return similarity;
+    }
+    if best <= 0.0 {
+        return 0.0;
+    }
+    (score() as f64 / best).clamp(0.0, 1.0)
+}
+
+/// The Needleman-Wunsch DP, or the Smith-Waterman one when `local`, walked back from the best cell.
+/// Returns the alignment and the number of its columns that are not matches.
+fn align(chars1: &[char], chars2: &[char], scores: Scores, local: bool) -> (Alignment, usize) {
+    let floor = |score: i32| if local { score.max(0) } else { score };
+
+    let mut matrix = Matrix::new(chars1.len() + 1, chars2.len() + 1, 0);
+    for j in 0..=chars2.len() {
+        matrix[(0, j)] = floor(j as i32 * scores.gap);
+    }
+    let mut end = (0, 0);
+    for (i, c1) in chars1.iter().enumerate() {
+        matrix[(i + 1, 0)] = floor((i + 1) as i32 * scores.gap);
+        for (j, c2) in chars2.iter().enumerate() {
+            let pair = if c1 == c2 {
+                scores.matched
+            } else {
+                scores.mismatch
+            };
+            let diagonal = matrix[(i, j)] + pair;
+            let up = matrix[(i, j + 1)] + scores.gap;
+            let left = matrix[(i + 1, j)] + scores.gap;
+            let cell = floor(diagonal.max(up).max(left));
+            matrix[(i + 1, j + 1)] = cell;
+            if local && cell > matrix[end] {
+                end = (i + 1, j + 1);
+            }
+        }
+    }
+    if !local {
+        end = (chars1.len(), chars2.len());
+    }
+
+    let (mut aligned1, mut aligned2) = (Vec::new(), Vec::new());
+    let mut unmatched = 0;
+    let (mut i, mut j) = end;
+    while i > 0 || j > 0 {
+        let current = matrix[(i, j)];
+        if local && current == 0 {
+            break;
+        }
+        if i > 0 && j > 0 {
+            let pair = if chars1[i - 1] == chars2[j - 1] {
+                scores.matched
+            } else {
+                scores.mismatch
+            };
+            if current == matrix[(i - 1, j - 1)] + pair {
+                i -= 1;
+                j -= 1;
+                aligned1.push(chars1[i]);
+                aligned2.push(chars2[j]);
+                if chars1[i] != chars2[j] {
+                    unmatched += 1;
+                }
+                continue;
+            }
+        }
+        unmatched += 1;
+        if i > 0 && current == matrix[(i - 1, j)] + scores.gap {
+            i -= 1;
+            aligned1.push(chars1[i]);
+            aligned2.push('-');
+        } else {
+            j -= 1;
+            aligned1.push('-');
+            aligned2.push(chars2[j]);
+        }
+    }
+
+    let alignment = Alignment {
+        score: matrix[end],
+        aligned1: aligned1.into_iter().rev().collect(),
+        aligned2: aligned2.into_iter().rev().collect(),
+        range1: i..end.0,
+        range2: j..end.1,
+    };
+    (alignment, unmatched)
+}
+
+/// A column of a global alignment, with the positions of its characters in the strings.
+///
+/// * Aligned: A character of the first string aligned to one of the second, which match or not.
+/// * Deleted: A character of the first string aligned to a gap.
+/// * Inserted: A character of the second string aligned to a gap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Column {
+    Aligned(usize, usize),
+    Deleted(usize),
+    Inserted(usize),
+}
+
+/// The best global alignment of the two sequences with Hirschberg's divide and conquer,
+/// as its columns from the start of the sequences to the end.
+///
+/// The first half of the first sequence is aligned with every prefix of the second, and the second half
+/// with every suffix, keeping only the last row of each DP. The best sum of the two rows is where the
+/// alignment crosses the middle, so both halves are aligned on their own, down to a single element.
+/// The rows are as long as the second sequence, so the shorter one is made the second.
+pub(crate) fn hirschberg<T: PartialEq>(
+    string1: &[T],
+    string2: &[T],
+    scores: Scores,
+) -> Vec<Column> {
+    let mut columns = Vec::with_capacity(string1.len().max(string2.len()));
+    if string2.len() > string1.len() {
+        divide(string2, string1, (0, 0), scores, &mut columns);
+        for column in &mut columns {
+            *column = match *column {
+                Column::Aligned(j, i) => Column::Aligned(i, j),
+                Column::Deleted(j) => Column::Inserted(j),
+                Column::Inserted(i) => Column::Deleted(i),
+            };
+        }
+    } else {
+        divide(string1, string2, (0, 0), scores, &mut columns);
+    }
+    columns
+}
+
+/// Aligns `a` and `b`, which start at `offset` in the whole sequences, and adds the columns.
+fn divide<T: PartialEq>(
+    a: &[T],
+    b: &[T],
+    offset: (usize, usize),
+    scores: Scores,
+    columns: &mut Vec<Column>,
+) {
+    let inserted =
+        |range: std::ops::Range<usize>| range.map(move |j| Column::Inserted(offset.1 + j));
+    if a.is_empty() {
+        columns.extend(inserted(0..b.len()));
+        return;
+    }
+    if a.len() == 1 {
+        // The element is aligned to the first of the best elements of `b`, unless two gaps score more
+        let best = (0..b.len())
+            .rev()
+            .max_by_key(|&j| scores.pair(&a[0], &b[j]));
+        match best {
+            Some(j) if scores.pair(&a[0], &b[j]) >= 2 * scores.gap => {
+                columns.extend(inserted(0..j));
+                columns.push(Column::Aligned(offset.0, offset.1 + j));
+                columns.extend(inserted(j + 1..b.len()));
+            }
+            _ => {
+                columns.push(Column::Deleted(offset.0));
+                columns.extend(inserted(0..b.len()));
+            }
+        }
+        return;
+    }
+
+    let middle = a.len() / 2;
+    let prefixes = last_row(a[..middle].iter(), b.iter(), b.len(), scores);
+    let suffixes = last_row(a[middle..].iter().rev(), b.iter().rev(), b.len(), scores);
+    let split = (0..=b.len())
+        .rev()
+        .max_by_key(|&j| prefixes[j] + suffixes[b.len() - j])
+        .unwrap_or(0);
+    divide(&a[..middle], &b[..split], offset, scores, columns);
+    divide(
+        &a[middle..],
+        &b[split..],
+        (offset.0 + middle, offset.1 + split),
+        scores,
+        columns,
+    );
+}
+
+/// The last row of the Needleman-Wunsch DP of `a` and `b`: the score of aligning all of `a` with each prefix of `b`.
+fn last_row<'t, T: PartialEq + 't>(
+    a: impl Iterator<Item = &'t T>,
+    b: impl Iterator<Item = &'t T> + Clone,
+    length: usize,
+    scores: Scores,
+) -> Vec<i32> {
+    let mut row: Vec<i32> = (0..=length as i32).map(|j| j * scores.gap).collect();
+    for (i, c1) in a.enumerate() {
+        let mut diagonal = row[0];
+        row[0] = (i as i32 + 1) * scores.gap;
+        for (j, c2) in b.clone().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (diagonal + scores.pair(c1, c2))
+                .max(above + scores.gap)
+                .max(row[j] + scores.gap);
+            diagonal = above;
+        }
+    }
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alignments_cover_their_ranges() {
+        let pairs = [
+            ("", ""),
+            ("", "ab"),
+            ("abc", "xyz"),
+            ("kitten", "sitting"),
+            ("caf\u{e9} au lait", "cafe"),
+            ("the quick brown fox", "a quick fox"),
+        ];
+        for (a, b) in pairs {
+            let chars1 = chars(a);
+            let chars2 = chars(b);
+            for local in [false, true] {
+                let (alignment, unmatched) = align(&chars1, &chars2, Scores::default(), local);
+                assert_eq!(
+                    alignment.aligned1.chars().count(),
+                    alignment.aligned2.chars().count()
+                );
+                let columns: Vec<(char, char)> = alignment
+                    .aligned1
+                    .chars()
+                    .zip(alignment.aligned2.chars())
+                    .collect();
+                assert_eq!(unmatched, columns.iter().filter(|(x, y)| x != y).count());
+                // None of the strings has a '-', so removing the gaps gives back the aligned characters
+                let ungapped = |aligned: &str| aligned.replace('-', "");
+                let part1: String = chars1[alignment.range1.clone()].iter().collect();
+                let part2: String = chars2[alignment.range2.clone()].iter().collect();
+                assert_eq!(ungapped(&alignment.aligned1), part1, "{a:?} {b:?}");
+                assert_eq!(ungapped(&alignment.aligned2), part2, "{a:?} {b:?}");
+                if !local {
+                    assert_eq!(alignment.range1, 0..chars1.len());
+                    assert_eq!(alignment.range2, 0..chars2.len());
+                }
+            }
+        }
+
+        let scorer = SmithWaterman::default();
+        assert_eq!(scorer.similarity("quick", "the quick brown fox"), 1.0);
+        assert_eq!(scorer.similarity("abc", "xyz"), 0.0);
+        assert_eq!(NeedlemanWunsch::default().similarity("", ""), 1.0);
+        assert_eq!(NeedlemanWunsch::default().similarity("", "a"), 0.0);
+    }
+
+    #[test]
+    fn linear_alignments_score_the_same() {
+        let mut seed: u64 = 3;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as usize
+        };
+        let scorers = [
+            NeedlemanWunsch::default(),
+            NeedlemanWunsch::default().scores(2, -1, -2),
+            NeedlemanWunsch::default().scores(1, -3, -1),
+        ];
+        for _ in 0..500 {
+            let string1: String = (0..next() % 40)
+                .map(|_| ['a', 'b', 'c', 'é'][next() % 4])
+                .collect();
+            let string2: String = (0..next() % 40)
+                .map(|_| ['a', 'b', 'c', 'é'][next() % 4])
+                .collect();
+            for scorer in scorers {
+                let alignment = scorer.align_linear(&string1, &string2);
+                assert_eq!(
+                    alignment.score,
+                    scorer.align(&string1, &string2).score,
+                    "{string1:?} {string2:?}"
+                );
+                assert_eq!(alignment.aligned1.replace('-', ""), string1);
+                assert_eq!(alignment.aligned2.replace('-', ""), string2);
+                assert_eq!(
+                    alignment.aligned1.chars().count(),
+                    alignment.aligned2.chars().count()
+                );
+            }
+        }
+    }
+}
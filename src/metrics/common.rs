@@ -0,0 +1,57 @@
+use std::ops::{Index, IndexMut};
+
+/// The characters of a string, for the metrics that compare them by position.
+pub(crate) fn chars(string: &str) -> Vec<char> {
+    string.chars().collect()
+}
+
+/// Turns a distance into a similarity, relative to the length of the longer string.
+/// Two empty strings are equal, so their similarity is 1.
+pub(crate) fn similarity(distance: usize, length: usize) -> f64 {
+    if length == 0 {
+        return 1.0;
+    }
+    1.0 - (distance as f64 / length as f64)
+}
+
+/// The similarity every metric agrees on, when it doesn't need to compare the strings:
+/// 1 for equal strings, two empty ones included, and 0 when only one of them is empty.
+pub(crate) fn trivial_similarity<T: PartialEq>(string1: &[T], string2: &[T]) -> Option<f64> {
+    if string1 == string2 {
+        Some(1.0)
+    } else if string1.is_empty() || string2.is_empty() {
+        Some(0.0)
+    } else {
+        None
+    }
+}
+
+/// A whole DP matrix, stored row after row in a single buffer, for the metrics that walk it back.
+pub(crate) struct Matrix<T> {
+    columns: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Matrix<T> {
+    /// A matrix of `rows` by `columns` cells, all of them `value`.
+    pub(crate) fn new(rows: usize, columns: usize, value: T) -> Self {
+        Matrix {
+            columns,
+            cells: vec![value; rows * columns],
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        &self.cells[row * self.columns + column]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        &mut self.cells[row * self.columns + column]
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::metrics::common::{chars, trivial_similarity};
+use crate::Scorer;
+
+/// Uses the [cosine similarity](https://en.wikipedia.org/wiki/Cosine_similarity) of the character bigrams of two strings
+/// to calculate how similar they are.
+/// Every string is a vector of how many times each pair of adjacent characters appears in it,
+/// and the similarity is the cosine of the angle between the two vectors.
+/// Returns a f64 from 0 to 1: 1 being strings with the same bigrams, and 0 being strings without a bigram in common.
+/// Two empty strings are equal, and an empty string has a similarity of 0 to any other string.
+/// A string of a single character is a bigram of its own.
+///
+/// The order of the bigrams doesn't matter, so moving a word around costs little,
+/// and comparing long strings, like titles or addresses, is linear in their length instead of quadratic.
+/// Use a [CosineScorer](struct.CosineScorer.html) for other n-grams, or to weight them by how rare they are.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::cosine::cosine_similarity;
+///
+/// // "night" and "nacht" only share "ht" of their 4 bigrams
+/// assert_eq!(cosine_similarity("night".to_string(), "nacht".to_string()), 0.25);
+/// assert!(cosine_similarity("12 Main Street".to_string(), "Main Street 12".to_string()) > 0.8);
+/// ```
+pub fn cosine_similarity(string1: String, string2: String) -> f64 {
+    CosineScorer::new(2).similarity(&string1, &string2)
+}
+
+/// Uses the character bigrams of two strings to calculate the distance between them.
+/// The distance is the number of bigrams that are in one string but not in the other, counting repeated bigrams every time.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `u16` - The distance between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::cosine::cosine_distance;
+///
+/// // "ni", "ig" and "gh" against "na", "ac" and "ch"
+/// assert_eq!(cosine_distance("night".to_string(), "nacht".to_string()), 6);
+/// ```
+pub fn cosine_distance(string1: String, string2: String) -> u16 {
+    CosineScorer::new(2).distance(&string1, &string2)
+}
+
+/// # Struct: CosineScorer
+/// The cosine similarity of the character n-grams of two strings, like [cosine_similarity](fn.cosine_similarity.html),
+/// with any `n`, and optional [TF-IDF](https://en.wikipedia.org/wiki/Tf%E2%80%93idf) weights.
+///
+/// With [tf_idf](#method.tf_idf), every n-gram is weighted by how rare it is in a corpus, usually the options themselves.
+/// The n-grams that are in every option, like the "st" of "Street", then count less than the ones that tell them apart.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::cosine::CosineScorer;
+/// use correct_word::{Corrector, Scorer};
+///
+/// let options = ["Baker Street", "Abbey Road", "Baker Road"];
+/// let trigrams = CosineScorer::new(3);
+/// assert!(trigrams.similarity("Baker St", "Baker Street") > trigrams.similarity("Baker St", "Abbey Road"));
+///
+/// let corrector = Corrector::new(CosineScorer::new(2).tf_idf(options)).threshold(0.3);
+/// assert_eq!(corrector.correct("Abey Rd", &options).word.unwrap(), "Abbey Road");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CosineScorer {
+    n: usize,
+    idf: Option<Idf>,
+}
+
+/// The inverse document frequency of the n-grams of a corpus.
+#[derive(Debug, Clone, PartialEq)]
+struct Idf {
+    weights: HashMap<Vec<char>, f64>,
+    /// The weight of an n-gram that isn't in the corpus, the highest of all.
+    unseen: f64,
+}
+
+impl CosineScorer {
+    /// Creates a scorer over the n-grams of `n` characters, without weights. An `n` of 0 is 1.
+    pub fn new(n: usize) -> Self {
+        CosineScorer {
+            n: n.max(1),
+            idf: None,
+        }
+    }
+
+    /// Weights every n-gram by its inverse document frequency in the corpus, `ln((1 + N) / (1 + df)) + 1`,
+    /// with `N` the number of strings of the corpus and `df` the number of them that have the n-gram.
+    /// The n-grams that are not in the corpus get the highest weight.
+    pub fn tf_idf<D: AsRef<str>>(mut self, corpus: impl IntoIterator<Item = D>) -> Self {
+        let mut frequencies: HashMap<Vec<char>, usize> = HashMap::new();
+        let mut documents = 0;
+        for document in corpus {
+            documents += 1;
+            let chars = chars(document.as_ref());
+            for gram in ngrams(&chars, self.n).into_keys() {
+                *frequencies.entry(gram.to_vec()).or_default() += 1;
+            }
+        }
+        let idf = |frequency: usize| ((1 + documents) as f64 / (1 + frequency) as f64).ln() + 1.0;
+        self.idf = Some(Idf {
+            weights: frequencies
+                .into_iter()
+                .map(|(gram, frequency)| (gram, idf(frequency)))
+                .collect(),
+            unseen: idf(0),
+        });
+        self
+    }
+}
+
+impl Scorer for CosineScorer {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        let a = chars(a);
+        let b = chars(b);
+        match &self.idf {
+            None => similarity_slices(&a, &b, self.n),
+            Some(idf) => weighted_similarity(&a, &b, self.n, |gram: &[char]| {
+                idf.weights.get(gram).copied().unwrap_or(idf.unseen)
+            }),
+        }
+    }
+
+    /// The number of n-grams that are in one string but not in the other, without weights.
+    fn distance(&self, a: &str, b: &str) -> u16 {
+        let a = chars(a);
+        let b = chars(b);
+        distance_slices(&a, &b, self.n) as u16
+    }
+}
+
+/// How many times every n-gram of the units appears. Fewer than `n` units are a single n-gram.
+pub(crate) fn ngrams<T: Hash + Eq>(units: &[T], n: usize) -> HashMap<&[T], usize> {
+    let mut counts = HashMap::new();
+    if units.is_empty() {
+        return counts;
+    }
+    if units.len() < n {
+        counts.insert(units, 1);
+        return counts;
+    }
+    for gram in units.windows(n) {
+        *counts.entry(gram).or_default() += 1;
+    }
+    counts
+}
+
+/// The cosine similarity of the n-grams of any two sequences of units, like the tokens of a [Granularity](../../enum.Granularity.html).
+pub(crate) fn similarity_slices<T: Hash + Eq>(string1: &[T], string2: &[T], n: usize) -> f64 {
+    weighted_similarity(string1, string2, n, |_| 1.0)
+}
+
+fn weighted_similarity<T: Hash + Eq>(
+    string1: &[T],
+    string2: &[T],
+    n: usize,
+    weight: impl Fn(&[T]) -> f64,
+) -> f64 {
+    if let Some(similarity) = trivial_similarity(string1, string2) {
+        return similarity;
+    }
+    let grams1 = ngrams(string1, n);
+    let grams2 = ngrams(string2, n);
+    let norm = |grams: &HashMap<&[T], usize>| {
+        let squares = grams
+            .iter()
+            .map(|(gram, &count)| (count as f64 * weight(gram)).powi(2));
+        sum(squares).sqrt()
+    };
+    let dot = sum(grams1.iter().filter_map(|(gram, &count)| {
+        let other = grams2.get(gram)?;
+        Some(count as f64 * *other as f64 * weight(gram).powi(2))
+    }));
+    let norms = norm(&grams1) * norm(&grams2);
+    if norms == 0.0 {
+        return 0.0;
+    }
+    (dot / norms).clamp(0.0, 1.0)
+}
+
+/// Sums the terms in increasing order, so the result doesn't depend on the order of a `HashMap`,
+/// and swapping the strings gives the same similarity.
+fn sum(terms: impl Iterator<Item = f64>) -> f64 {
+    let mut terms: Vec<f64> = terms.collect();
+    terms.sort_by(f64::total_cmp);
+    terms.into_iter().sum()
+}
+
+/// The number of n-grams of any two sequences of units that are in one but not in the other.
+pub(crate) fn distance_slices<T: Hash + Eq>(string1: &[T], string2: &[T], n: usize) -> usize {
+    let grams1 = ngrams(string1, n);
+    let grams2 = ngrams(string2, n);
+    let only1: usize = grams1
+        .iter()
+        .map(|(gram, &count)| count.saturating_sub(grams2.get(gram).copied().unwrap_or(0)))
+        .sum();
+    let only2: usize = grams2
+        .iter()
+        .map(|(gram, &count)| count.saturating_sub(grams1.get(gram).copied().unwrap_or(0)))
+        .sum();
+    only1 + only2
+}
@@ -0,0 +1,602 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::metrics::common::{chars, similarity, Matrix};
+use crate::Granularity;
+
+/// Uses the [Optimal String Alignment](https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance#Optimal_string_alignment_distance)
+/// distance, also called the restricted Damerau-Levenshtein distance, to calculate the distance between two strings.
+/// It is the Levenshtein distance where swapping two adjacent characters, like "teh" and "the", is a single edit.
+///
+/// Unlike the [true Damerau-Levenshtein distance](fn.damerau_levenshtein_distance.html),
+/// no substring is edited more than once, so a swapped pair can't be edited again.
+/// That's why "ca" and "abc" are 3 edits apart here, but only 2 with the true distance.
+/// This is the variant most libraries call "OSA", and what many call "Damerau-Levenshtein".
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `u16` - The distance between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::damerau::osa_distance;
+///
+/// assert_eq!(osa_distance("teh".to_string(), "the".to_string()), 1);
+/// assert_eq!(osa_distance("ca".to_string(), "abc".to_string()), 3);
+/// ```
+pub fn osa_distance(string1: String, string2: String) -> u16 {
+    let chars1 = chars(&string1);
+    let chars2 = chars(&string2);
+    osa_distance_slice(&chars1, &chars2) as u16
+}
+
+/// Uses the Optimal String Alignment distance as a measure to calculate the similarity between two strings,
+/// like [levenshtein_similarity](../levenshtein/fn.levenshtein_similarity.html).
+/// Returns a f64 from 0 to 1: 1 being equal strings and 0 being completely different strings.
+/// Two empty strings are equal, and an empty string has a similarity of 0 to any other string.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two strings.
+pub fn osa_similarity(string1: String, string2: String) -> f64 {
+    let chars1 = chars(&string1);
+    let chars2 = chars(&string2);
+    osa_similarity_slice(&chars1, &chars2)
+}
+
+/// Uses the true [Damerau-Levenshtein distance](https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance)
+/// to calculate the distance between two strings: the number of insertions, deletions, substitutions
+/// and swaps of adjacent characters needed to turn one string into the other.
+///
+/// Unlike the [Optimal String Alignment distance](fn.osa_distance.html), characters can still be inserted
+/// between a swapped pair, so "ca" to "abc" is 2 edits: swap to "ac", and insert the "b".
+///
+/// The algorithm is the one by Lowrance and Wagner, which keeps the whole matrix,
+/// and the last row every character was seen in.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `u16` - The distance between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::damerau::damerau_levenshtein_distance;
+///
+/// assert_eq!(damerau_levenshtein_distance("teh".to_string(), "the".to_string()), 1);
+/// assert_eq!(damerau_levenshtein_distance("ca".to_string(), "abc".to_string()), 2);
+/// ```
+pub fn damerau_levenshtein_distance(string1: String, string2: String) -> u16 {
+    let chars1 = chars(&string1);
+    let chars2 = chars(&string2);
+    damerau_levenshtein_distance_slice(&chars1, &chars2) as u16
+}
+
+/// Uses the true Damerau-Levenshtein distance as a measure to calculate the similarity between two strings,
+/// like [levenshtein_similarity](../levenshtein/fn.levenshtein_similarity.html).
+/// Returns a f64 from 0 to 1: 1 being equal strings and 0 being completely different strings.
+/// Two empty strings are equal, and an empty string has a similarity of 0 to any other string.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two strings.
+pub fn damerau_levenshtein_similarity(string1: String, string2: String) -> f64 {
+    let chars1 = chars(&string1);
+    let chars2 = chars(&string2);
+    damerau_levenshtein_similarity_slice(&chars1, &chars2)
+}
+
+/// Calculates the Optimal String Alignment distance like [osa_distance](fn.osa_distance.html),
+/// counting edits in the units of the given [Granularity](../../enum.Granularity.html) instead of characters.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+/// * `granularity` - What counts as a single unit, like a byte, a character or a word.
+///
+/// # Returns
+///
+/// `u16` - The number of edits, in units, to turn one string into the other.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::damerau::osa_distance_with;
+/// use correct_word::Granularity;
+///
+/// let distance = osa_distance_with("york new mets".to_string(), "new york mets".to_string(), Granularity::Words);
+/// assert_eq!(distance, 1);
+/// ```
+pub fn osa_distance_with(string1: String, string2: String, granularity: Granularity) -> u16 {
+    osa_distance_slice(&granularity.tokens(&string1), &granularity.tokens(&string2)) as u16
+}
+
+/// Calculates the true Damerau-Levenshtein distance like [damerau_levenshtein_distance](fn.damerau_levenshtein_distance.html),
+/// counting edits in the units of the given [Granularity](../../enum.Granularity.html) instead of characters.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+/// * `granularity` - What counts as a single unit, like a byte, a character or a word.
+///
+/// # Returns
+///
+/// `u16` - The number of edits, in units, to turn one string into the other.
+pub fn damerau_levenshtein_distance_with(
+    string1: String,
+    string2: String,
+    granularity: Granularity,
+) -> u16 {
+    damerau_levenshtein_distance_slice(&granularity.tokens(&string1), &granularity.tokens(&string2))
+        as u16
+}
+
+/// Calculates the Optimal String Alignment similarity like [osa_similarity](fn.osa_similarity.html),
+/// between any two sequences of comparable elements.
+///
+/// # Arguments
+///
+/// * `string1` - The first sequence to compare.
+/// * `string2` - The second sequence to compare.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two sequences.
+pub fn osa_similarity_slice<T: PartialEq>(string1: &[T], string2: &[T]) -> f64 {
+    similarity(
+        osa_distance_slice(string1, string2),
+        string1.len().max(string2.len()),
+    )
+}
+
+/// Calculates the true Damerau-Levenshtein similarity like [damerau_levenshtein_similarity](fn.damerau_levenshtein_similarity.html),
+/// between any two sequences of elements that can be hashed.
+///
+/// # Arguments
+///
+/// * `string1` - The first sequence to compare.
+/// * `string2` - The second sequence to compare.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two sequences.
+pub fn damerau_levenshtein_similarity_slice<T: Hash + Eq>(string1: &[T], string2: &[T]) -> f64 {
+    similarity(
+        damerau_levenshtein_distance_slice(string1, string2),
+        string1.len().max(string2.len()),
+    )
+}
+
+/// Calculates the Optimal String Alignment distance like [osa_distance](fn.osa_distance.html),
+/// between any two sequences of comparable elements, like tokens or the pixels of a row.
+///
+/// This is [osa_cost](fn.osa_cost.html) where every edit costs 1.
+///
+/// # Arguments
+///
+/// * `string1` - The first sequence to compare.
+/// * `string2` - The second sequence to compare.
+///
+/// # Returns
+///
+/// `usize` - The number of edits, in elements, to turn one sequence into the other.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::damerau::osa_distance_slice;
+///
+/// assert_eq!(osa_distance_slice(&["york", "new", "mets"], &["new", "york", "mets"]), 1);
+/// ```
+pub fn osa_distance_slice<T: PartialEq>(string1: &[T], string2: &[T]) -> usize {
+    osa_cost(string1, string2, &UniformCosts::default()) as usize
+}
+
+/// Calculates the true Damerau-Levenshtein distance like [damerau_levenshtein_distance](fn.damerau_levenshtein_distance.html),
+/// between any two sequences of elements that can be hashed.
+///
+/// This is [damerau_levenshtein_cost](fn.damerau_levenshtein_cost.html) where every edit costs 1.
+///
+/// # Arguments
+///
+/// * `string1` - The first sequence to compare.
+/// * `string2` - The second sequence to compare.
+///
+/// # Returns
+///
+/// `usize` - The number of edits, in elements, to turn one sequence into the other.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::damerau::damerau_levenshtein_distance_slice;
+///
+/// assert_eq!(damerau_levenshtein_distance_slice(&[3, 1], &[1, 2, 3]), 2);
+/// ```
+pub fn damerau_levenshtein_distance_slice<T: Hash + Eq>(string1: &[T], string2: &[T]) -> usize {
+    damerau_levenshtein_cost(string1, string2, &UniformCosts::default()) as usize
+}
+
+/// # Trait: EditCosts
+/// The cost of every edit of [osa_cost](fn.osa_cost.html) and [damerau_levenshtein_cost](fn.damerau_levenshtein_cost.html),
+/// for a weighted distance, like one where the keys next to each other are cheap to mix up.
+///
+/// Every edit costs 1 by default, which is the plain distance. Equal elements always cost nothing to keep.
+/// A cost of `f64::INFINITY` turns an edit off: without swaps, the distance is a weighted Levenshtein distance.
+///
+/// Some substitutions are of more than one element at once, like "rn" read as "m" by an OCR engine.
+/// Those are the confusions, asked for with [confusion](#method.confusion) when [longest_confusion](#method.longest_confusion) is more than 1.
+pub trait EditCosts<T> {
+    /// The cost of inserting the element. By default, 1.
+    fn insertion(&self, element: &T) -> f64 {
+        let _ = element;
+        1.0
+    }
+
+    /// The cost of deleting the element. By default, 1.
+    fn deletion(&self, element: &T) -> f64 {
+        let _ = element;
+        1.0
+    }
+
+    /// The cost of substituting `b` for `a`, which are never equal. By default, 1.
+    fn substitution(&self, a: &T, b: &T) -> f64 {
+        let _ = (a, b);
+        1.0
+    }
+
+    /// The cost of swapping `a` and the `b` that follows it. By default, 1.
+    fn transposition(&self, a: &T, b: &T) -> f64 {
+        let _ = (a, b);
+        1.0
+    }
+
+    /// The number of elements of the longest confusion. By default, 1, for no confusions.
+    fn longest_confusion(&self) -> usize {
+        1
+    }
+
+    /// The cost of substituting `b` for `a` as a whole, or None if they are not a confusion.
+    /// Only asked for when one of them has more than one element, and none has more than [longest_confusion](#method.longest_confusion).
+    /// By default, None.
+    fn confusion(&self, a: &[T], b: &[T]) -> Option<f64> {
+        let _ = (a, b);
+        None
+    }
+}
+
+/// # Struct: UniformCosts
+/// [EditCosts](trait.EditCosts.html) with the same cost for every edit of a kind, whatever the elements.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::damerau::{osa_cost, UniformCosts};
+///
+/// let costs = UniformCosts { transposition: 0.5, ..UniformCosts::default() };
+/// assert_eq!(osa_cost(&['t', 'e', 'h'], &['t', 'h', 'e'], &costs), 0.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UniformCosts {
+    pub insertion: f64,
+    pub deletion: f64,
+    pub substitution: f64,
+    pub transposition: f64,
+}
+
+impl Default for UniformCosts {
+    fn default() -> Self {
+        UniformCosts {
+            insertion: 1.0,
+            deletion: 1.0,
+            substitution: 1.0,
+            transposition: 1.0,
+        }
+    }
+}
+
+impl<T> EditCosts<T> for UniformCosts {
+    fn insertion(&self, _: &T) -> f64 {
+        self.insertion
+    }
+
+    fn deletion(&self, _: &T) -> f64 {
+        self.deletion
+    }
+
+    fn substitution(&self, _: &T, _: &T) -> f64 {
+        self.substitution
+    }
+
+    fn transposition(&self, _: &T, _: &T) -> f64 {
+        self.transposition
+    }
+}
+
+/// Calculates the Optimal String Alignment distance between two sequences, with the cost of every edit given by `costs`.
+///
+/// This is the Levenshtein DP with one more case: the last two elements of both prefixes are swapped,
+/// and one more for every confusion that ends there. Only the rows a swap or a confusion can reach back to are kept.
+///
+/// # Arguments
+///
+/// * `string1` - The first sequence to compare.
+/// * `string2` - The second sequence to compare.
+/// * `costs` - The cost of every edit.
+///
+/// # Returns
+///
+/// `f64` - The cost of the cheapest edits that turn one sequence into the other.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::damerau::{osa_cost, UniformCosts};
+///
+/// let costs = UniformCosts { insertion: 0.5, deletion: 2.0, ..UniformCosts::default() };
+/// assert_eq!(osa_cost(&[1, 2], &[1, 2, 3], &costs), 0.5);
+/// assert_eq!(osa_cost(&[1, 2, 3], &[1, 2], &costs), 2.0);
+/// ```
+pub fn osa_cost<T: PartialEq>(string1: &[T], string2: &[T], costs: &impl EditCosts<T>) -> f64 {
+    let longest = costs.longest_confusion().max(1);
+    let kept = longest.max(2) + 1;
+    let columns = string2.len() + 1;
+    let at = |i: usize, j: usize| (i % kept) * columns + j;
+    let mut rows: Vec<f64> = vec![0.0; kept * columns];
+
+    for i in 0..=string1.len() {
+        for j in 0..=string2.len() {
+            if i == 0 && j == 0 {
+                continue;
+            }
+            let mut cost = f64::INFINITY;
+            if i > 0 {
+                cost = cost.min(rows[at(i - 1, j)] + costs.deletion(&string1[i - 1]));
+            }
+            if j > 0 {
+                cost = cost.min(rows[at(i, j - 1)] + costs.insertion(&string2[j - 1]));
+            }
+            if i > 0 && j > 0 {
+                let (a, b) = (&string1[i - 1], &string2[j - 1]);
+                let substitution = if a == b {
+                    0.0
+                } else {
+                    costs.substitution(a, b)
+                };
+                cost = cost.min(rows[at(i - 1, j - 1)] + substitution);
+            }
+            if i > 1
+                && j > 1
+                && string1[i - 1] == string2[j - 2]
+                && string1[i - 2] == string2[j - 1]
+            {
+                let swap = costs.transposition(&string1[i - 2], &string1[i - 1]);
+                cost = cost.min(rows[at(i - 2, j - 2)] + swap);
+            }
+            for length1 in 1..=longest.min(i) {
+                for length2 in 1..=longest.min(j) {
+                    if length1 == 1 && length2 == 1 {
+                        continue;
+                    }
+                    let a = &string1[i - length1..i];
+                    let b = &string2[j - length2..j];
+                    if let Some(confusion) = costs.confusion(a, b) {
+                        cost = cost.min(rows[at(i - length1, j - length2)] + confusion);
+                    }
+                }
+            }
+            rows[at(i, j)] = cost;
+        }
+    }
+
+    rows[at(string1.len(), string2.len())]
+}
+
+/// Calculates the true Damerau-Levenshtein distance between two sequences, with the cost of every edit given by `costs`.
+///
+/// This is the Lowrance-Wagner algorithm. A swap can be of two elements that are any distance apart,
+/// as long as everything between them is inserted or deleted.
+/// It only finds the cheapest edits when a swap costs at least half of an insertion and a deletion,
+/// which is always the case with the same costs for everything.
+///
+/// # Arguments
+///
+/// * `string1` - The first sequence to compare.
+/// * `string2` - The second sequence to compare.
+/// * `costs` - The cost of every edit.
+///
+/// # Returns
+///
+/// `f64` - The cost of the cheapest edits that turn one sequence into the other.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::damerau::{damerau_levenshtein_cost, UniformCosts};
+///
+/// let costs = UniformCosts { insertion: 0.5, ..UniformCosts::default() };
+/// assert_eq!(damerau_levenshtein_cost(&['c', 'a'], &['a', 'b', 'c'], &costs), 1.5);
+/// ```
+pub fn damerau_levenshtein_cost<T: Hash + Eq>(
+    string1: &[T],
+    string2: &[T],
+    costs: &impl EditCosts<T>,
+) -> f64 {
+    let (len1, len2) = (string1.len(), string2.len());
+    let longest = costs.longest_confusion().max(1);
+    // The cost of deleting the first i elements of string1, and of inserting the first j of string2
+    let mut deletions = vec![0.0; len1 + 1];
+    for (i, a) in string1.iter().enumerate() {
+        deletions[i + 1] = deletions[i] + costs.deletion(a);
+    }
+    let mut insertions = vec![0.0; len2 + 1];
+    for (j, b) in string2.iter().enumerate() {
+        insertions[j + 1] = insertions[j] + costs.insertion(b);
+    }
+
+    // The matrix has an extra row and column that can't be reached around it, so swaps never reach outside of it
+    let mut matrix = Matrix::new(len1 + 2, len2 + 2, f64::INFINITY);
+    for i in 0..=len1 {
+        matrix[(i + 1, 1)] = deletions[i];
+    }
+    for j in 0..=len2 {
+        matrix[(1, j + 1)] = insertions[j];
+    }
+
+    // The last row each unit of string1 was seen in
+    let mut last_row: HashMap<&T, usize> = HashMap::new();
+    for i in 1..=len1 {
+        let mut last_column = 0;
+        for j in 1..=len2 {
+            let swap_row = *last_row.get(&string2[j - 1]).unwrap_or(&0);
+            let swap_column = last_column;
+            let (a, b) = (&string1[i - 1], &string2[j - 1]);
+            let cost = if a == b {
+                last_column = j;
+                0.0
+            } else {
+                costs.substitution(a, b)
+            };
+
+            let mut distance = (matrix[(i, j)] + cost)
+                .min(matrix[(i + 1, j)] + costs.insertion(b))
+                .min(matrix[(i, j + 1)] + costs.deletion(a));
+            if swap_row > 0 && swap_column > 0 {
+                let swap = costs.transposition(&string1[swap_row - 1], a)
+                    + (deletions[i - 1] - deletions[swap_row])
+                    + (insertions[j - 1] - insertions[swap_column]);
+                distance = distance.min(matrix[(swap_row, swap_column)] + swap);
+            }
+            for length1 in 1..=longest.min(i) {
+                for length2 in 1..=longest.min(j) {
+                    if length1 == 1 && length2 == 1 {
+                        continue;
+                    }
+                    let confused = (&string1[i - length1..i], &string2[j - length2..j]);
+                    if let Some(confusion) = costs.confusion(confused.0, confused.1) {
+                        distance =
+                            distance.min(matrix[(i + 1 - length1, j + 1 - length2)] + confusion);
+                    }
+                }
+            }
+            matrix[(i + 1, j + 1)] = distance;
+        }
+        last_row.insert(&string1[i - 1], i);
+    }
+
+    matrix[(len1 + 1, len2 + 1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::levenshtein::levenshtein_distance;
+
+    #[test]
+    fn variants_differ() {
+        let cases = [
+            ("", "", 0, 0),
+            ("abc", "", 3, 3),
+            ("ca", "abc", 3, 2),
+            ("abcdef", "abdcfe", 2, 2),
+            ("a cat", "an act", 2, 2),
+            ("kitten", "sitting", 3, 3),
+        ];
+        for (a, b, osa, damerau) in cases {
+            assert_eq!(
+                osa_distance(a.to_string(), b.to_string()),
+                osa,
+                "{a:?} {b:?}"
+            );
+            assert_eq!(
+                osa_distance(b.to_string(), a.to_string()),
+                osa,
+                "{b:?} {a:?}"
+            );
+            assert_eq!(
+                damerau_levenshtein_distance(a.to_string(), b.to_string()),
+                damerau,
+                "{a:?} {b:?}"
+            );
+            assert_eq!(
+                damerau_levenshtein_distance(b.to_string(), a.to_string()),
+                damerau,
+                "{b:?} {a:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn never_more_than_levenshtein() {
+        let words = [
+            "", "a", "ab", "ba", "abc", "acb", "bca", "cab", "abcabc", "cbacba", "baab",
+        ];
+        for a in words {
+            for b in words {
+                let levenshtein = levenshtein_distance(a.to_string(), b.to_string());
+                let osa = osa_distance(a.to_string(), b.to_string());
+                let damerau = damerau_levenshtein_distance(a.to_string(), b.to_string());
+                assert!(damerau <= osa && osa <= levenshtein, "{a:?} {b:?}");
+                assert!(levenshtein <= 2 * damerau, "{a:?} {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn weighted_costs() {
+        let chars = |string: &str| string.chars().collect::<Vec<_>>();
+        let swaps = UniformCosts {
+            transposition: 0.25,
+            ..UniformCosts::default()
+        };
+        assert_eq!(osa_cost(&chars("abcd"), &chars("badc"), &swaps), 0.5);
+        assert_eq!(
+            damerau_levenshtein_cost(&chars("ca"), &chars("abc"), &swaps),
+            1.25
+        );
+        // The swapped pair can't be edited again, so OSA swaps nothing here
+        assert_eq!(osa_cost(&chars("ca"), &chars("abc"), &swaps), 3.0);
+
+        let levenshtein = UniformCosts {
+            transposition: f64::INFINITY,
+            ..UniformCosts::default()
+        };
+        assert_eq!(osa_cost(&chars("teh"), &chars("the"), &levenshtein), 2.0);
+        assert_eq!(
+            damerau_levenshtein_cost(&chars("teh"), &chars("the"), &levenshtein),
+            2.0
+        );
+
+        // "rn" read as "m"
+        struct Ocr;
+        impl EditCosts<char> for Ocr {
+            fn longest_confusion(&self) -> usize {
+                2
+            }
+
+            fn confusion(&self, a: &[char], b: &[char]) -> Option<f64> {
+                (a == ['r', 'n'] && b == ['m'] || a == ['m'] && b == ['r', 'n']).then_some(0.25)
+            }
+        }
+        for (a, b) in [("modern", "rnodern"), ("rnodern", "modern")] {
+            assert_eq!(osa_cost(&chars(a), &chars(b), &Ocr), 0.25);
+            assert_eq!(damerau_levenshtein_cost(&chars(a), &chars(b), &Ocr), 0.25);
+        }
+        assert_eq!(osa_cost(&chars("modem"), &chars("rnodern"), &Ocr), 0.5);
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::BTreeSet;
+
+use crate::metrics::common::chars;
+use crate::metrics::lcs::{self, lcs_similarity};
+
+/// Compares two strings after sorting their words, so the order of the words doesn't matter.
+/// Returns a f64 from 0 to 1: 1 being the same words in any order and 0 being completely different strings.
+///
+/// Both strings are lowercased and everything that isn't a letter or a digit is treated as a space,
+/// then the words are sorted, joined back together and scored with the [LCS similarity](../lcs/fn.lcs_similarity.html).
+/// This is the same score as `token_sort_ratio` in fuzzywuzzy and thefuzz, divided by 100.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::fuzz::token_sort_ratio;
+///
+/// let similarity = token_sort_ratio("new york mets".to_string(), "mets new york".to_string());
+/// assert_eq!(similarity, 1.0);
+/// ```
+pub fn token_sort_ratio(string1: String, string2: String) -> f64 {
+    let mut tokens1 = tokens(&string1);
+    let mut tokens2 = tokens(&string2);
+    tokens1.sort();
+    tokens2.sort();
+    lcs_similarity(tokens1.join(" "), tokens2.join(" "))
+}
+
+/// Compares two strings by the words they have in common, so repeated and extra words matter less.
+/// Returns a f64 from 0 to 1: 1 being strings where the words of one are all in the other and 0 being completely different strings.
+///
+/// Both strings are split into sets of words, like in [token_sort_ratio](fn.token_sort_ratio.html).
+/// The sorted common words are then compared to the common words plus the rest of each string,
+/// and the best of these scores is returned.
+/// This is the same score as `token_set_ratio` in fuzzywuzzy and thefuzz, divided by 100.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two strings. If only one of the strings has no words, it is 0,
+/// and if neither has any, it is 1. thefuzz gives 0 for both.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::fuzz::token_set_ratio;
+///
+/// let similarity = token_set_ratio("mets vs braves".to_string(), "the new york mets vs the atlanta braves".to_string());
+/// assert_eq!(similarity, 1.0);
+/// ```
+pub fn token_set_ratio(string1: String, string2: String) -> f64 {
+    let tokens1: BTreeSet<String> = tokens(&string1).into_iter().collect();
+    let tokens2: BTreeSet<String> = tokens(&string2).into_iter().collect();
+    if tokens1.is_empty() || tokens2.is_empty() {
+        return if tokens1 == tokens2 { 1.0 } else { 0.0 };
+    }
+
+    let intersection = join(tokens1.intersection(&tokens2));
+    let combined1 = join_with(&intersection, join(tokens1.difference(&tokens2)));
+    let combined2 = join_with(&intersection, join(tokens2.difference(&tokens1)));
+
+    lcs_similarity(intersection.clone(), combined1.clone())
+        .max(lcs_similarity(intersection, combined2.clone()))
+        .max(lcs_similarity(combined1, combined2))
+}
+
+/// Compares the shorter string to every window of the same length in the longer string, and returns the best score.
+/// Returns a f64 from 0 to 1: 1 being a needle that appears somewhere in the haystack and 0 being strings without a common character.
+///
+/// Unlike the other similarities, the extra characters of the longer string are not penalised,
+/// so "hello" is a perfect match for "oh hello there". The windows are scored with the [LCS similarity](../lcs/fn.lcs_similarity.html).
+/// It doesn't matter which of the two strings is the shorter one.
+/// Two empty strings are equal, and an empty string has a similarity of 0 to any other string.
+///
+/// # Arguments
+///
+/// * `needle` - The string to look for.
+/// * `haystack` - The string to look in.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the needle and the best matching part of the haystack.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::fuzz::partial_similarity;
+///
+/// assert_eq!(partial_similarity("hello".to_string(), "oh hello there".to_string()), 1.0);
+/// assert_eq!(partial_similarity("helo".to_string(), "oh hello there".to_string()), 0.75);
+/// ```
+pub fn partial_similarity(needle: String, haystack: String) -> f64 {
+    let needle = chars(&needle);
+    let haystack = chars(&haystack);
+    best_window(&needle, &haystack).0
+}
+
+/// Finds the window of the longer sequence that is the most similar to the shorter one.
+/// Returns the similarity and the LCS distance between the shorter sequence and that window.
+pub(crate) fn best_window<T: PartialEq>(string1: &[T], string2: &[T]) -> (f64, usize) {
+    let (shorter, longer) = if string1.len() <= string2.len() {
+        (string1, string2)
+    } else {
+        (string2, string1)
+    };
+
+    if shorter.is_empty() {
+        return if longer.is_empty() {
+            (1.0, 0)
+        } else {
+            (0.0, longer.len())
+        };
+    }
+
+    let mut best = (0.0, 2 * shorter.len());
+    for window in longer.windows(shorter.len()) {
+        let similarity = lcs::lcs_similarity_slice(shorter, window);
+        if similarity > best.0 {
+            best = (similarity, lcs::lcs_distance_slice(shorter, window));
+            if similarity == 1.0 {
+                break;
+            }
+        }
+    }
+    best
+}
+
+/// Lowercases a string and splits it into words, treating everything that isn't a letter or a digit as a space.
+fn tokens(string: &str) -> Vec<String> {
+    string
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn join<'a>(tokens: impl Iterator<Item = &'a String>) -> String {
+    tokens.map(String::as_str).collect::<Vec<_>>().join(" ")
+}
+
+fn join_with(intersection: &str, rest: String) -> String {
+    match (intersection.is_empty(), rest.is_empty()) {
+        (true, _) => rest,
+        (_, true) => intersection.to_string(),
+        _ => format!("{intersection} {rest}"),
+    }
+}
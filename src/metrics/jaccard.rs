@@ -0,0 +1,177 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::metrics::common::{chars, trivial_similarity};
+use crate::metrics::cosine::ngrams;
+use crate::Scorer;
+
+/// Uses the [Jaccard index](https://en.wikipedia.org/wiki/Jaccard_index) of the character bigrams of two strings
+/// to calculate how similar they are.
+/// Every string is the set of the pairs of adjacent characters in it,
+/// and the similarity is the number of bigrams in both sets over the number of bigrams in either.
+/// Returns a f64 from 0 to 1: 1 being strings with the same bigrams, and 0 being strings without a bigram in common.
+/// Two empty strings are equal, and an empty string has a similarity of 0 to any other string.
+/// A string of a single character is a bigram of its own.
+///
+/// Unlike [cosine_similarity](../cosine/fn.cosine_similarity.html), a bigram that is repeated only counts once,
+/// which is what finding near-duplicate records usually wants.
+/// Use a [JaccardScorer](struct.JaccardScorer.html) for other shingles, like the words of the strings.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::jaccard::jaccard_similarity;
+///
+/// // "night" and "nacht" only share "ht" of the 7 bigrams they have between them
+/// assert_eq!(jaccard_similarity("night".to_string(), "nacht".to_string()), 1.0 / 7.0);
+/// assert_eq!(jaccard_similarity("abab".to_string(), "ab".to_string()), 0.5);
+/// ```
+pub fn jaccard_similarity(string1: String, string2: String) -> f64 {
+    JaccardScorer::new(Shingles::Chars(2)).similarity(&string1, &string2)
+}
+
+/// Uses the character bigrams of two strings to calculate the distance between them.
+/// The distance is the number of different bigrams that are in one string but not in the other.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `u16` - The distance between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::jaccard::jaccard_distance;
+///
+/// // "ni", "ig" and "gh" against "na", "ac" and "ch"
+/// assert_eq!(jaccard_distance("night".to_string(), "nacht".to_string()), 6);
+/// ```
+pub fn jaccard_distance(string1: String, string2: String) -> u16 {
+    JaccardScorer::new(Shingles::Chars(2)).distance(&string1, &string2)
+}
+
+/// # Enum: Shingles
+/// How a string is cut into the overlapping pieces, or shingles, that set-based similarities compare.
+///
+/// * Chars: The runs of `n` adjacent characters, like "ni", "ig", "gh" and "ht" for "night" with 2.
+/// * Words: The runs of `n` adjacent words, split on whitespace, like "quick brown" and "brown fox" for "quick brown fox" with 2.
+///
+/// A string with fewer than `n` characters or words is a single shingle. An `n` of 0 is 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shingles {
+    Chars(usize),
+    Words(usize),
+}
+
+impl Default for Shingles {
+    /// Character bigrams, like [jaccard_similarity](fn.jaccard_similarity.html).
+    fn default() -> Self {
+        Shingles::Chars(2)
+    }
+}
+
+impl Shingles {
+    /// The set of the shingles of the string.
+    pub(crate) fn set(&self, string: &str) -> HashSet<String> {
+        match *self {
+            Shingles::Chars(n) => {
+                let chars = chars(string);
+                ngrams(&chars, n.max(1))
+                    .into_keys()
+                    .map(|gram| gram.iter().collect())
+                    .collect()
+            }
+            Shingles::Words(n) => {
+                let words: Vec<&str> = string.split_whitespace().collect();
+                ngrams(&words, n.max(1))
+                    .into_keys()
+                    .map(|gram| gram.join(" "))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// # Struct: JaccardScorer
+/// The Jaccard index of the shingles of two strings, like [jaccard_similarity](fn.jaccard_similarity.html),
+/// with any [Shingles](enum.Shingles.html).
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::jaccard::{JaccardScorer, Shingles};
+/// use correct_word::{Corrector, Scorer};
+///
+/// let words = JaccardScorer::new(Shingles::Words(1));
+/// assert_eq!(words.similarity("the quick brown fox", "the quick red fox"), 0.6);
+///
+/// let options = ["the quick brown fox", "a lazy dog"];
+/// let corrector = Corrector::new(words).threshold(0.5);
+/// assert_eq!(corrector.correct("brown fox the quick", &options).word.unwrap(), "the quick brown fox");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct JaccardScorer {
+    shingles: Shingles,
+}
+
+impl JaccardScorer {
+    /// Creates a scorer over the given shingles.
+    pub fn new(shingles: Shingles) -> Self {
+        JaccardScorer { shingles }
+    }
+}
+
+impl Scorer for JaccardScorer {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        if a == b {
+            return 1.0;
+        }
+        similarity_sets(&self.shingles.set(a), &self.shingles.set(b))
+    }
+
+    /// The number of different shingles that are in one string but not in the other.
+    fn distance(&self, a: &str, b: &str) -> u16 {
+        let a = self.shingles.set(a);
+        let b = self.shingles.set(b);
+        a.symmetric_difference(&b).count() as u16
+    }
+}
+
+fn similarity_sets<T: Hash + Eq>(set1: &HashSet<T>, set2: &HashSet<T>) -> f64 {
+    if set1.is_empty() || set2.is_empty() {
+        return if set1.is_empty() && set2.is_empty() {
+            1.0
+        } else {
+            0.0
+        };
+    }
+    let common = set1.intersection(set2).count();
+    common as f64 / (set1.len() + set2.len() - common) as f64
+}
+
+/// The Jaccard index of the n-grams of any two sequences of units, like the tokens of a [Granularity](../../enum.Granularity.html).
+pub(crate) fn similarity_slices<T: Hash + Eq>(string1: &[T], string2: &[T], n: usize) -> f64 {
+    if let Some(similarity) = trivial_similarity(string1, string2) {
+        return similarity;
+    }
+    let set1: HashSet<&[T]> = ngrams(string1, n).into_keys().collect();
+    let set2: HashSet<&[T]> = ngrams(string2, n).into_keys().collect();
+    similarity_sets(&set1, &set2)
+}
+
+/// The number of different n-grams of any two sequences of units that are in one but not in the other.
+pub(crate) fn distance_slices<T: Hash + Eq>(string1: &[T], string2: &[T], n: usize) -> usize {
+    let set1: HashSet<&[T]> = ngrams(string1, n).into_keys().collect();
+    let set2: HashSet<&[T]> = ngrams(string2, n).into_keys().collect();
+    set1.symmetric_difference(&set2).count()
+}
@@ -0,0 +1,212 @@
+use crate::metrics::common::chars;
+use crate::Granularity;
+
+/// Uses the [Longest Common Subsequence](https://en.wikipedia.org/wiki/Longest_common_subsequence) algorithm
+/// to calculate the length of the longest sequence of characters that appears in both strings in the same order.
+/// The characters of the subsequence don't have to be next to each other.
+///
+/// The algorithm is implemented using a dynamic programming approach, keeping only a single row of the matrix.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `u16` - The length of the longest common subsequence.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::lcs::lcs_length;
+///
+/// let length = lcs_length("colour".to_string(), "color".to_string());
+/// assert_eq!(length, 5);
+/// ```
+pub fn lcs_length(string1: String, string2: String) -> u16 {
+    let chars1 = chars(&string1);
+    let chars2 = chars(&string2);
+    lcs_length_slice(&chars1, &chars2) as u16
+}
+
+/// Uses the Longest Common Subsequence to calculate the distance between two strings.
+/// The distance is the number of characters that have to be inserted or deleted to turn one string into the other,
+/// which is the edit distance when substitutions are not allowed.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `u16` - The distance between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::lcs::lcs_distance;
+///
+/// let distance = lcs_distance("colour".to_string(), "color".to_string());
+/// assert_eq!(distance, 1);
+/// ```
+pub fn lcs_distance(string1: String, string2: String) -> u16 {
+    let chars1 = chars(&string1);
+    let chars2 = chars(&string2);
+    lcs_distance_slice(&chars1, &chars2) as u16
+}
+
+/// Uses the Longest Common Subsequence as a measure to calculate the similarity between two strings.
+/// Returns a f64 from 0 to 1: 1 being equal strings and 0 being strings that don't share a single character.
+/// Two empty strings are equal, and an empty string has a similarity of 0 to any other string.
+///
+/// The similarity is twice the subsequence length over the total number of characters,
+/// so extra characters in either string are penalised equally.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::lcs::lcs_similarity;
+///
+/// let similarity = lcs_similarity("abcd".to_string(), "abd".to_string());
+/// assert!((similarity - 6.0 / 7.0).abs() < 1e-9);
+/// ```
+pub fn lcs_similarity(string1: String, string2: String) -> f64 {
+    let chars1 = chars(&string1);
+    let chars2 = chars(&string2);
+    lcs_similarity_slice(&chars1, &chars2)
+}
+
+/// Calculates the LCS distance like [lcs_distance](fn.lcs_distance.html),
+/// counting the units of the given [Granularity](../../enum.Granularity.html) instead of characters.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+/// * `granularity` - What counts as a single unit, like a byte, a character or a word.
+///
+/// # Returns
+///
+/// `u16` - The number of units to insert or delete to turn one string into the other.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::lcs::lcs_distance_with;
+/// use correct_word::Granularity;
+///
+/// let distance = lcs_distance_with("new york mets".to_string(), "new york city mets".to_string(), Granularity::Words);
+/// assert_eq!(distance, 1);
+/// ```
+pub fn lcs_distance_with(string1: String, string2: String, granularity: Granularity) -> u16 {
+    let tokens1 = granularity.tokens(&string1);
+    let tokens2 = granularity.tokens(&string2);
+    lcs_distance_slice(&tokens1, &tokens2) as u16
+}
+
+/// Calculates the LCS similarity like [lcs_similarity](fn.lcs_similarity.html),
+/// in the units of the given [Granularity](../../enum.Granularity.html) instead of characters.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+/// * `granularity` - What counts as a single unit, like a byte, a character or a word.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two strings.
+pub fn lcs_similarity_with(string1: String, string2: String, granularity: Granularity) -> f64 {
+    let tokens1 = granularity.tokens(&string1);
+    let tokens2 = granularity.tokens(&string2);
+    lcs_similarity_slice(&tokens1, &tokens2)
+}
+
+/// Calculates the LCS distance like [lcs_distance](fn.lcs_distance.html),
+/// between any two sequences of comparable elements, like tokens or the pixels of a row.
+///
+/// # Arguments
+///
+/// * `string1` - The first sequence to compare.
+/// * `string2` - The second sequence to compare.
+///
+/// # Returns
+///
+/// `usize` - The number of elements to insert or delete to turn one sequence into the other.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::lcs::lcs_distance_slice;
+///
+/// let row1: Vec<u8> = vec![0, 0, 255, 255, 0];
+/// let row2: Vec<u8> = vec![0, 255, 255, 0, 0];
+/// assert_eq!(lcs_distance_slice(&row1, &row2), 2);
+/// ```
+pub fn lcs_distance_slice<T: PartialEq>(string1: &[T], string2: &[T]) -> usize {
+    string1.len() + string2.len() - 2 * lcs_length_slice(string1, string2)
+}
+
+/// Calculates the LCS similarity like [lcs_similarity](fn.lcs_similarity.html),
+/// between any two sequences of comparable elements.
+///
+/// # Arguments
+///
+/// * `string1` - The first sequence to compare.
+/// * `string2` - The second sequence to compare.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two sequences.
+pub fn lcs_similarity_slice<T: PartialEq>(string1: &[T], string2: &[T]) -> f64 {
+    let total = string1.len() + string2.len();
+    if total == 0 {
+        return 1.0;
+    }
+    2.0 * lcs_length_slice(string1, string2) as f64 / total as f64
+}
+
+/// Calculates the length of the longest common subsequence like [lcs_length](fn.lcs_length.html),
+/// between any two sequences of comparable elements.
+///
+/// # Arguments
+///
+/// * `chars1` - The first sequence to compare.
+/// * `chars2` - The second sequence to compare.
+///
+/// # Returns
+///
+/// `usize` - The length of the longest common subsequence.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::lcs::lcs_length_slice;
+///
+/// assert_eq!(lcs_length_slice(&["a", "b", "c", "d"], &["a", "c", "d", "e"]), 3);
+/// ```
+pub fn lcs_length_slice<T: PartialEq>(chars1: &[T], chars2: &[T]) -> usize {
+    if chars1.len() < chars2.len() {
+        return lcs_length_slice(chars2, chars1);
+    }
+
+    let mut previous_row = vec![0; chars2.len() + 1];
+    for c1 in chars1 {
+        let mut current_row = vec![0];
+        for (j, c2) in chars2.iter().enumerate() {
+            if c1 == c2 {
+                current_row.push(previous_row[j] + 1);
+            } else {
+                current_row.push(previous_row[j + 1].max(current_row[j]));
+            }
+        }
+        previous_row = current_row;
+    }
+
+    previous_row[chars2.len()]
+}
@@ -0,0 +1,850 @@
+use crate::metrics::alignment::{hirschberg, Column, Scores};
+use crate::metrics::common::{chars, similarity, Matrix};
+use crate::Granularity;
+
+/// Uses the [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance) algorithm to calculate the distance between two strings.
+/// The lower the distance, the better the correction.
+///
+/// The Levenshtein Distance as a number is not a measure of string similarity.
+/// For similarity of string, use levenshtein_similarity.
+///
+/// The algorithm is implemented using a dynamic programming approach.
+/// In this, the distance between two strings is calculated by calculating the distance between the prefixes of the strings.
+/// Look at the wiki page for more information.
+///
+/// When both strings are ASCII, the distance is calculated with
+/// [Myers' bit-parallel algorithm](https://doi.org/10.1145/316542.316550) instead,
+/// which handles 64 characters of the shorter string per machine word and gives the same result much faster.
+///
+/// This function is used by the Levenshtein algorithm in the Algorithm enum.
+/// You are free to use it in your own code, however, the most common use case is to use the correct function.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `u16` - The distance between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::levenshtein::levenshtein_distance;
+///
+/// let distance = levenshtein_distance("hilo".to_string(), "hello".to_string());
+/// assert_eq!(distance, 2);
+/// ```
+pub fn levenshtein_distance(string1: String, string2: String) -> u16 {
+    LevenshteinState::new().distance(&string1, &string2)
+}
+
+/// # Struct: Levenshtein State
+/// Scratch space for calculating many Levenshtein distances in a row.
+///
+/// Every distance calculation needs a few buffers: the characters of both strings and a row of the DP matrix,
+/// or the bit vectors of the bit-parallel algorithm.
+/// The state keeps these buffers around between calls, so scoring one input against a long list of options
+/// allocates only when a string is longer than every string before it.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::levenshtein::LevenshteinState;
+///
+/// let mut state = LevenshteinState::new();
+/// let options = ["hello", "help", "world"];
+/// let distances: Vec<u16> = options.iter().map(|option| state.distance("helo", option)).collect();
+/// assert_eq!(distances, vec![1, 1, 4]);
+/// ```
+#[derive(Default)]
+pub struct LevenshteinState {
+    chars1: Vec<char>,
+    chars2: Vec<char>,
+    row: Vec<usize>,
+    peq: Vec<[u64; 256]>,
+    vp: Vec<u64>,
+    vn: Vec<u64>,
+}
+
+impl LevenshteinState {
+    /// Creates a state with empty buffers. They grow on first use.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calculates the Levenshtein distance between two strings, the same as [levenshtein_distance](fn.levenshtein_distance.html).
+    pub fn distance(&mut self, string1: &str, string2: &str) -> u16 {
+        if string1.is_ascii() && string2.is_ascii() {
+            return self.myers_distance(string1.as_bytes(), string2.as_bytes()) as u16;
+        }
+
+        self.chars1.clear();
+        self.chars1.extend(string1.chars());
+        self.chars2.clear();
+        self.chars2.extend(string2.chars());
+        self.dp_distance() as u16
+    }
+
+    /// Calculates the Levenshtein similarity between two strings, the same as [levenshtein_similarity](fn.levenshtein_similarity.html).
+    pub fn similarity(&mut self, string1: &str, string2: &str) -> f64 {
+        let distance = self.distance(string1, string2);
+        let length = std::cmp::max(string1.chars().count(), string2.chars().count());
+        similarity(distance as usize, length)
+    }
+
+    /// The classic DP over `chars1` and `chars2`.
+    fn dp_distance(&mut self) -> usize {
+        dp_distance(&mut self.row, &self.chars1, &self.chars2)
+    }
+
+    /// Calculates the Levenshtein distance between two strings if it is at most `max`, the same as
+    /// [levenshtein_distance_within](fn.levenshtein_distance_within.html).
+    pub fn distance_within(&mut self, string1: &str, string2: &str, max: u16) -> Option<u16> {
+        let max = max as usize;
+        if string1.is_ascii() && string2.is_ascii() {
+            let (bytes1, bytes2) = (string1.as_bytes(), string2.as_bytes());
+            if bytes1.len().abs_diff(bytes2.len()) > max {
+                return None;
+            }
+            // Every word of a bit vector covers 64 cells of a column,
+            // so the band only does less work when it is narrower than the words of the shorter string
+            let words = bytes1.len().min(bytes2.len()).div_ceil(64);
+            if 2 * max + 1 >= words {
+                let distance = self.myers_distance(bytes1, bytes2);
+                return (distance <= max).then_some(distance as u16);
+            }
+            return banded_distance(&mut self.row, bytes1, bytes2, max).map(|d| d as u16);
+        }
+
+        self.chars1.clear();
+        self.chars1.extend(string1.chars());
+        self.chars2.clear();
+        self.chars2.extend(string2.chars());
+        banded_distance(&mut self.row, &self.chars1, &self.chars2, max).map(|d| d as u16)
+    }
+
+    /// Calculates the Levenshtein distance between any two sequences, the same as [levenshtein_distance_slice](fn.levenshtein_distance_slice.html).
+    pub fn distance_slice<T: PartialEq>(&mut self, string1: &[T], string2: &[T]) -> usize {
+        dp_distance(&mut self.row, string1, string2)
+    }
+
+    /// Calculates the Levenshtein distance between two byte strings, the same as [levenshtein_distance_bytes](fn.levenshtein_distance_bytes.html).
+    pub fn distance_bytes(&mut self, string1: &[u8], string2: &[u8]) -> usize {
+        self.myers_distance(string1, string2)
+    }
+
+    /// Myers' bit-vector algorithm, in the blocked form described by Hyyrö, for byte strings.
+    ///
+    /// Every column of the DP matrix is kept as two bit vectors of vertical +1 and -1 deltas,
+    /// one bit per byte of the pattern, so a whole column is updated with a handful of word operations.
+    /// Patterns longer than 64 bytes are split into blocks, passing the horizontal delta
+    /// at the bottom of each block on to the next one.
+    fn myers_distance(&mut self, string1: &[u8], string2: &[u8]) -> usize {
+        let (pattern, text) = if string1.len() < string2.len() {
+            (string1, string2)
+        } else {
+            (string2, string1)
+        };
+
+        if pattern.is_empty() {
+            return text.len();
+        }
+
+        let words = pattern.len().div_ceil(64);
+        if self.peq.len() < words {
+            self.peq.resize(words, [0; 256]);
+        }
+        for (i, c) in pattern.iter().enumerate() {
+            self.peq[i / 64][*c as usize] |= 1 << (i % 64);
+        }
+
+        self.vp.clear();
+        self.vp.resize(words, u64::MAX);
+        self.vn.clear();
+        self.vn.resize(words, 0);
+
+        let last = 1u64 << ((pattern.len() - 1) % 64);
+        let mut distance = pattern.len();
+
+        for c in text {
+            // The top row of the matrix is 0, 1, 2, ..., so every step to the right starts with a +1
+            let mut hp_carry = 1;
+            let mut hn_carry = 0;
+            for word in 0..words {
+                let vp = self.vp[word];
+                let vn = self.vn[word];
+                let x = self.peq[word][*c as usize] | hn_carry;
+                let d0 = ((x & vp).wrapping_add(vp) ^ vp) | x | vn;
+                let mut hp = vn | !(d0 | vp);
+                let mut hn = d0 & vp;
+
+                let hp_carry_in = hp_carry;
+                let hn_carry_in = hn_carry;
+                if word < words - 1 {
+                    hp_carry = hp >> 63;
+                    hn_carry = hn >> 63;
+                } else {
+                    hp_carry = (hp & last != 0) as u64;
+                    hn_carry = (hn & last != 0) as u64;
+                }
+
+                hp = (hp << 1) | hp_carry_in;
+                hn = (hn << 1) | hn_carry_in;
+                self.vp[word] = hn | !(d0 | hp);
+                self.vn[word] = hp & d0;
+            }
+
+            distance += hp_carry as usize;
+            distance -= hn_carry as usize;
+        }
+
+        // Only the bits of the pattern were set, so clearing them leaves the table empty for the next call
+        for (i, c) in pattern.iter().enumerate() {
+            self.peq[i / 64][*c as usize] = 0;
+        }
+
+        distance
+    }
+}
+
+/// The classic DP, keeping a single row of the matrix in `row`.
+fn dp_distance<T: PartialEq>(row: &mut Vec<usize>, string1: &[T], string2: &[T]) -> usize {
+    let (longer, shorter) = if string1.len() < string2.len() {
+        (string2, string1)
+    } else {
+        (string1, string2)
+    };
+
+    if shorter.is_empty() {
+        return longer.len();
+    }
+
+    row.clear();
+    row.extend(0..shorter.len() + 1);
+    for (i, c1) in longer.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, c2) in shorter.iter().enumerate() {
+            let above = row[j + 1];
+            let insertions = above + 1;
+            let deletions = row[j] + 1;
+            let substitutions = diagonal + if c1 == c2 { 0 } else { 1 };
+            row[j + 1] = insertions.min(deletions).min(substitutions);
+            diagonal = above;
+        }
+    }
+
+    row[shorter.len()]
+}
+
+/// Ukkonen's banded DP: only the cells at most `max` diagonals away from the main one are calculated,
+/// since a path through any other cell makes more than `max` edits. The rest of the row is left at `max + 1`.
+/// None when the distance is more than `max`, as soon as a whole band of a row is.
+fn banded_distance<T: PartialEq>(
+    row: &mut Vec<usize>,
+    string1: &[T],
+    string2: &[T],
+    max: usize,
+) -> Option<usize> {
+    let (longer, shorter) = if string1.len() < string2.len() {
+        (string2, string1)
+    } else {
+        (string1, string2)
+    };
+
+    if longer.len() - shorter.len() > max {
+        return None;
+    }
+    if max >= longer.len() {
+        return Some(dp_distance(row, string1, string2));
+    }
+
+    let outside = max + 1;
+    row.clear();
+    row.extend((0..=shorter.len()).map(|j| j.min(outside)));
+    for (i, c1) in longer.iter().enumerate() {
+        let first = (i + 1).saturating_sub(max).max(1);
+        let last = (i + 1 + max).min(shorter.len());
+        let mut diagonal = row[first - 1];
+        // The cell left of the band is the first column while the band touches it, and outside of it after
+        let mut left = if first == 1 {
+            (i + 1).min(outside)
+        } else {
+            outside
+        };
+        row[first - 1] = left;
+        let mut smallest = left;
+        for j in first..=last {
+            let above = row[j];
+            let substitution = diagonal + if *c1 == shorter[j - 1] { 0 } else { 1 };
+            let value = (above + 1).min(left + 1).min(substitution).min(outside);
+            diagonal = above;
+            row[j] = value;
+            left = value;
+            smallest = smallest.min(value);
+        }
+        if smallest > max {
+            return None;
+        }
+    }
+
+    let distance = row[shorter.len()];
+    (distance <= max).then_some(distance)
+}
+
+/// The Levenshtein DP over units that can be partly equal, like the words or path components of two strings.
+/// Inserting or deleting a unit costs 1, and replacing one costs `substitution`, from 0 for equal units to 1.
+pub(crate) fn alignment_cost<T>(
+    string1: &[T],
+    string2: &[T],
+    substitution: impl Fn(&T, &T) -> f64,
+) -> f64 {
+    let mut row: Vec<f64> = (0..=string2.len()).map(|j| j as f64).collect();
+    for (i, a) in string1.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = (i + 1) as f64;
+        for (j, b) in string2.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (above + 1.0)
+                .min(row[j] + 1.0)
+                .min(diagonal + substitution(a, b));
+            diagonal = above;
+        }
+    }
+    row[string2.len()]
+}
+
+/// Uses the [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance) algorithm
+/// as a measure to calculate the similarity beyween two strings.
+/// Returns a f64 from 0 to 1: 1 being equal strings and 0 being completely different strings.
+/// Two empty strings are equal, and an empty string has a similarity of 0 to any other string.
+///
+/// If you want to know if two strings are nearly equal; 0.5 is a good measure.
+/// This is what you want to use in most cases instead of calcuating distance.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::levenshtein::levenshtein_similarity;
+///
+/// assert_eq!(levenshtein_similarity("hello".to_string(), "helo".to_string()), 0.8);
+/// assert_eq!(levenshtein_similarity("".to_string(), "".to_string()), 1.0);
+/// ```
+pub fn levenshtein_similarity(string1: String, string2: String) -> f64 {
+    LevenshteinState::new().similarity(&string1, &string2)
+}
+
+/// Calculates the Levenshtein distance like [levenshtein_distance](fn.levenshtein_distance.html),
+/// but only if it is at most `max`, for when anything further away is of no use, like a correction under a maximum distance.
+///
+/// It uses [Ukkonen's banded DP](https://doi.org/10.1016/S0019-9958(85)80046-2), which only calculates the diagonal band
+/// of the DP matrix that is at most `max` cells away from the corner to corner diagonal, and stops as soon as a whole row
+/// of the band is more than `max`. That takes time proportional to the length of the strings times `2 * max + 1`
+/// instead of the product of their lengths, so two long strings that are close are compared quickly.
+/// Strings whose lengths differ by more than `max` aren't compared at all.
+/// Short ASCII strings are still compared with the bit-parallel algorithm when it is faster than the band.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+/// * `max` - The largest distance to calculate.
+///
+/// # Returns
+///
+/// `Option<u16>` - The distance between the two strings, or None if it is more than `max`.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::levenshtein::levenshtein_distance_within;
+///
+/// assert_eq!(levenshtein_distance_within("hilo".to_string(), "hello".to_string(), 2), Some(2));
+/// assert_eq!(levenshtein_distance_within("hilo".to_string(), "hello".to_string(), 1), None);
+///
+/// let text = "água ".repeat(2000);
+/// let edited = text.replacen("água", "agua", 3);
+/// assert_eq!(levenshtein_distance_within(text, edited, 5), Some(3));
+/// ```
+pub fn levenshtein_distance_within(string1: String, string2: String, max: u16) -> Option<u16> {
+    LevenshteinState::new().distance_within(&string1, &string2, max)
+}
+
+/// Calculates the Levenshtein distance like [levenshtein_distance](fn.levenshtein_distance.html),
+/// between any two sequences of comparable elements, like the tokens of a lexer or the pixels of a row.
+///
+/// It uses the classic DP, not the bit-parallel algorithm, which only works on strings.
+/// Unlike the distance of two strings, this one isn't capped to a `u16`, since sequences can be much longer than words.
+///
+/// # Arguments
+///
+/// * `string1` - The first sequence to compare.
+/// * `string2` - The second sequence to compare.
+///
+/// # Returns
+///
+/// `usize` - The number of elements to insert, delete or substitute to turn one sequence into the other.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::levenshtein::levenshtein_distance_slice;
+///
+/// let tokens1 = ["let", "x", "=", "1", ";"];
+/// let tokens2 = ["let", "mut", "x", "=", "2", ";"];
+/// assert_eq!(levenshtein_distance_slice(&tokens1, &tokens2), 2);
+///
+/// let row1: Vec<u8> = vec![0, 0, 255, 255];
+/// let row2: Vec<u8> = vec![0, 255, 255, 255];
+/// assert_eq!(levenshtein_distance_slice(&row1, &row2), 1);
+/// ```
+pub fn levenshtein_distance_slice<T: PartialEq>(string1: &[T], string2: &[T]) -> usize {
+    dp_distance(&mut Vec::new(), string1, string2)
+}
+
+/// Calculates the Levenshtein similarity like [levenshtein_similarity](fn.levenshtein_similarity.html),
+/// between any two sequences of comparable elements, relative to the length of the longer one.
+///
+/// # Arguments
+///
+/// * `string1` - The first sequence to compare.
+/// * `string2` - The second sequence to compare.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two sequences.
+pub fn levenshtein_similarity_slice<T: PartialEq>(string1: &[T], string2: &[T]) -> f64 {
+    let distance = levenshtein_distance_slice(string1, string2);
+    similarity(distance, string1.len().max(string2.len()))
+}
+
+/// Calculates the Levenshtein distance like [levenshtein_distance](fn.levenshtein_distance.html),
+/// between two byte strings that don't have to be valid UTF-8, like raw identifiers, file names on Unix or binary tokens.
+///
+/// Every byte is a unit, so a non-ASCII character counts as several edits, the same as [Granularity::Bytes](../../enum.Granularity.html).
+/// It always uses the bit-parallel algorithm, which works on any bytes.
+///
+/// # Arguments
+///
+/// * `string1` - The first byte string to compare.
+/// * `string2` - The second byte string to compare.
+///
+/// # Returns
+///
+/// `usize` - The number of bytes to insert, delete or substitute to turn one byte string into the other.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::levenshtein::levenshtein_distance_bytes;
+///
+/// assert_eq!(levenshtein_distance_bytes(b"hilo", b"hello"), 2);
+///
+/// // Latin-1 bytes, which are not valid UTF-8
+/// assert_eq!(levenshtein_distance_bytes(b"caf\xe9", b"cafe"), 1);
+/// ```
+pub fn levenshtein_distance_bytes(string1: &[u8], string2: &[u8]) -> usize {
+    LevenshteinState::new().distance_bytes(string1, string2)
+}
+
+/// Calculates the Levenshtein similarity like [levenshtein_similarity](fn.levenshtein_similarity.html),
+/// between two byte strings that don't have to be valid UTF-8, relative to the length of the longer one in bytes.
+///
+/// # Arguments
+///
+/// * `string1` - The first byte string to compare.
+/// * `string2` - The second byte string to compare.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two byte strings.
+pub fn levenshtein_similarity_bytes(string1: &[u8], string2: &[u8]) -> f64 {
+    let distance = levenshtein_distance_bytes(string1, string2);
+    similarity(distance, string1.len().max(string2.len()))
+}
+
+/// Calculates the Levenshtein distance like [levenshtein_distance](fn.levenshtein_distance.html),
+/// counting edits in the units of the given [Granularity](../../enum.Granularity.html) instead of characters.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+/// * `granularity` - What counts as a single unit, like a byte, a character or a word.
+///
+/// # Returns
+///
+/// `u16` - The number of units to insert, delete or substitute to turn one string into the other.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::levenshtein::levenshtein_distance_with;
+/// use correct_word::Granularity;
+///
+/// let distance = levenshtein_distance_with("new york mets".to_string(), "new york jets".to_string(), Granularity::Words);
+/// assert_eq!(distance, 1);
+/// ```
+pub fn levenshtein_distance_with(
+    string1: String,
+    string2: String,
+    granularity: Granularity,
+) -> u16 {
+    if granularity == Granularity::Chars {
+        return levenshtein_distance(string1, string2);
+    }
+
+    let tokens1 = granularity.tokens(&string1);
+    let tokens2 = granularity.tokens(&string2);
+    LevenshteinState::new().distance_slice(&tokens1, &tokens2) as u16
+}
+
+/// Calculates the Levenshtein similarity like [levenshtein_similarity](fn.levenshtein_similarity.html),
+/// in the units of the given [Granularity](../../enum.Granularity.html) instead of characters.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+/// * `granularity` - What counts as a single unit, like a byte, a character or a word.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two strings.
+pub fn levenshtein_similarity_with(
+    string1: String,
+    string2: String,
+    granularity: Granularity,
+) -> f64 {
+    levenshtein_similarity_slice(&granularity.tokens(&string1), &granularity.tokens(&string2))
+}
+
+/// # Enum: Edit Op
+/// A single edit that turns one string into another, as returned by [levenshtein_ops](fn.levenshtein_ops.html).
+///
+/// Every edit has the position, in characters, where it happens in both strings:
+/// `source` is the position in the first string and `target` is the position in the second string.
+/// * Insert: `char` is inserted, and is found at `target` in the second string.
+/// * Delete: `char`, at `source` in the first string, is deleted.
+/// * Substitute: `from`, at `source` in the first string, is replaced with `to`, at `target` in the second string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOp {
+    Insert {
+        source: usize,
+        target: usize,
+        char: char,
+    },
+    Delete {
+        source: usize,
+        target: usize,
+        char: char,
+    },
+    Substitute {
+        source: usize,
+        target: usize,
+        from: char,
+        to: char,
+    },
+}
+
+/// Finds the edits that turn the first string into the second, using the Levenshtein distance.
+/// The number of edits is always the [levenshtein_distance](fn.levenshtein_distance.html) between the strings.
+///
+/// Unlike the distance, this keeps the whole DP matrix and walks it back from the end,
+/// so it uses memory proportional to the product of the string lengths.
+/// For long strings, use [levenshtein_ops_linear](fn.levenshtein_ops_linear.html).
+/// When there are several ways to make the fewest edits, substitutions are preferred over deletions, and deletions over insertions.
+///
+/// # Arguments
+///
+/// * `string1` - The string to start from.
+/// * `string2` - The string to end up with.
+///
+/// # Returns
+///
+/// `Vec<EditOp>` - The edits, from the start of the strings to the end.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::levenshtein::{levenshtein_ops, EditOp};
+///
+/// let ops = levenshtein_ops("kitten".to_string(), "sitting".to_string());
+/// assert_eq!(ops, vec![
+///     EditOp::Substitute { source: 0, target: 0, from: 'k', to: 's' },
+///     EditOp::Substitute { source: 4, target: 4, from: 'e', to: 'i' },
+///     EditOp::Insert { source: 6, target: 6, char: 'g' },
+/// ]);
+/// ```
+pub fn levenshtein_ops(string1: String, string2: String) -> Vec<EditOp> {
+    let chars1 = chars(&string1);
+    let chars2 = chars(&string2);
+    let mut matrix = Matrix::new(chars1.len() + 1, chars2.len() + 1, 0);
+    for j in 0..=chars2.len() {
+        matrix[(0, j)] = j;
+    }
+    for (i, c1) in chars1.iter().enumerate() {
+        matrix[(i + 1, 0)] = i + 1;
+        for (j, c2) in chars2.iter().enumerate() {
+            let insertions = matrix[(i + 1, j)] + 1;
+            let deletions = matrix[(i, j + 1)] + 1;
+            let substitutions = matrix[(i, j)] + if c1 == c2 { 0 } else { 1 };
+            matrix[(i + 1, j + 1)] = insertions.min(deletions).min(substitutions);
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (chars1.len(), chars2.len());
+    while i > 0 || j > 0 {
+        let current = matrix[(i, j)];
+        if i > 0 && j > 0 && chars1[i - 1] == chars2[j - 1] && current == matrix[(i - 1, j - 1)] {
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && current == matrix[(i - 1, j - 1)] + 1 {
+            i -= 1;
+            j -= 1;
+            ops.push(EditOp::Substitute {
+                source: i,
+                target: j,
+                from: chars1[i],
+                to: chars2[j],
+            });
+        } else if i > 0 && current == matrix[(i - 1, j)] + 1 {
+            i -= 1;
+            ops.push(EditOp::Delete {
+                source: i,
+                target: j,
+                char: chars1[i],
+            });
+        } else {
+            j -= 1;
+            ops.push(EditOp::Insert {
+                source: i,
+                target: j,
+                char: chars2[j],
+            });
+        }
+    }
+
+    ops.reverse();
+    ops
+}
+
+/// Finds the edits that turn the first string into the second like [levenshtein_ops](fn.levenshtein_ops.html),
+/// with [Hirschberg's algorithm](https://en.wikipedia.org/wiki/Hirschberg%27s_algorithm) instead of the whole DP matrix.
+///
+/// It splits the first string in half, finds where the best path through the matrix crosses the middle row
+/// from the last rows of the DPs of both halves, and does the same with each half, so it keeps only two rows at a time.
+/// That takes memory proportional to the length of the shorter string, besides the edits themselves,
+/// and about twice the time of [levenshtein_ops](fn.levenshtein_ops.html), so it is the one for long texts, like two versions of a document.
+/// There are always as many edits as the Levenshtein distance, but when there are several ways to make them,
+/// it may pick another one than [levenshtein_ops](fn.levenshtein_ops.html).
+///
+/// # Arguments
+///
+/// * `string1` - The string to start from.
+/// * `string2` - The string to end up with.
+///
+/// # Returns
+///
+/// `Vec<EditOp>` - The edits, from the start of the strings to the end.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::levenshtein::{levenshtein_distance, levenshtein_ops_linear, EditOp};
+///
+/// let ops = levenshtein_ops_linear("kitten".to_string(), "sitting".to_string());
+/// assert_eq!(ops.len(), 3);
+/// assert_eq!(ops[0], EditOp::Substitute { source: 0, target: 0, from: 'k', to: 's' });
+///
+/// let text = "the quick brown fox ".repeat(500);
+/// let edited = text.replacen("quick", "quack", 1);
+/// assert_eq!(levenshtein_ops_linear(text, edited).len(), 1);
+/// ```
+pub fn levenshtein_ops_linear(string1: String, string2: String) -> Vec<EditOp> {
+    let chars1 = chars(&string1);
+    let chars2 = chars(&string2);
+    // The position in the other string, for the edits that only touch one of them
+    let (mut source, mut target) = (0, 0);
+    let mut ops = Vec::new();
+    for column in hirschberg(&chars1, &chars2, Scores::LEVENSHTEIN) {
+        match column {
+            Column::Aligned(i, j) => {
+                if chars1[i] != chars2[j] {
+                    ops.push(EditOp::Substitute {
+                        source: i,
+                        target: j,
+                        from: chars1[i],
+                        to: chars2[j],
+                    });
+                }
+                (source, target) = (i + 1, j + 1);
+            }
+            Column::Deleted(i) => {
+                ops.push(EditOp::Delete {
+                    source: i,
+                    target,
+                    char: chars1[i],
+                });
+                source = i + 1;
+            }
+            Column::Inserted(j) => {
+                ops.push(EditOp::Insert {
+                    source,
+                    target: j,
+                    char: chars2[j],
+                });
+                target = j + 1;
+            }
+        }
+    }
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dp_distance(string1: &str, string2: &str) -> usize {
+        let chars2 = chars(string2);
+        let mut previous_row: Vec<usize> = (0..chars2.len() + 1).collect();
+        for (i, c1) in string1.chars().enumerate() {
+            let mut current_row = vec![i + 1];
+            for (j, c2) in chars2.iter().enumerate() {
+                let substitution = previous_row[j] + if c1 == *c2 { 0 } else { 1 };
+                current_row.push(
+                    (previous_row[j + 1] + 1)
+                        .min(current_row[j] + 1)
+                        .min(substitution),
+                );
+            }
+            previous_row = current_row;
+        }
+        previous_row[chars2.len()]
+    }
+
+    #[test]
+    fn myers_matches_dp() {
+        let mut seed: u64 = 42;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as usize
+        };
+        let mut state = LevenshteinState::new();
+        for _ in 0..2000 {
+            let alphabet = 2 + next() % 6;
+            let length1 = next() % 200;
+            let length2 = next() % 200;
+            let string1: String = (0..length1)
+                .map(|_| (b'a' + (next() % alphabet) as u8) as char)
+                .collect();
+            let string2: String = (0..length2)
+                .map(|_| (b'a' + (next() % alphabet) as u8) as char)
+                .collect();
+            assert_eq!(
+                state.myers_distance(string1.as_bytes(), string2.as_bytes()),
+                dp_distance(&string1, &string2),
+                "{string1:?} {string2:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn banded_matches_dp() {
+        let mut seed: u64 = 7;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as usize
+        };
+        let mut state = LevenshteinState::new();
+        for _ in 0..2000 {
+            let alphabet = ['a', 'b', 'é', '日'];
+            let length1 = next() % 300;
+            let length2 = next() % 300;
+            let string1: String = (0..length1).map(|_| alphabet[next() % 4]).collect();
+            let string2: String = (0..length2).map(|_| alphabet[next() % 4]).collect();
+            let ascii1: String = string1
+                .chars()
+                .map(|c| if c.is_ascii() { c } else { 'c' })
+                .collect();
+            let ascii2: String = string2
+                .chars()
+                .map(|c| if c.is_ascii() { c } else { 'c' })
+                .collect();
+            let max = (next() % 40) as u16;
+            for (string1, string2) in [(&string1, &string2), (&ascii1, &ascii2)] {
+                let distance = dp_distance(string1, string2);
+                let expected = (distance <= max as usize).then_some(distance as u16);
+                assert_eq!(
+                    state.distance_within(string1, string2, max),
+                    expected,
+                    "{string1:?} {string2:?} {max}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ops_rebuild_the_target() {
+        let cases = [
+            ("kitten", "sitting"),
+            ("", "abc"),
+            ("abc", ""),
+            ("flaw", "lawn"),
+            ("日本語", "本語です"),
+        ];
+        for ((string1, string2), linear) in cases
+            .into_iter()
+            .flat_map(|case| [(case, false), (case, true)])
+        {
+            let ops = if linear {
+                levenshtein_ops_linear(string1.to_string(), string2.to_string())
+            } else {
+                levenshtein_ops(string1.to_string(), string2.to_string())
+            };
+            assert_eq!(
+                ops.len(),
+                dp_distance(string1, string2),
+                "{string1:?} {string2:?}"
+            );
+
+            let mut chars: Vec<char> = string1.chars().collect();
+            // Applying the edits from the end keeps the earlier source positions valid
+            for op in ops.iter().rev() {
+                match *op {
+                    EditOp::Insert { source, char, .. } => chars.insert(source, char),
+                    EditOp::Delete { source, .. } => {
+                        chars.remove(source);
+                    }
+                    EditOp::Substitute { source, to, .. } => chars[source] = to,
+                }
+            }
+            assert_eq!(chars.into_iter().collect::<String>(), string2);
+        }
+    }
+
+    #[test]
+    fn unicode_matches_dp() {
+        let cases = [
+            ("héllo", "hello"),
+            ("", "ñu"),
+            ("日本語", "日本"),
+            ("straße", "strasse"),
+        ];
+        let mut state = LevenshteinState::new();
+        for (string1, string2) in cases {
+            assert_eq!(
+                state.distance(string1, string2) as usize,
+                dp_distance(string1, string2),
+                "{string1:?} {string2:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::metrics::common::chars;
+use crate::Granularity;
+
+/// Uses the [Ratcliff/Obershelp](https://en.wikipedia.org/wiki/Gestalt_pattern_matching) gestalt pattern matching algorithm
+/// to calculate the similarity between two strings.
+/// Returns a f64 from 0 to 1: 1 being equal strings and 0 being strings without a single common character.
+/// Two empty strings are equal, and an empty string has a similarity of 0 to any other string.
+///
+/// The algorithm finds the longest common substring, and then recursively does the same to the pieces
+/// to the left and to the right of it. The similarity is twice the number of matched characters
+/// over the total number of characters in both strings.
+///
+/// The result is the same as Python's `difflib.SequenceMatcher(None, string1, string2).ratio()`,
+/// including the "autojunk" heuristic for `string2` of 200 characters or more.
+/// Just like in difflib, the measure is not symmetric: swapping the strings can change the result.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare. This is `a` in difflib.
+/// * `string2` - The second string to compare. This is `b` in difflib.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::ratcliff_obershelp::ratcliff_obershelp_similarity;
+///
+/// let similarity = ratcliff_obershelp_similarity("abcd".to_string(), "bcde".to_string());
+/// assert_eq!(similarity, 0.75);
+///
+/// // The order of the strings matters
+/// assert_eq!(ratcliff_obershelp_similarity("tide".to_string(), "diet".to_string()), 0.25);
+/// assert_eq!(ratcliff_obershelp_similarity("diet".to_string(), "tide".to_string()), 0.5);
+/// ```
+pub fn ratcliff_obershelp_similarity(string1: String, string2: String) -> f64 {
+    let a = chars(&string1);
+    let b = chars(&string2);
+    ratio(&a, &b)
+}
+
+/// Calculates the Ratcliff/Obershelp similarity like [ratcliff_obershelp_similarity](fn.ratcliff_obershelp_similarity.html),
+/// matching blocks of the units of the given [Granularity](../../enum.Granularity.html) instead of characters.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare. This is `a` in difflib.
+/// * `string2` - The second string to compare. This is `b` in difflib.
+/// * `granularity` - What counts as a single unit, like a byte, a character or a word.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::ratcliff_obershelp::ratcliff_obershelp_similarity_with;
+/// use correct_word::Granularity;
+///
+/// let similarity = ratcliff_obershelp_similarity_with("a b c d".to_string(), "b c d e".to_string(), Granularity::Words);
+/// assert_eq!(similarity, 0.75);
+/// ```
+pub fn ratcliff_obershelp_similarity_with(
+    string1: String,
+    string2: String,
+    granularity: Granularity,
+) -> f64 {
+    ratio(&granularity.tokens(&string1), &granularity.tokens(&string2))
+}
+
+/// The number of characters of both strings that are not part of a matching block.
+/// This is the distance used by a [Threshold::MaxDistance](../../enum.Threshold.html) for this algorithm.
+pub(crate) fn unmatched<T: Hash + Eq>(a: &[T], b: &[T]) -> usize {
+    a.len() + b.len() - 2 * matching_characters(a, b)
+}
+
+pub(crate) fn ratio<T: Hash + Eq>(a: &[T], b: &[T]) -> f64 {
+    let total = a.len() + b.len();
+    if total == 0 {
+        return 1.0;
+    }
+    2.0 * matching_characters(a, b) as f64 / total as f64
+}
+
+/// Counts the characters in all the matching blocks, like summing the sizes
+/// of `SequenceMatcher.get_matching_blocks()`.
+fn matching_characters<T: Hash + Eq>(a: &[T], b: &[T]) -> usize {
+    let b2j = index_of(b);
+    let mut matched = 0;
+    let mut queue = vec![(0, a.len(), 0, b.len())];
+    while let Some((alo, ahi, blo, bhi)) = queue.pop() {
+        let (i, j, k) = find_longest_match(a, b, &b2j, alo, ahi, blo, bhi);
+        if k > 0 {
+            matched += k;
+            if alo < i && blo < j {
+                queue.push((alo, i, blo, j));
+            }
+            if i + k < ahi && j + k < bhi {
+                queue.push((i + k, ahi, j + k, bhi));
+            }
+        }
+    }
+    matched
+}
+
+/// Maps every character of `b` to the positions it occurs at.
+/// When `b` has 200 or more characters, the characters that make up more than 1% of it
+/// are left out, the same way difflib's autojunk does.
+fn index_of<T: Hash + Eq>(b: &[T]) -> HashMap<&T, Vec<usize>> {
+    let mut b2j: HashMap<&T, Vec<usize>> = HashMap::new();
+    for (j, c) in b.iter().enumerate() {
+        b2j.entry(c).or_default().push(j);
+    }
+
+    if b.len() >= 200 {
+        let popular = b.len() / 100 + 1;
+        b2j.retain(|_, positions| positions.len() <= popular);
+    }
+
+    b2j
+}
+
+/// Finds the longest block `a[i..i + k] == b[j..j + k]` inside the given bounds.
+/// Of all the longest blocks, the one that starts first in `a`, and then first in `b`, is returned.
+fn find_longest_match<T: Hash + Eq>(
+    a: &[T],
+    b: &[T],
+    b2j: &HashMap<&T, Vec<usize>>,
+    alo: usize,
+    ahi: usize,
+    blo: usize,
+    bhi: usize,
+) -> (usize, usize, usize) {
+    let (mut best_i, mut best_j, mut best_size) = (alo, blo, 0);
+    let mut j2len: HashMap<usize, usize> = HashMap::new();
+    for (i, c) in a.iter().enumerate().take(ahi).skip(alo) {
+        let mut new_j2len = HashMap::new();
+        if let Some(positions) = b2j.get(c) {
+            for &j in positions {
+                if j < blo {
+                    continue;
+                }
+                if j >= bhi {
+                    break;
+                }
+                let k = if j == 0 {
+                    0
+                } else {
+                    *j2len.get(&(j - 1)).unwrap_or(&0)
+                } + 1;
+                new_j2len.insert(j, k);
+                if k > best_size {
+                    best_i = i + 1 - k;
+                    best_j = j + 1 - k;
+                    best_size = k;
+                }
+            }
+        }
+        j2len = new_j2len;
+    }
+
+    // Characters dropped by autojunk are not in the index, so the block can still grow over them.
+    while best_i > alo && best_j > blo && a[best_i - 1] == b[best_j - 1] {
+        best_i -= 1;
+        best_j -= 1;
+        best_size += 1;
+    }
+    while best_i + best_size < ahi
+        && best_j + best_size < bhi
+        && a[best_i + best_size] == b[best_j + best_size]
+    {
+        best_size += 1;
+    }
+
+    (best_i, best_j, best_size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_difflib() {
+        let cases = [
+            (" abcd", "abcd abcd", 0.7142857142857143),
+            ("appel", "apple", 0.8),
+            ("appel", "ape", 0.75),
+            ("", "", 1.0),
+            ("abc", "", 0.0),
+        ];
+        for (a, b, expected) in cases {
+            let similarity = ratcliff_obershelp_similarity(a.to_string(), b.to_string());
+            assert!(
+                (similarity - expected).abs() < 1e-12,
+                "{a:?} {b:?}: {similarity}"
+            );
+        }
+    }
+
+    #[test]
+    fn autojunk_long_strings() {
+        // difflib: SequenceMatcher(None, "ab" * 150, "a" * 100 + "b" * 200).ratio()
+        let a = "ab".repeat(150);
+        let b = "a".repeat(100) + &"b".repeat(200);
+        let similarity = ratcliff_obershelp_similarity(a, b);
+        assert!(
+            (similarity - 0.0033333333333333335).abs() < 1e-12,
+            "{similarity}"
+        );
+    }
+}
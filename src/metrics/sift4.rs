@@ -0,0 +1,207 @@
+use crate::metrics::common::{chars, similarity};
+use crate::Scorer;
+
+/// Uses the [Sift4](https://siderite.dev/blog/super-fast-and-accurate-string-distance.html) algorithm
+/// to approximate the edit distance between two strings in linear time.
+///
+/// Sift4 walks both strings at once, and when the characters differ it looks up to 5 characters ahead in either string
+/// for the next match, instead of trying every alignment like the Levenshtein distance.
+/// Matches that were found out of order count as transpositions.
+/// The result is usually close to the Levenshtein distance, and exact for strings with a few scattered typos,
+/// but it can be further off for strings that were rearranged a lot.
+/// Use a [Sift4Scorer](struct.Sift4Scorer.html) to look further ahead.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `u16` - The approximate distance between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::sift4::sift4_distance;
+///
+/// assert_eq!(sift4_distance("kitten".to_string(), "sitting".to_string()), 3);
+/// assert_eq!(sift4_distance("hello".to_string(), "hlelo".to_string()), 1);
+/// ```
+pub fn sift4_distance(string1: String, string2: String) -> u16 {
+    Sift4Scorer::default().distance(&string1, &string2)
+}
+
+/// Uses the [Sift4](fn.sift4_distance.html) distance to calculate the similarity between two strings,
+/// relative to the length of the longer one, like [levenshtein_similarity](../levenshtein/fn.levenshtein_similarity.html).
+/// Returns a f64 from 0 to 1: 1 being equal strings and 0 being completely different strings.
+/// Two empty strings are equal, and an empty string has a similarity of 0 to any other string.
+///
+/// # Arguments
+///
+/// * `string1` - The first string to compare.
+/// * `string2` - The second string to compare.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the two strings.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::sift4::sift4_similarity;
+///
+/// assert_eq!(sift4_similarity("hello".to_string(), "helo".to_string()), 0.8);
+/// ```
+pub fn sift4_similarity(string1: String, string2: String) -> f64 {
+    Sift4Scorer::default().similarity(&string1, &string2)
+}
+
+/// # Struct: Sift4Scorer
+/// The [Sift4](fn.sift4_distance.html) distance, with how far ahead it looks for the next match.
+///
+/// A larger `max_offset` finds matches after longer insertions or deletions, and gets closer to the Levenshtein distance,
+/// at the cost of more comparisons for every mismatch. The default is 5.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::sift4::Sift4Scorer;
+/// use correct_word::{Corrector, Scorer};
+///
+/// // The match after the 6 inserted characters is too far ahead for the default offset
+/// assert_eq!(Sift4Scorer::default().distance("ab", "a123456b"), 7);
+/// assert_eq!(Sift4Scorer::new(10).distance("ab", "a123456b"), 6);
+///
+/// let corrector = Corrector::new(Sift4Scorer::new(3));
+/// assert_eq!(corrector.correct("hlelo", &["hello", "world"]).word.unwrap(), "hello");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sift4Scorer {
+    max_offset: usize,
+}
+
+impl Default for Sift4Scorer {
+    fn default() -> Self {
+        Sift4Scorer { max_offset: 5 }
+    }
+}
+
+impl Sift4Scorer {
+    /// Creates a scorer that looks up to `max_offset` characters ahead for the next match.
+    pub fn new(max_offset: usize) -> Self {
+        Sift4Scorer { max_offset }
+    }
+}
+
+impl Scorer for Sift4Scorer {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        let a = chars(a);
+        let b = chars(b);
+        similarity_slices(&a, &b, self.max_offset)
+    }
+
+    fn distance(&self, a: &str, b: &str) -> u16 {
+        let a = chars(a);
+        let b = chars(b);
+        distance_slices(&a, &b, self.max_offset) as u16
+    }
+}
+
+/// The Sift4 similarity of any two sequences of units, like the tokens of a [Granularity](../../enum.Granularity.html).
+pub(crate) fn similarity_slices<T: PartialEq>(
+    string1: &[T],
+    string2: &[T],
+    max_offset: usize,
+) -> f64 {
+    let length = string1.len().max(string2.len());
+    let distance = distance_slices(string1, string2, max_offset).min(length);
+    similarity(distance, length)
+}
+
+/// A match found while walking the strings, to tell the later matches that came before it apart as transpositions.
+struct Offset {
+    c1: usize,
+    c2: usize,
+    transposed: bool,
+}
+
+/// The common Sift4 distance, with transpositions, over any two sequences of units.
+pub(crate) fn distance_slices<T: PartialEq>(
+    string1: &[T],
+    string2: &[T],
+    max_offset: usize,
+) -> usize {
+    let (l1, l2) = (string1.len(), string2.len());
+    if l1 == 0 || l2 == 0 {
+        return l1.max(l2);
+    }
+
+    // The cursors go one before the start of a string when a match is found ahead in the other one
+    let (mut c1, mut c2): (isize, isize) = (0, 0);
+    let mut common = 0;
+    let mut local_common = 0;
+    let mut transpositions = 0;
+    let mut offsets: Vec<Offset> = Vec::new();
+
+    while (c1 as usize) < l1 && (c2 as usize) < l2 {
+        let (u1, u2) = (c1 as usize, c2 as usize);
+        if string1[u1] == string2[u2] {
+            local_common += 1;
+            let mut transposed = false;
+            let mut i = 0;
+            while i < offsets.len() {
+                let offset = &mut offsets[i];
+                if u1 <= offset.c1 || u2 <= offset.c2 {
+                    transposed = u2.abs_diff(u1) >= offset.c2.abs_diff(offset.c1);
+                    if transposed {
+                        transpositions += 1;
+                    } else if !offset.transposed {
+                        offset.transposed = true;
+                        transpositions += 1;
+                    }
+                    break;
+                } else if u1 > offset.c2 && u2 > offset.c1 {
+                    offsets.remove(i);
+                } else {
+                    i += 1;
+                }
+            }
+            offsets.push(Offset {
+                c1: u1,
+                c2: u2,
+                transposed,
+            });
+        } else {
+            common += local_common;
+            local_common = 0;
+            if c1 != c2 {
+                c1 = c1.min(c2);
+                c2 = c1;
+            }
+            let (u1, u2) = (c1 as usize, c2 as usize);
+            for i in 0..max_offset {
+                if u1 + i >= l1 && u2 + i >= l2 {
+                    break;
+                }
+                if u1 + i < l1 && string1[u1 + i] == string2[u2] {
+                    c1 += i as isize - 1;
+                    c2 -= 1;
+                    break;
+                }
+                if u2 + i < l2 && string1[u1] == string2[u2 + i] {
+                    c1 -= 1;
+                    c2 += i as isize - 1;
+                    break;
+                }
+            }
+        }
+        c1 += 1;
+        c2 += 1;
+        if c1 as usize >= l1 || c2 as usize >= l2 {
+            common += local_common;
+            local_common = 0;
+            c1 = c1.min(c2);
+            c2 = c1;
+        }
+    }
+    common += local_common;
+    (l1.max(l2) + transpositions).saturating_sub(common)
+}
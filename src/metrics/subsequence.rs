@@ -0,0 +1,473 @@
+use crate::metrics::common::chars;
+use crate::{Granularity, Scorer};
+
+/// Scores how well the input matches the candidate as an abbreviation, like the fuzzy finders fzf and fzy do.
+/// Every character of the input has to appear in the candidate, in the same order, but not next to each other,
+/// so "gcm" matches "git commit message". Letters are compared without their case.
+/// Returns a f64 from 0 to 1: 1 being an input that is there in one piece at the start of a word, or at the start of words,
+/// and 0 being an input that is not a subsequence of the candidate.
+///
+/// This is a [SubsequenceScorer](struct.SubsequenceScorer.html) with the default bonuses:
+/// every matched character scores 0.5, and 1 when it starts a word of the candidate, starts a camelCase hump,
+/// or follows the previous match.
+/// Every gap between two matched characters costs 0.05, and 0.01 for every character after the first.
+/// Of all the ways the input can be found in the candidate, the one with the best score is used,
+/// and the similarity is its score over the number of characters of the input.
+/// Characters of the candidate before the first match or after the last one don't cost anything.
+///
+/// # Arguments
+///
+/// * `input` - The abbreviation to look for.
+/// * `candidate` - The string to look in.
+///
+/// # Returns
+///
+/// `f64` - The similarity between the input and the candidate.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::subsequence::subsequence_similarity;
+///
+/// let similarity = subsequence_similarity("gcm".to_string(), "git commit message".to_string());
+/// assert!((similarity - 2.83 / 3.0).abs() < 1e-9);
+///
+/// assert_eq!(subsequence_similarity("gcm".to_string(), "magic".to_string()), 0.0);
+/// assert_eq!(subsequence_similarity("rust".to_string(), "Rust".to_string()), 1.0);
+/// ```
+pub fn subsequence_similarity(input: String, candidate: String) -> f64 {
+    SubsequenceScorer::default().similarity(&input, &candidate)
+}
+
+/// # Struct: SubsequenceScorer
+/// The scorer behind [Algorithm::Subsequence](../../enum.Algorithm.html), with bonuses and penalties that can be tuned
+/// to rank the results of a fuzzy finder.
+///
+/// Every matched character scores 0.5, plus the largest of the bonuses it gets:
+/// * Boundary: It starts a word, at the start of the candidate or after a space or punctuation. 0.5 by default.
+/// * Camel: It starts a camelCase hump, like the "C" of "getCount", or the "S" of "HTTPServer". 0.5 by default.
+/// * Consecutive: It follows the previous matched character, without a gap. 0.5 by default.
+///
+/// Every gap between two matched characters costs its [gap penalty](#method.gap_penalty),
+/// and every character before the first match costs the [leading gap penalty](#method.leading_gap_penalty), none by default.
+/// The similarity is the best score over the best a character can do, which is 1 with the defaults.
+/// Negative scores are a similarity of 0, and equal strings always have a similarity of 1.
+///
+/// [find](#method.find) also returns the positions of the matched characters, to highlight them.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::subsequence::SubsequenceScorer;
+/// use correct_word::Corrector;
+///
+/// let candidates = ["src/configure.rs", "src/config_reader.rs"];
+/// let scorer = SubsequenceScorer::default().leading_gap_penalty(0.01);
+/// let corrector = Corrector::new(scorer).threshold(0.0);
+/// assert_eq!(corrector.correct("cr", &candidates).word.unwrap(), "src/config_reader.rs");
+///
+/// let found = scorer.find("cr", "src/config_reader.rs").unwrap();
+/// assert_eq!(found.positions, vec![4, 11]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SubsequenceScorer {
+    boundary: f64,
+    camel: f64,
+    consecutive: f64,
+    leading_gap: f64,
+    gap_start: f64,
+    gap_extension: f64,
+}
+
+impl Default for SubsequenceScorer {
+    fn default() -> Self {
+        SubsequenceScorer {
+            boundary: 0.5,
+            camel: 0.5,
+            consecutive: 0.5,
+            leading_gap: 0.0,
+            gap_start: 0.05,
+            gap_extension: 0.01,
+        }
+    }
+}
+
+/// # Struct: SubsequenceMatch
+/// Where a [SubsequenceScorer](struct.SubsequenceScorer.html) found the input in a candidate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubsequenceMatch {
+    /// The similarity between the input and the candidate, from 0 to 1.
+    pub similarity: f64,
+    /// The indices of the characters of the candidate that were matched, one for every character of the input, in order.
+    /// These are indices of `chars()`, not of bytes.
+    pub positions: Vec<usize>,
+}
+
+const MATCH: f64 = 0.5;
+
+impl SubsequenceScorer {
+    /// Sets the bonus of a character that starts a word.
+    pub fn boundary_bonus(mut self, bonus: f64) -> Self {
+        self.boundary = bonus;
+        self
+    }
+
+    /// Sets the bonus of a character that starts a camelCase hump.
+    pub fn camel_bonus(mut self, bonus: f64) -> Self {
+        self.camel = bonus;
+        self
+    }
+
+    /// Sets the bonus of a character that follows the previous matched character.
+    pub fn consecutive_bonus(mut self, bonus: f64) -> Self {
+        self.consecutive = bonus;
+        self
+    }
+
+    /// Sets the penalty of every character of the candidate before the first match,
+    /// so matches closer to the start rank higher.
+    pub fn leading_gap_penalty(mut self, penalty: f64) -> Self {
+        self.leading_gap = penalty;
+        self
+    }
+
+    /// Sets the penalty of a gap between two matched characters:
+    /// `start` for the first character of the gap, and `extension` for every character after it.
+    pub fn gap_penalty(mut self, start: f64, extension: f64) -> Self {
+        self.gap_start = start;
+        self.gap_extension = extension;
+        self
+    }
+
+    /// Finds the input in the candidate, and returns the similarity and the positions of the best match.
+    /// None if the input is not a subsequence of the candidate.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The abbreviation to look for.
+    /// * `candidate` - The string to look in.
+    ///
+    /// # Returns
+    ///
+    /// `Option<SubsequenceMatch>` - The best match, if there is one.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::metrics::subsequence::SubsequenceScorer;
+    ///
+    /// let found = SubsequenceScorer::default().find("gcm", "git commit message").unwrap();
+    /// assert_eq!(found.positions, vec![0, 4, 11]);
+    /// assert!(SubsequenceScorer::default().find("gcm", "magic").is_none());
+    /// ```
+    pub fn find(&self, input: &str, candidate: &str) -> Option<SubsequenceMatch> {
+        let input = chars(input);
+        let candidate = chars(candidate);
+        if input.is_empty() {
+            return Some(SubsequenceMatch {
+                similarity: self.similarity_slices(&input, &candidate),
+                positions: Vec::new(),
+            });
+        }
+        let (score, positions) = self.align(&input, &candidate)?;
+        let similarity = if input == candidate {
+            1.0
+        } else {
+            self.normalize(score, input.len())
+        };
+        Some(SubsequenceMatch {
+            similarity,
+            positions,
+        })
+    }
+
+    pub(crate) fn similarity_slices<T: Unit + PartialEq>(
+        &self,
+        input: &[T],
+        candidate: &[T],
+    ) -> f64 {
+        if input.is_empty() {
+            return if candidate.is_empty() { 1.0 } else { 0.0 };
+        }
+        // Equal strings are a perfect match, even when they are made of separators that never get a bonus
+        if input == candidate {
+            return 1.0;
+        }
+        match self.align(input, candidate) {
+            Some((score, _)) => self.normalize(score, input.len()),
+            None => 0.0,
+        }
+    }
+
+    fn normalize(&self, score: f64, length: usize) -> f64 {
+        let best = MATCH + self.boundary.max(self.camel).max(self.consecutive);
+        (score / (best * length as f64)).clamp(0.0, 1.0)
+    }
+
+    /// The best score of all the ways to find the input in the candidate, and the positions of its units.
+    /// None if it's not there. The input must not be empty.
+    ///
+    /// `rows[i][j]` is the best score of the first `i + 1` units of the input, with the last one matched at `j`,
+    /// and where the one before it was matched.
+    /// Gaps grow by the same cost for every unit, so the best match to jump from only needs a running maximum.
+    fn align<T: Unit>(&self, input: &[T], candidate: &[T]) -> Option<(f64, Vec<usize>)> {
+        let kinds: Vec<Kind> = candidate.iter().map(Unit::kind).collect();
+        let bonuses: Vec<f64> = (0..candidate.len())
+            .map(|j| {
+                let previous = j.checked_sub(1).map_or(Kind::Separator, |i| kinds[i]);
+                let next = kinds.get(j + 1).copied().unwrap_or(Kind::Separator);
+                let mut bonus: f64 = 0.0;
+                if kinds[j] != Kind::Separator && previous == Kind::Separator {
+                    bonus = bonus.max(self.boundary);
+                }
+                if kinds[j] == Kind::Upper
+                    && (matches!(previous, Kind::Lower | Kind::Digit)
+                        || (previous == Kind::Upper && next == Kind::Lower))
+                {
+                    bonus = bonus.max(self.camel);
+                }
+                bonus
+            })
+            .collect();
+        let unit_score = |j: usize, follows: bool| {
+            let bonus = if follows {
+                bonuses[j].max(self.consecutive)
+            } else {
+                bonuses[j]
+            };
+            MATCH + bonus
+        };
+
+        let mut rows: Vec<Vec<Option<Cell>>> = Vec::with_capacity(input.len());
+        rows.push(
+            candidate
+                .iter()
+                .enumerate()
+                .map(|(j, unit)| {
+                    input[0].matches(unit).then(|| Cell {
+                        score: unit_score(j, false) - self.leading_gap * j as f64,
+                        from: j,
+                    })
+                })
+                .collect(),
+        );
+        for unit in &input[1..] {
+            let previous = rows.last().unwrap();
+            let mut current = vec![None; candidate.len()];
+            // The best match to jump to `j` from, over a gap of at least one unit
+            let mut jump: Option<Cell> = None;
+            for j in 1..candidate.len() {
+                if j >= 2 {
+                    let extended = jump.map(|cell| cell.add(-self.gap_extension));
+                    let started = previous[j - 2].map(|cell| Cell {
+                        score: cell.score - self.gap_start,
+                        from: j - 2,
+                    });
+                    jump = better(extended, started);
+                }
+                if !unit.matches(&candidate[j]) {
+                    continue;
+                }
+                let follows = previous[j - 1].map(|cell| Cell {
+                    score: cell.score + unit_score(j, true),
+                    from: j - 1,
+                });
+                let jumps = jump.map(|cell| cell.add(unit_score(j, false)));
+                current[j] = better(follows, jumps);
+            }
+            rows.push(current);
+        }
+
+        let (mut j, last) = rows
+            .last()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .filter_map(|(j, cell)| cell.map(|cell| (j, cell)))
+            .reduce(|best, next| {
+                if next.1.score > best.1.score {
+                    next
+                } else {
+                    best
+                }
+            })?;
+        let mut positions = vec![0; input.len()];
+        for i in (0..input.len()).rev() {
+            positions[i] = j;
+            j = rows[i][j].unwrap().from;
+        }
+        Some((last.score, positions))
+    }
+}
+
+impl Scorer for SubsequenceScorer {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        let a = chars(a);
+        let b = chars(b);
+        self.similarity_slices(&a, &b)
+    }
+
+    /// The number of characters of the option that are not matched, or `u16::MAX` when the input is not a subsequence of it.
+    fn distance(&self, a: &str, b: &str) -> u16 {
+        let a = chars(a);
+        let b = chars(b);
+        unmatched(&a, &b)
+    }
+
+    fn similarity_with(&self, a: &str, b: &str, granularity: Granularity) -> f64 {
+        self.similarity_slices(&granularity.tokens(a), &granularity.tokens(b))
+    }
+
+    fn distance_with(&self, a: &str, b: &str, granularity: Granularity) -> u16 {
+        unmatched(&granularity.tokens(a), &granularity.tokens(b))
+    }
+}
+
+/// A score in the DP, and where the unit before it was matched.
+#[derive(Debug, Clone, Copy)]
+struct Cell {
+    score: f64,
+    from: usize,
+}
+
+impl Cell {
+    fn add(self, score: f64) -> Cell {
+        Cell {
+            score: self.score + score,
+            ..self
+        }
+    }
+}
+
+/// The cell with the better score, and the first one if they are equal.
+fn better(a: Option<Cell>, b: Option<Cell>) -> Option<Cell> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(if b.score > a.score { b } else { a }),
+        (a, b) => a.or(b),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Upper,
+    Lower,
+    Digit,
+    /// A letter without a case, like in Chinese or Arabic.
+    Letter,
+    /// Spaces and punctuation, anything that is not part of a word.
+    Separator,
+}
+
+impl Kind {
+    fn of(c: char) -> Kind {
+        if c.is_uppercase() {
+            Kind::Upper
+        } else if c.is_lowercase() {
+            Kind::Lower
+        } else if c.is_numeric() {
+            Kind::Digit
+        } else if c.is_alphanumeric() {
+            Kind::Letter
+        } else {
+            Kind::Separator
+        }
+    }
+}
+
+/// A unit that can be matched without its case, and can start a word or a camelCase hump.
+pub(crate) trait Unit {
+    fn matches(&self, other: &Self) -> bool;
+    fn kind(&self) -> Kind;
+}
+
+impl Unit for char {
+    fn matches(&self, other: &Self) -> bool {
+        self == other || self.to_lowercase().eq(other.to_lowercase())
+    }
+
+    fn kind(&self) -> Kind {
+        Kind::of(*self)
+    }
+}
+
+impl Unit for &[u8] {
+    fn matches(&self, other: &Self) -> bool {
+        match (std::str::from_utf8(self), std::str::from_utf8(other)) {
+            (Ok(a), Ok(b)) => a == b || a.to_lowercase() == b.to_lowercase(),
+            _ => self == other,
+        }
+    }
+
+    fn kind(&self) -> Kind {
+        match std::str::from_utf8(self) {
+            Ok(unit) => unit
+                .chars()
+                .map(Kind::of)
+                .find(|kind| *kind != Kind::Separator)
+                .unwrap_or(Kind::Separator),
+            Err(_) => Kind::Letter,
+        }
+    }
+}
+
+/// The number of units of the candidate that are not matched, or `u16::MAX` when the input is not a subsequence of it.
+/// This is the distance used by a [Threshold::MaxDistance](../../enum.Threshold.html) for this algorithm.
+pub(crate) fn unmatched<T: Unit>(input: &[T], candidate: &[T]) -> u16 {
+    let mut rest = candidate.iter();
+    let is_subsequence = input
+        .iter()
+        .all(|unit| rest.any(|candidate| unit.matches(candidate)));
+    if is_subsequence {
+        (candidate.len() - input.len()).min(u16::MAX as usize - 1) as u16
+    } else {
+        u16::MAX
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn best_match_is_used() {
+        let similarity = |a: &str, b: &str| subsequence_similarity(a.to_string(), b.to_string());
+        // The first "ab" is in one piece, but the later one is at the start of two words
+        assert!(close(similarity("ab", "xab a_b"), 1.95 / 2.0));
+        assert!(close(similarity("ab", "xab"), 1.5 / 2.0));
+        assert_eq!(similarity("gcm", "gcm"), 1.0);
+        assert_eq!(similarity("", ""), 1.0);
+        assert_eq!(similarity("", "abc"), 0.0);
+        assert_eq!(similarity("abc", "ab"), 0.0);
+
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(unmatched(&chars("gcm"), &chars("git commit")), 7);
+        assert_eq!(unmatched(&chars("mcg"), &chars("git commit")), u16::MAX);
+    }
+
+    #[test]
+    fn bonuses_and_positions() {
+        let scorer = SubsequenceScorer::default();
+        let positions = |a: &str, b: &str| scorer.find(a, b).unwrap().positions;
+        assert_eq!(positions("ab", "xab a_b"), vec![4, 6]);
+        assert_eq!(positions("hs", "HTTPServer"), vec![0, 4]);
+        assert_eq!(positions("ß", "Straße"), vec![4]);
+
+        // Without the camel bonus, the "U" of "getUser" is just another letter
+        assert!(close(scorer.similarity("gu", "getUser"), 1.94 / 2.0));
+        let flat = scorer.camel_bonus(0.0);
+        assert!(close(flat.similarity("gu", "getUser"), 1.44 / 2.0));
+
+        // Without bonuses for words, only a consecutive run scores 1
+        let runs = scorer.boundary_bonus(0.0).camel_bonus(0.0);
+        assert!(close(runs.similarity("abc", "xabc"), 2.5 / 3.0));
+        // The boundary bonus is the best a character can do here, so the similarity is normalized by it
+        let words = scorer.consecutive_bonus(0.0).boundary_bonus(1.0);
+        assert!(close(words.similarity("ab", "a b"), 2.95 / 3.0));
+
+        let leading = scorer.leading_gap_penalty(0.1);
+        assert!(close(leading.similarity("b", "a b"), 0.8));
+        let gaps = scorer.gap_penalty(0.2, 0.1);
+        assert!(close(gaps.similarity("ac", "abbc"), 1.2 / 2.0));
+    }
+}
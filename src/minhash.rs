@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+
+use crate::metrics::jaccard::Shingles;
+use crate::{CorrectWord, Corrector, Scorer};
+
+/// # Struct: MinHasher
+/// Turns strings into [MinHash](https://en.wikipedia.org/wiki/MinHash) signatures,
+/// short lists of numbers whose agreement estimates the [Jaccard similarity](../metrics/jaccard/fn.jaccard_similarity.html) of their shingles.
+///
+/// Every one of the `hashes` hash functions keeps the smallest hash of the shingles of a string,
+/// and two strings get the same smallest hash with a probability equal to their Jaccard similarity.
+/// More hashes give a closer estimate, at the cost of longer signatures: the error is about `1 / sqrt(hashes)`.
+///
+/// The hash functions only depend on their number, so signatures can be saved and compared across runs and machines.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::jaccard::{jaccard_similarity, Shingles};
+/// use correct_word::minhash::MinHasher;
+///
+/// let hasher = MinHasher::new(256);
+/// let a = hasher.signature("the quick brown fox jumps");
+/// let b = hasher.signature("the quick brown fox jumped");
+/// let exact = jaccard_similarity("the quick brown fox jumps".to_string(), "the quick brown fox jumped".to_string());
+/// assert!((a.similarity(&b) - exact).abs() < 0.1);
+///
+/// let words = MinHasher::new(128).shingles(Shingles::Words(1));
+/// assert_eq!(words.signature("brown fox").similarity(&words.signature("fox brown")), 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinHasher {
+    shingles: Shingles,
+    seeds: Vec<u64>,
+}
+
+/// # Struct: Signature
+/// The MinHash signature of a string, made by a [MinHasher](struct.MinHasher.html).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Signature(Vec<u64>);
+
+impl MinHasher {
+    /// Creates a hasher with `hashes` hash functions over character bigrams. A `hashes` of 0 is 1.
+    pub fn new(hashes: usize) -> Self {
+        let mut state = 0;
+        let seeds = (0..hashes.max(1))
+            .map(|_| {
+                state = mix(state);
+                state
+            })
+            .collect();
+        MinHasher {
+            shingles: Shingles::default(),
+            seeds,
+        }
+    }
+
+    /// Sets the shingles the strings are cut into, character bigrams by default.
+    pub fn shingles(mut self, shingles: Shingles) -> Self {
+        self.shingles = shingles;
+        self
+    }
+
+    /// The number of hash functions, which is the length of every signature.
+    pub fn hashes(&self) -> usize {
+        self.seeds.len()
+    }
+
+    /// The signature of the string.
+    /// A string without shingles, like an empty one, only matches other strings without shingles.
+    pub fn signature(&self, string: &str) -> Signature {
+        let mut minimums = vec![u64::MAX; self.seeds.len()];
+        for shingle in self.shingles.set(string) {
+            let hash = fnv(shingle.as_bytes());
+            for (minimum, seed) in minimums.iter_mut().zip(&self.seeds) {
+                *minimum = (*minimum).min(mix(hash ^ seed));
+            }
+        }
+        Signature(minimums)
+    }
+}
+
+impl Signature {
+    /// Estimates the Jaccard similarity of the two strings, from 0 to 1, as the share of the hashes they agree on.
+    /// Signatures of different lengths are compared on the hashes they both have.
+    pub fn similarity(&self, other: &Signature) -> f64 {
+        let hashes = self.0.len().min(other.0.len());
+        if hashes == 0 {
+            return 1.0;
+        }
+        let equal = self.0.iter().zip(&other.0).filter(|(a, b)| a == b).count();
+        equal as f64 / hashes as f64
+    }
+
+    /// The minimum hashes, one per hash function of the hasher.
+    pub fn as_slice(&self) -> &[u64] {
+        &self.0
+    }
+}
+
+/// # Struct: MinHashIndex
+/// An index that finds the near-duplicates of a string among very many words
+/// with [locality-sensitive hashing](https://en.wikipedia.org/wiki/Locality-sensitive_hashing) of their MinHash signatures,
+/// without comparing the input to every word.
+///
+/// The signatures are split into `bands` bands of `hashes / bands` rows, and two strings are candidates
+/// when all the rows of at least one band are equal.
+/// A pair with a Jaccard similarity of `s` becomes candidates with a probability of `1 - (1 - s^rows)^bands`,
+/// so more bands find less similar pairs, and more rows keep out the pairs that are not similar.
+///
+/// The search is approximate: it may miss a similar word, and it only finds words that share shingles with the input,
+/// so unlike a [TrieDictionary](../struct.TrieDictionary.html) it doesn't promise every word within a Levenshtein distance.
+///
+/// # Example
+/// ```
+/// use correct_word::metrics::jaccard::JaccardScorer;
+/// use correct_word::minhash::{MinHashIndex, MinHasher};
+/// use correct_word::Corrector;
+///
+/// let mut index = MinHashIndex::new(MinHasher::new(64), 16);
+/// index.extend(["221B Baker Street, London", "10 Downing Street, London", "4 Privet Drive, Little Whinging"]);
+/// assert_eq!(index.search("221b Baker Street London"), vec!["221B Baker Street, London"]);
+///
+/// let corrector = Corrector::new(JaccardScorer::default());
+/// let result = index.correct(&corrector, "10 Downing St, London");
+/// assert_eq!(result.word.as_deref(), Some("10 Downing Street, London"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MinHashIndex {
+    hasher: MinHasher,
+    rows: usize,
+    words: Vec<String>,
+    /// For every band, the words with each hash of the rows of the band.
+    buckets: Vec<HashMap<u64, Vec<usize>>>,
+}
+
+impl MinHashIndex {
+    /// Creates an empty index that splits the signatures of the hasher into `bands` bands.
+    /// The number of bands is at least 1 and at most the number of hashes,
+    /// and the hashes that don't fill a band are not used, so `bands` should divide it.
+    pub fn new(hasher: MinHasher, bands: usize) -> Self {
+        let bands = bands.clamp(1, hasher.hashes());
+        MinHashIndex {
+            rows: hasher.hashes() / bands,
+            hasher,
+            words: Vec::new(),
+            buckets: vec![HashMap::new(); bands],
+        }
+    }
+
+    /// Adds a word to the index. A word added twice is kept twice.
+    pub fn insert(&mut self, word: impl Into<String>) {
+        let word = word.into();
+        let index = self.words.len();
+        let signature = self.hasher.signature(&word);
+        for (band, buckets) in self.bands(&signature).zip(&mut self.buckets) {
+            buckets.entry(band).or_default().push(index);
+        }
+        self.words.push(word);
+    }
+
+    /// The words of the index, in the order they were added.
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// The number of words in the index.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Whether the index has no words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// The words that share a band with the input, which are likely to be similar to it, in the order they were added.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The string to look up.
+    ///
+    /// # Returns
+    ///
+    /// `Vec<&str>` - The candidates, every one of them once.
+    pub fn search(&self, input: &str) -> Vec<&str> {
+        let signature = self.hasher.signature(input);
+        let mut indices: Vec<usize> = self
+            .bands(&signature)
+            .zip(&self.buckets)
+            .filter_map(|(band, buckets)| buckets.get(&band))
+            .flatten()
+            .copied()
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+            .into_iter()
+            .map(|index| self.words[index].as_str())
+            .collect()
+    }
+
+    /// Returns up to `n` corrections of the input from the candidates of [search](#method.search), best first,
+    /// like [Dictionary::suggest](../struct.Dictionary.html#method.suggest).
+    pub fn suggest<S: Scorer>(
+        &self,
+        corrector: &Corrector<S>,
+        input: &str,
+        n: usize,
+    ) -> Vec<CorrectWord> {
+        corrector.correct_n(input, &self.search(input), n)
+    }
+
+    /// Corrects the input from the candidates of [search](#method.search), like [Corrector::correct](../struct.Corrector.html#method.correct).
+    pub fn correct<S: Scorer>(&self, corrector: &Corrector<S>, input: &str) -> CorrectWord {
+        corrector.correct(input, &self.search(input))
+    }
+
+    /// The hash of the rows of every band of the signature.
+    fn bands<'a>(&self, signature: &'a Signature) -> impl Iterator<Item = u64> + 'a {
+        signature.0.chunks_exact(self.rows).map(|rows| {
+            let bytes: Vec<u8> = rows.iter().flat_map(|row| row.to_le_bytes()).collect();
+            fnv(&bytes)
+        })
+    }
+}
+
+impl<W: Into<String>> Extend<W> for MinHashIndex {
+    fn extend<I: IntoIterator<Item = W>>(&mut self, words: I) {
+        for word in words {
+            self.insert(word);
+        }
+    }
+}
+
+/// The 64-bit FNV-1a hash of the bytes, which is the same everywhere, unlike the hasher of a `HashMap`.
+fn fnv(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// The finalizer of SplitMix64, which scatters the bits of a hash.
+fn mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,197 @@
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use memmap2::Mmap;
+
+use crate::{instrument, CorrectWord, Corrector, Scorer};
+
+/// # Struct: MmapDictionary
+/// A dictionary that reads its words straight from a memory-mapped file, instead of loading them onto the heap.
+/// Opening a word list of hundreds of megabytes is then about as fast as opening the file,
+/// and the operating system only reads the pages that are looked at.
+///
+/// The file has one word per line, in UTF-8, sorted by their bytes like `LC_ALL=C sort` does, without empty lines.
+/// A trailing newline and `\r\n` line endings are fine. [open](#method.open) checks all of this once,
+/// and then [contains](#method.contains) is a binary search.
+///
+/// As a [CandidateSource](trait.CandidateSource.html), only the words whose length is close enough to the input
+/// to be within the distance are candidates, so a [Corrector](struct.Corrector.html) scores fewer of them.
+/// The words come in the order of the file, so the first of them wins a tie.
+///
+/// The file must not be changed while it is open: the words would change under the dictionary,
+/// which is undefined behavior for a memory map.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector, MmapDictionary};
+///
+/// let path = std::env::temp_dir().join("correct_word_mmap_example.txt");
+/// std::fs::write(&path, "hello\nhelp\nworld\n").unwrap();
+///
+/// let dictionary = MmapDictionary::open(&path).unwrap();
+/// assert_eq!(dictionary.len(), 3);
+/// assert!(dictionary.contains("help"));
+///
+/// let result = Corrector::new(Algorithm::Levenshtein).correct_from("wrld", &dictionary);
+/// assert_eq!(result.word.as_deref(), Some("world"));
+/// # std::fs::remove_file(path).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct MmapDictionary {
+    map: Mmap,
+    len: usize,
+}
+
+impl MmapDictionary {
+    /// Maps a sorted word list, with one word per line.
+    ///
+    /// # Errors
+    ///
+    /// Fails with `InvalidData` if the file is not UTF-8, has an empty line, or is not sorted.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(path = %path.as_ref().display()))
+    )]
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: The file is only read, and the caller is told not to change it while it is mapped
+        let map = unsafe { Mmap::map(&file)? };
+        let text = std::str::from_utf8(&map)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        let mut len = 0;
+        let mut previous: Option<&str> = None;
+        for word in lines(text) {
+            if word.is_empty() {
+                return Err(invalid(format!("empty line after {} words", len)));
+            }
+            if previous.is_some_and(|previous| previous > word) {
+                return Err(invalid(format!("{:?} is not in sorted order", word)));
+            }
+            previous = Some(word);
+            len += 1;
+        }
+        instrument::debug!(words = len, bytes = map.len(), "mapped a dictionary");
+        Ok(MmapDictionary { map, len })
+    }
+
+    /// The words of the dictionary, in the order of the file.
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        lines(self.text())
+    }
+
+    /// The number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the dictionary has no words.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether the dictionary has exactly this word, with a binary search over the lines of the file.
+    pub fn contains(&self, word: &str) -> bool {
+        let text = self.text();
+        let bytes = text.as_bytes();
+        // The lines that are left start at `low`, and end right before `high`
+        let (mut low, mut high) = (0, bytes.len());
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let start = bytes[low..middle]
+                .iter()
+                .rposition(|&byte| byte == b'\n')
+                .map_or(low, |newline| low + newline + 1);
+            let end = bytes[start..high]
+                .iter()
+                .position(|&byte| byte == b'\n')
+                .map_or(high, |newline| start + newline);
+            let line = &text[start..end];
+            match line.strip_suffix('\r').unwrap_or(line).cmp(word) {
+                std::cmp::Ordering::Equal => return true,
+                std::cmp::Ordering::Less => low = end + 1,
+                std::cmp::Ordering::Greater => high = start,
+            }
+        }
+        false
+    }
+
+    /// Returns up to `n` corrections of the input from the words of the dictionary, best first,
+    /// the same as [Corrector::correct_n_from](struct.Corrector.html#method.correct_n_from).
+    pub fn suggest<S: Scorer>(
+        &self,
+        corrector: &Corrector<S>,
+        input: &str,
+        n: usize,
+    ) -> Vec<CorrectWord> {
+        corrector.correct_n_from(input, self, n)
+    }
+
+    fn text(&self) -> &str {
+        // SAFETY: `open` checked that the whole mapping is UTF-8, and it doesn't change
+        unsafe { std::str::from_utf8_unchecked(&self.map) }
+    }
+}
+
+fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.split_terminator('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open(name: &str, contents: &str) -> io::Result<MmapDictionary> {
+        let path = std::env::temp_dir().join(format!("correct_word_mmap_{name}.txt"));
+        std::fs::write(&path, contents).unwrap();
+        let dictionary = MmapDictionary::open(&path);
+        std::fs::remove_file(path).unwrap();
+        dictionary
+    }
+
+    #[test]
+    fn binary_search_finds_every_word() {
+        let words = [
+            "a",
+            "ab",
+            "abc",
+            "b",
+            "ba",
+            "caf\u{e9}",
+            "z",
+            "\u{e9}t\u{e9}",
+        ];
+        for ending in ["\n", "\r\n"] {
+            for trailing in [true, false] {
+                let mut contents = words.join(ending);
+                if trailing {
+                    contents.push_str(ending);
+                }
+                let dictionary = open("search", &contents).unwrap();
+                assert_eq!(dictionary.len(), words.len());
+                assert!(dictionary.words().eq(words));
+                for word in words {
+                    assert!(dictionary.contains(word), "{word:?}");
+                }
+                for word in ["", "aa", "abcd", "c", "cafe", "zz", "\u{e9}"] {
+                    assert!(!dictionary.contains(word), "{word:?}");
+                }
+            }
+        }
+
+        let empty = open("empty", "").unwrap();
+        assert!(empty.is_empty());
+        assert!(!empty.contains("a"));
+
+        for contents in ["b\na\n", "a\n\nb\n"] {
+            let error = open("invalid", contents).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+}
@@ -0,0 +1,358 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+/// # Struct: Normalizer
+/// A pipeline of steps that clean up strings before they are compared, like trimming them or lowercasing them.
+///
+/// Attached to a [Corrector](struct.Corrector.html) with [normalizer](struct.Corrector.html#method.normalizer),
+/// it is applied the same way to the input and to every option before they are scored,
+/// so the dictionary doesn't need a normalized copy, and the corrections are still the options as they were written.
+///
+/// The steps run in the order they were added:
+/// * `trim`: Removes the whitespace at both ends.
+/// * `lowercase`: Lowercases every letter, so the case doesn't count as an edit.
+/// * `compose_latin_accents`: Composes every Latin letter followed by a combining accent, like "e" and U+0301,
+///   into the accented letter, "é", so a decomposed accent is one character, like the precomposed one it stands for.
+///   It only covers the letters of Latin-1 and Latin Extended-A: it isn't NFC, and the other scripts are left as they are.
+/// * `nfc`: Puts the text in [Normalization Form C](https://unicode.org/reports/tr15/), composing every script,
+///   Hangul jamo included, and putting stacked accents in their canonical order. Needs the `unicode-normalization` feature.
+/// * `strip_accents`: Removes the accents of the same letters `compose_latin_accents` composes, precomposed or followed by combining accents,
+///   and every other combining accent: "Jose\u{301}" and "José" are both "Jose".
+/// * `strip_punctuation`: Removes ASCII punctuation and the general punctuation of Unicode, like dashes, quotes and ellipses.
+/// * `collapse_whitespace`: Turns every run of whitespace into a single space.
+/// * `map`: Any other step, as a closure.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector, Normalizer};
+///
+/// let normalizer = Normalizer::new().trim().lowercase().strip_punctuation();
+/// assert_eq!(normalizer.normalize("  Hello, World! "), "hello world");
+///
+/// let corrector = Corrector::new(Algorithm::Levenshtein).normalizer(normalizer);
+/// let result = corrector.correct("NEW-YORK", &["New York", "Newark"]);
+/// assert_eq!(result.word.as_deref(), Some("New York"));
+/// assert_eq!(result.confidence, 0.875);
+/// ```
+#[derive(Clone, Default)]
+pub struct Normalizer {
+    steps: Vec<Step>,
+}
+
+#[derive(Clone)]
+enum Step {
+    Trim,
+    Lowercase,
+    ComposeLatinAccents,
+    #[cfg(feature = "unicode-normalization")]
+    Nfc,
+    StripAccents,
+    StripPunctuation,
+    CollapseWhitespace,
+    Map(Arc<dyn Fn(&str) -> String + Send + Sync>),
+}
+
+impl Normalizer {
+    /// Creates a normalizer without steps, which leaves strings as they are.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a step that removes the whitespace at both ends.
+    pub fn trim(self) -> Self {
+        self.step(Step::Trim)
+    }
+
+    /// Adds a step that lowercases every letter.
+    pub fn lowercase(self) -> Self {
+        self.step(Step::Lowercase)
+    }
+
+    /// Adds a step that composes Latin letters followed by combining accents into accented letters.
+    pub fn compose_latin_accents(self) -> Self {
+        self.step(Step::ComposeLatinAccents)
+    }
+
+    /// Adds a step that puts the text in Normalization Form C.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::Normalizer;
+    ///
+    /// let normalizer = Normalizer::new().nfc();
+    /// assert_eq!(normalizer.normalize("\u{1100}\u{1161}"), "\u{ac00}");
+    /// ```
+    #[cfg(feature = "unicode-normalization")]
+    pub fn nfc(self) -> Self {
+        self.step(Step::Nfc)
+    }
+
+    /// Adds a step that removes the accents of Latin letters.
+    pub fn strip_accents(self) -> Self {
+        self.step(Step::StripAccents)
+    }
+
+    /// Adds a step that removes punctuation.
+    pub fn strip_punctuation(self) -> Self {
+        self.step(Step::StripPunctuation)
+    }
+
+    /// Adds a step that turns every run of whitespace into a single space.
+    pub fn collapse_whitespace(self) -> Self {
+        self.step(Step::CollapseWhitespace)
+    }
+
+    /// Adds a step that runs the closure, like one that expands abbreviations or removes a prefix.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::Normalizer;
+    ///
+    /// let normalizer = Normalizer::new().map(|text: &str| text.replace("St.", "Street"));
+    /// assert_eq!(normalizer.normalize("Baker St."), "Baker Street");
+    /// ```
+    pub fn map(self, step: impl Fn(&str) -> String + Send + Sync + 'static) -> Self {
+        self.step(Step::Map(Arc::new(step)))
+    }
+
+    /// Whether the normalizer has no steps, so it leaves strings as they are.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Runs the steps on the text, in the order they were added. Without steps, the text is borrowed as it is.
+    pub fn normalize<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut text = Cow::Borrowed(text);
+        for step in &self.steps {
+            text = match step {
+                Step::Trim => match text {
+                    Cow::Borrowed(text) => Cow::Borrowed(text.trim()),
+                    Cow::Owned(text) => Cow::Owned(text.trim().to_string()),
+                },
+                Step::Lowercase => Cow::Owned(text.to_lowercase()),
+                Step::ComposeLatinAccents => Cow::Owned(compose(&text)),
+                #[cfg(feature = "unicode-normalization")]
+                Step::Nfc => {
+                    use unicode_normalization::UnicodeNormalization;
+                    Cow::Owned(text.nfc().collect())
+                }
+                Step::StripAccents => match text {
+                    Cow::Borrowed(text) => strip_accents(text),
+                    Cow::Owned(text) => Cow::Owned(strip_accents(&text).into_owned()),
+                },
+                Step::StripPunctuation => {
+                    Cow::Owned(text.chars().filter(|&c| !is_punctuation(c)).collect())
+                }
+                Step::CollapseWhitespace => {
+                    Cow::Owned(text.split_whitespace().collect::<Vec<_>>().join(" "))
+                }
+                Step::Map(step) => Cow::Owned(step(&text)),
+            };
+        }
+        text
+    }
+
+    fn step(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        self
+    }
+}
+
+impl fmt::Debug for Normalizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let steps: Vec<&str> = self
+            .steps
+            .iter()
+            .map(|step| match step {
+                Step::Trim => "trim",
+                Step::Lowercase => "lowercase",
+                Step::ComposeLatinAccents => "compose_latin_accents",
+                #[cfg(feature = "unicode-normalization")]
+                Step::Nfc => "nfc",
+                Step::StripAccents => "strip_accents",
+                Step::StripPunctuation => "strip_punctuation",
+                Step::CollapseWhitespace => "collapse_whitespace",
+                Step::Map(_) => "map",
+            })
+            .collect();
+        f.debug_struct("Normalizer").field("steps", &steps).finish()
+    }
+}
+
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation()
+        || matches!(c, '¡' | '§' | '«' | '¶' | '·' | '»' | '¿')
+        || ('\u{2010}'..='\u{2027}').contains(&c)
+        || ('\u{2030}'..='\u{205e}').contains(&c)
+        || ('\u{3001}'..='\u{3003}').contains(&c)
+        || ('\u{3008}'..='\u{3011}').contains(&c)
+}
+
+/// Replaces every letter followed by a combining accent with the letter that has the accent, if there is one.
+fn compose(text: &str) -> String {
+    let mut composed = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        // The accented letters don't take another accent in the table, so only the first of several is composed
+        match chars.peek().and_then(|&mark| composition(c, mark)) {
+            Some(letter) => {
+                composed.push(letter);
+                chars.next();
+            }
+            None => composed.push(c),
+        }
+    }
+    composed
+}
+
+/// Removes every combining accent, and replaces every accented letter of the table with the letter without its accent.
+pub(crate) fn strip_accents(text: &str) -> Cow<'_, str> {
+    if text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(
+        text.chars()
+            .filter(|c| !('\u{300}'..='\u{36f}').contains(c))
+            .map(|c| decomposition(c).unwrap_or(c))
+            .collect(),
+    )
+}
+
+/// Writes the word in the case of the input, when the input is all uppercase, like "HELO", or capitalized, like "Helo".
+/// Otherwise, like for "helo" or "iPhone", the word is left as it is, since the case of an option is usually on purpose.
+pub(crate) fn match_case<'a>(input: &str, word: &'a str) -> Cow<'a, str> {
+    let mut letters = input
+        .chars()
+        .filter(|c| c.is_lowercase() || c.is_uppercase());
+    let Some(first) = letters.next() else {
+        return Cow::Borrowed(word);
+    };
+    let rest: Vec<char> = letters.collect();
+    if first.is_uppercase() && !rest.is_empty() && rest.iter().all(|c| c.is_uppercase()) {
+        Cow::Owned(word.to_uppercase())
+    } else if first.is_uppercase() && rest.iter().all(|c| c.is_lowercase()) {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(c) if c.is_lowercase() => Cow::Owned(c.to_uppercase().chain(chars).collect()),
+            _ => Cow::Borrowed(word),
+        }
+    } else {
+        Cow::Borrowed(word)
+    }
+}
+
+fn decomposition(composed: char) -> Option<char> {
+    COMPOSITIONS
+        .iter()
+        .find_map(|(_, letters, composed_letters)| {
+            let position = composed_letters
+                .chars()
+                .position(|known| known == composed)?;
+            letters.chars().nth(position)
+        })
+}
+
+fn composition(letter: char, mark: char) -> Option<char> {
+    let (_, letters, composed) = COMPOSITIONS.iter().find(|(known, ..)| *known == mark)?;
+    let position = letters.chars().position(|known| known == letter)?;
+    composed.chars().nth(position)
+}
+
+/// For every combining accent, the letters that take it, and the same letters with the accent, in the same order.
+const COMPOSITIONS: [(char, &str, &str); 13] = [
+    ('\u{300}', "AEIOUaeiou", "ÀÈÌÒÙàèìòù"),
+    (
+        '\u{301}',
+        "ACEILNORSUYZaceilnorsuyz",
+        "ÁĆÉÍĹŃÓŔŚÚÝŹáćéíĺńóŕśúýź",
+    ),
+    (
+        '\u{302}',
+        "ACEGHIJOSUWYaceghijosuwy",
+        "ÂĈÊĜĤÎĴÔŜÛŴŶâĉêĝĥîĵôŝûŵŷ",
+    ),
+    ('\u{303}', "AINOUainou", "ÃĨÑÕŨãĩñõũ"),
+    ('\u{304}', "AEIOUaeiou", "ĀĒĪŌŪāēīōū"),
+    ('\u{306}', "AEGIOUaegiou", "ĂĔĞĬŎŬăĕğĭŏŭ"),
+    ('\u{307}', "CEGIZcegz", "ĊĖĠİŻċėġż"),
+    ('\u{308}', "AEIOUYaeiouy", "ÄËÏÖÜŸäëïöüÿ"),
+    ('\u{30a}', "AUau", "ÅŮåů"),
+    ('\u{30b}', "OUou", "ŐŰőű"),
+    ('\u{30c}', "CDELNRSTZcdelnrstz", "ČĎĚĽŇŘŠŤŽčďěľňřšťž"),
+    ('\u{327}', "CGKLNRSTcgklnrst", "ÇĢĶĻŅŖŞŢçģķļņŗşţ"),
+    ('\u{328}', "AEIUaeiu", "ĄĘĮŲąęįų"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accents_are_composed_and_stripped() {
+        let compose = Normalizer::new().compose_latin_accents();
+        assert_eq!(compose.normalize("cafe\u{301}"), "café");
+        assert_eq!(compose.normalize("C\u{327}a\u{300} Z\u{30c}"), "Çà Ž");
+        // Only the first of two accents has a composed letter here
+        assert_eq!(compose.normalize("u\u{308}\u{304}"), "ü\u{304}");
+        // An accent without a letter, or on a letter without a composed form, is left alone
+        assert_eq!(compose.normalize("\u{301}x\u{301}"), "\u{301}x\u{301}");
+        assert_eq!(compose.normalize("é"), "é");
+        // Other scripts aren't composed, unlike NFC would: a Greek alpha with an acute, and Hangul jamo
+        assert_eq!(compose.normalize("\u{3b1}\u{301}"), "\u{3b1}\u{301}");
+        assert_eq!(compose.normalize("\u{1100}\u{1161}"), "\u{1100}\u{1161}");
+        assert_eq!(
+            compose.normalize("e\u{301}\u{3b1}\u{301}"),
+            "é\u{3b1}\u{301}"
+        );
+
+        let strip = Normalizer::new().strip_accents();
+        assert_eq!(strip.normalize("Jose\u{301} Ňoño"), "Jose Nono");
+        assert!(matches!(strip.normalize("Jose"), Cow::Borrowed("Jose")));
+
+        let cleanup = Normalizer::new().strip_punctuation().collapse_whitespace();
+        assert_eq!(cleanup.normalize("«Hello» — said   she…"), "Hello said she");
+        assert!(matches!(
+            Normalizer::new().trim().normalize(" a "),
+            Cow::Borrowed("a")
+        ));
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn nfc_composes_every_script() {
+        let nfc = Normalizer::new().nfc();
+        // Hangul jamo make one syllable, and a Greek alpha takes its acute
+        assert_eq!(nfc.normalize("\u{1112}\u{1161}\u{11ab}"), "\u{d55c}");
+        assert_eq!(nfc.normalize("\u{3b1}\u{301}"), "\u{3ac}");
+        // Vietnamese stacks a tone mark on a vowel that already has a mark: "ệ" and "ở"
+        assert_eq!(nfc.normalize("e\u{323}\u{302}"), "\u{1ec7}");
+        assert_eq!(nfc.normalize("o\u{31b}\u{309}"), "\u{1edf}");
+        // The marks are put in their canonical order first, so either order gives the same letter
+        assert_eq!(nfc.normalize("e\u{302}\u{323}"), "\u{1ec7}");
+        assert_eq!(nfc.normalize("q\u{307}\u{323}"), "q\u{323}\u{307}");
+        // And compose_latin_accents still only composes the first accent
+        assert_eq!(
+            Normalizer::new()
+                .compose_latin_accents()
+                .normalize("e\u{323}\u{302}"),
+            "e\u{323}\u{302}"
+        );
+        assert_eq!(format!("{nfc:?}"), r#"Normalizer { steps: ["nfc"] }"#);
+    }
+
+    #[test]
+    fn case_is_matched() {
+        assert_eq!(match_case("HELO", "hello"), "HELLO");
+        assert_eq!(match_case("Helo", "hello"), "Hello");
+        assert_eq!(match_case("Éte", "été"), "Été");
+        assert_eq!(match_case("A", "an"), "An");
+        // Lowercase and mixed case inputs keep the case of the word
+        assert_eq!(match_case("paris", "Paris"), "Paris");
+        assert_eq!(match_case("iPhon", "iphone"), "iphone");
+        assert_eq!(match_case("HeLo", "hello"), "hello");
+        assert_eq!(match_case("123", "one"), "one");
+        // Non-letters don't count
+        assert_eq!(match_case("O'NEIL", "o'neill"), "O'NEILL");
+    }
+}
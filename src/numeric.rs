@@ -0,0 +1,135 @@
+use crate::metrics::damerau::{osa_cost, EditCosts};
+use crate::Scorer;
+
+/// # Struct: NumericScorer
+/// A [Scorer](../trait.Scorer.html) for versioned identifiers and file names, where numbers can differ legitimately.
+///
+/// It is the Levenshtein similarity, where every run of ASCII digits is a single unit, compared as a number:
+/// * Two numbers with the same value are equal, so "file09" and "file9" are the same file.
+/// * Replacing a number with another one costs the [number weight](#method.number_weight), 0.5 by default,
+///   however many digits they have.
+/// * Inserting or deleting a number, or replacing it with a letter, costs 1, like any other unit.
+///
+/// So "file10" is half an edit away from "file9", while plain Levenshtein sees two edits, like for "filexq".
+/// The similarity is 1 minus the cost over the number of units of the longer string.
+///
+/// # Example
+/// ```
+/// use correct_word::numeric::NumericScorer;
+/// use correct_word::{Corrector, Scorer};
+///
+/// let scorer = NumericScorer::new();
+/// assert_eq!(scorer.similarity("file10", "file9"), 0.9);
+/// assert!(scorer.similarity("file10", "filexq") < 0.7);
+/// assert_eq!(scorer.similarity("file09", "file9"), 1.0);
+///
+/// let corrector = Corrector::new(scorer);
+/// assert_eq!(corrector.correct("report_v12.pdf", &["report_vi.pdf", "report_v3.pdf"]).word.unwrap(), "report_v3.pdf");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericScorer {
+    number_weight: f64,
+}
+
+impl Default for NumericScorer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumericScorer {
+    /// Creates a numeric scorer where replacing a number costs half an edit.
+    pub fn new() -> Self {
+        NumericScorer { number_weight: 0.5 }
+    }
+
+    /// Sets the cost of replacing a number with a different one, from 0, where all numbers are equal, to 1.
+    /// A weight outside of that range is clamped to it, and a NaN is the default of 0.5.
+    pub fn number_weight(mut self, weight: f64) -> Self {
+        self.number_weight = if weight.is_nan() {
+            0.5
+        } else {
+            weight.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    fn cost(&self, a: &str, b: &str) -> (f64, usize) {
+        let units1 = units(a);
+        let units2 = units(b);
+        let cost = osa_cost(&units1, &units2, self);
+        (cost, units1.len().max(units2.len()))
+    }
+}
+
+impl Scorer for NumericScorer {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        match self.cost(a, b) {
+            (_, 0) => 1.0,
+            (cost, length) => 1.0 - cost / length as f64,
+        }
+    }
+
+    /// The cost of the edits, rounded up, so a different number is one edit.
+    fn distance(&self, a: &str, b: &str) -> u16 {
+        let (cost, _) = self.cost(a, b);
+        cost.ceil().min(u16::MAX as f64) as u16
+    }
+}
+
+impl EditCosts<Unit<'_>> for NumericScorer {
+    fn substitution(&self, a: &Unit, b: &Unit) -> f64 {
+        match (a, b) {
+            (Unit::Number(_), Unit::Number(_)) => self.number_weight,
+            _ => 1.0,
+        }
+    }
+
+    /// A swap is two substitutions, like in the Levenshtein distance.
+    fn transposition(&self, _: &Unit, _: &Unit) -> f64 {
+        f64::INFINITY
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Unit<'a> {
+    Char(char),
+    /// The digits of a number, without its leading zeros.
+    Number(&'a str),
+}
+
+fn units(string: &str) -> Vec<Unit<'_>> {
+    let mut units = Vec::new();
+    let mut rest = string;
+    while let Some(c) = rest.chars().next() {
+        if c.is_ascii_digit() {
+            let length = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            units.push(Unit::Number(rest[..length].trim_start_matches('0')));
+            rest = &rest[length..];
+        } else {
+            units.push(Unit::Char(c));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_weight_is_clamped() {
+        let similarity = |weight: f64| {
+            NumericScorer::new()
+                .number_weight(weight)
+                .similarity("1", "2")
+        };
+        assert_eq!(similarity(1.5), 0.0);
+        assert_eq!(similarity(-0.5), 1.0);
+        assert_eq!(similarity(0.25), 0.75);
+        assert_eq!(similarity(f64::NAN), 0.5);
+    }
+}
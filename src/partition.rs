@@ -0,0 +1,39 @@
+/// # Enum: Partition
+/// How a [Dictionary](struct.Dictionary.html) splits its words into buckets, so a lookup only scores the buckets
+/// that can hold a correction. It is set with [Dictionary::partition](struct.Dictionary.html#method.partition).
+///
+/// * None: Every word is a candidate. This is the default.
+/// * FirstChar: Only the words that start with the same character as the input are candidates.
+///   It is the fastest, but it never corrects a typo in the first character, like "wello" for "hello".
+/// * Length: Only the words whose length is close enough to the input to be within the distance are candidates.
+///   It never misses a correction, but only skips words when the [Scorer](trait.Scorer.html) knows how far away an option can be.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector, Dictionary, Partition};
+///
+/// let corrector = Corrector::new(Algorithm::Levenshtein);
+/// let dictionary = Dictionary::from_iter(["hello", "wells"]);
+/// assert_eq!(dictionary.suggest(&corrector, "wello", 1)[0].word.as_deref(), Some("hello"));
+///
+/// let dictionary = dictionary.partition(Partition::FirstChar);
+/// assert_eq!(dictionary.suggest(&corrector, "wello", 1)[0].word.as_deref(), Some("wells"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Partition {
+    #[default]
+    None,
+    FirstChar,
+    Length,
+}
+
+impl Partition {
+    /// The bucket of a word. The empty word has a bucket of its own for the first character.
+    pub(crate) fn key(&self, word: &str) -> usize {
+        match self {
+            Partition::None => 0,
+            Partition::FirstChar => word.chars().next().map_or(usize::MAX, |c| c as usize),
+            Partition::Length => word.chars().count(),
+        }
+    }
+}
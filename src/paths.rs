@@ -0,0 +1,205 @@
+use crate::metrics::levenshtein::alignment_cost;
+use crate::{Algorithm, CorrectOsStr, CorrectWord, Corrector, Scorer};
+use std::ffi::OsStr;
+
+/// # Struct: PathScorer
+/// A [Scorer](../trait.Scorer.html) for file paths, that keeps their structure in mind.
+///
+/// The paths are split into components on `/`, which is never matched like a character.
+/// The last component, the file name, is compared on its own, and the directories are lined up with each other,
+/// so a typo in a directory name costs part of that directory, and a missing directory costs a whole one.
+/// Empty and `.` components are skipped, so `./src/lib.rs` and `src/lib.rs` are the same path.
+///
+/// The file name is split into its stem and its extension, which are compared separately with the inner scorer.
+/// A different extension costs half of the file name, unless extensions are [ignored](#method.ignore_extension).
+/// The similarity is the average over all the components, counting the file name as one.
+///
+/// # Example
+/// ```
+/// use correct_word::paths::PathScorer;
+/// use correct_word::{Algorithm, Corrector};
+///
+/// let candidates = ["src/lib.rs", "src/main.rs", "tests/lib.rs"];
+/// let corrector = Corrector::new(PathScorer::new(Algorithm::Levenshtein));
+/// assert_eq!(corrector.correct("scr/lib.rs", &candidates).word.unwrap(), "src/lib.rs");
+///
+/// let corrector = Corrector::new(PathScorer::new(Algorithm::Levenshtein).ignore_extension(true));
+/// assert_eq!(corrector.correct("src/main", &candidates).word.unwrap(), "src/main.rs");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PathScorer<S: Scorer = Algorithm> {
+    scorer: S,
+    ignore_extension: bool,
+}
+
+impl<S: Scorer> PathScorer<S> {
+    /// Creates a path scorer that compares components with the given algorithm, or scorer.
+    pub fn new(scorer: S) -> Self {
+        PathScorer {
+            scorer,
+            ignore_extension: false,
+        }
+    }
+
+    /// Sets whether the extensions of the file names are left out, so only the stems are compared.
+    /// By default, they are compared.
+    pub fn ignore_extension(mut self, ignore: bool) -> Self {
+        self.ignore_extension = ignore;
+        self
+    }
+
+    fn file_similarity(&self, name1: &str, name2: &str) -> f64 {
+        let (stem1, extension1) = split_extension(name1);
+        let (stem2, extension2) = split_extension(name2);
+        let stem = self.scorer.similarity(stem1, stem2);
+        if self.ignore_extension || extension1 == extension2 {
+            return stem;
+        }
+        let extension = self.scorer.similarity(
+            extension1.unwrap_or_default(),
+            extension2.unwrap_or_default(),
+        );
+        (stem + extension) / 2.0
+    }
+}
+
+impl<S: Scorer> Scorer for PathScorer<S> {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        let components1 = components(a);
+        let components2 = components(b);
+        let (Some((file1, directories1)), Some((file2, directories2))) =
+            (components1.split_last(), components2.split_last())
+        else {
+            return if components1.is_empty() && components2.is_empty() {
+                1.0
+            } else {
+                0.0
+            };
+        };
+
+        let directories = alignment_cost(directories1, directories2, |a, b| {
+            1.0 - self.scorer.similarity(a, b)
+        });
+        let file = 1.0 - self.file_similarity(file1, file2);
+        let count = directories1.len().max(directories2.len()) + 1;
+        1.0 - (directories + file) / count as f64
+    }
+}
+
+/// Corrects a file path from a list of candidates, with a [PathScorer](struct.PathScorer.html) using Levenshtein
+/// and the default threshold of 0.5.
+/// Use a [Corrector](../struct.Corrector.html) with a `PathScorer` for any other configuration.
+///
+/// # Arguments
+///
+/// * `input` - The path to correct.
+/// * `candidates` - The paths that exist.
+///
+/// # Returns
+///
+/// `CorrectWord` - The closest path, if any is close enough, and its similarity.
+///
+/// # Example
+/// ```
+/// use correct_word::paths::correct_path;
+///
+/// let result = correct_path("src/lbi.rs", &["src/lib.rs", "src/bin/main.rs"]);
+/// assert_eq!(result.word.unwrap(), "src/lib.rs");
+/// ```
+pub fn correct_path<O: AsRef<str>>(input: &str, candidates: &[O]) -> CorrectWord {
+    Corrector::new(PathScorer::new(Algorithm::Levenshtein)).correct(input, candidates)
+}
+
+/// Corrects a file path like [correct_path](fn.correct_path.html), for paths that don't have to be valid UTF-8,
+/// like the file names on Linux, with [Corrector::correct_os_str](../struct.Corrector.html#method.correct_os_str).
+///
+/// # Arguments
+///
+/// * `input` - The path to correct.
+/// * `candidates` - The paths that exist, like `&[PathBuf]`.
+///
+/// # Returns
+///
+/// `CorrectOsStr` - The closest path as it was, if any is close enough, and its similarity.
+///
+/// # Example
+/// ```
+/// use correct_word::paths::correct_os_str;
+/// use std::ffi::OsStr;
+/// use std::path::PathBuf;
+///
+/// # #[cfg(unix)]
+/// # {
+/// use std::os::unix::ffi::OsStrExt;
+///
+/// // A file name in Latin-1, which isn't valid UTF-8
+/// let candidates = vec![PathBuf::from(OsStr::from_bytes(b"docs/r\xe9sum\xe9.txt")), PathBuf::from("docs/notes.txt")];
+/// let result = correct_os_str(OsStr::new("docs/resume.txt"), &candidates);
+/// assert_eq!(result.word.unwrap(), candidates[0].as_os_str());
+/// # }
+/// ```
+pub fn correct_os_str<O: AsRef<OsStr>>(input: &OsStr, candidates: &[O]) -> CorrectOsStr {
+    Corrector::new(PathScorer::new(Algorithm::Levenshtein)).correct_os_str(input, candidates)
+}
+
+fn components(path: &str) -> Vec<&str> {
+    path.split('/')
+        .filter(|component| !component.is_empty() && *component != ".")
+        .collect()
+}
+
+/// Splits a file name at its last dot. A leading dot, like in `.gitignore`, is part of the stem.
+fn split_extension(name: &str) -> (&str, Option<&str>) {
+    match name.rfind('.') {
+        Some(dot) if dot > 0 => (&name[..dot], Some(&name[dot + 1..])),
+        _ => (name, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn components_are_scored_separately() {
+        let scorer = PathScorer::new(Algorithm::Levenshtein);
+        assert_eq!(scorer.similarity("./src//lib.rs", "src/lib.rs"), 1.0);
+        assert_eq!(scorer.similarity("", "/"), 1.0);
+        assert_eq!(scorer.similarity("", "lib.rs"), 0.0);
+        // A whole directory is missing
+        assert_eq!(scorer.similarity("lib.rs", "src/lib.rs"), 0.5);
+        // Half of the file name is the extension
+        assert_eq!(scorer.similarity("src/lib.rs", "src/lib.py"), 0.75);
+        assert_eq!(
+            scorer
+                .ignore_extension(true)
+                .similarity("src/lib.rs", "src/lib.py"),
+            1.0
+        );
+        assert_eq!(scorer.similarity(".gitignore", ".gitignore"), 1.0);
+
+        // Plain Levenshtein sees both as one edit away, and takes the first
+        let candidates = ["srclib.rs", "src/lob.rs"];
+        let plain = Corrector::new(Algorithm::Levenshtein).correct("src/lib.rs", &candidates);
+        assert_eq!(plain.word.unwrap(), "srclib.rs");
+        assert_eq!(
+            correct_path("src/lib.rs", &candidates).word.unwrap(),
+            "src/lob.rs"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn os_str_equal_option_wins() {
+        use std::os::unix::ffi::OsStrExt;
+
+        // Both are "a\u{fffd}.txt" once lossy
+        let candidates = [
+            OsStr::from_bytes(b"a\xfe.txt"),
+            OsStr::from_bytes(b"a\xff.txt"),
+        ];
+        let result = correct_os_str(OsStr::from_bytes(b"a\xff.txt"), &candidates);
+        assert_eq!(result.word.as_deref(), Some(candidates[1]));
+        assert_eq!(result.confidence, 1.0);
+    }
+}
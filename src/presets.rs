@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use crate::{Algorithm, Corrector, FuzzyMap, Normalizer};
+
+/// # Struct: PresetEntry
+/// An entry of a [PresetMatcher](struct.PresetMatcher.html), with its canonical code and its English name,
+/// like "DE" and "Germany".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PresetEntry {
+    pub code: &'static str,
+    pub name: &'static str,
+}
+
+/// # Struct: PresetMatcher
+/// A ready-made matcher for the values of a form field, like a country, a US state or a currency,
+/// that turns what users type into the canonical code, each list behind its own feature:
+/// * [countries](#method.countries) - The countries and territories of ISO 3166-1, with the `preset-countries` feature.
+///   The code is the alpha-2 one, like "DE", and the alpha-3 one, like "DEU", is matched too.
+/// * [us_states](#method.us_states) - The 50 US states and the District of Columbia, with their postal abbreviations,
+///   with the `preset-us-states` feature.
+/// * [currencies](#method.currencies) - The currencies of ISO 4217, with the `preset-currencies` feature.
+///
+/// A code is only matched exactly, in any case, since a typo in a code of two or three letters is usually another code.
+/// A name, or one of its other common names like "Ivory Coast", is matched with [Algorithm::Osa](enum.Algorithm.html),
+/// so a swap of two letters is one edit, ignoring case, accents and punctuation, and it needs a similarity of 0.75.
+/// The lists are a snapshot of the standards: names and currencies change, so check them if you rely on them.
+///
+/// # Example
+/// ```
+/// use correct_word::presets::PresetMatcher;
+///
+/// # #[cfg(feature = "preset-countries")] {
+/// let countries = PresetMatcher::countries();
+/// assert_eq!(countries.find("Germny").unwrap().code, "DE");
+/// assert_eq!(countries.find("deu").unwrap().code, "DE");
+/// assert_eq!(countries.find("untied states").unwrap().name, "United States");
+/// assert_eq!(countries.find("Cote dIvoire").unwrap().code, "CI");
+/// assert_eq!(countries.find("Atlantis"), None);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PresetMatcher {
+    entries: Vec<PresetEntry>,
+    codes: HashMap<String, usize>,
+    names: FuzzyMap<usize>,
+}
+
+impl PresetMatcher {
+    /// The countries and territories of ISO 3166-1, by alpha-2 code.
+    #[cfg(feature = "preset-countries")]
+    pub fn countries() -> Self {
+        Self::parse(include_str!("presets/countries.tsv"))
+    }
+
+    /// The 50 US states and the District of Columbia, by postal abbreviation.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::presets::PresetMatcher;
+    ///
+    /// # #[cfg(feature = "preset-us-states")] {
+    /// let states = PresetMatcher::us_states();
+    /// assert_eq!(states.find("Califronia").unwrap().code, "CA");
+    /// assert_eq!(states.find("ny").unwrap().name, "New York");
+    /// # }
+    /// ```
+    #[cfg(feature = "preset-us-states")]
+    pub fn us_states() -> Self {
+        Self::parse(include_str!("presets/us_states.tsv"))
+    }
+
+    /// The currencies of ISO 4217, by code.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::presets::PresetMatcher;
+    ///
+    /// # #[cfg(feature = "preset-currencies")] {
+    /// let currencies = PresetMatcher::currencies();
+    /// assert_eq!(currencies.find("japanese yen").unwrap().code, "JPY");
+    /// assert_eq!(currencies.find("Swiss frank").unwrap().code, "CHF");
+    /// assert_eq!(currencies.find("eur").unwrap().name, "Euro");
+    /// # }
+    /// ```
+    #[cfg(feature = "preset-currencies")]
+    pub fn currencies() -> Self {
+        Self::parse(include_str!("presets/currencies.tsv"))
+    }
+
+    /// Reads a list with an entry per line: its code, its name, and its other codes and names, separated by tabs.
+    /// An other name that is two or three uppercase letters, like "DEU" or "UK", is a code.
+    fn parse(list: &'static str) -> Self {
+        let corrector = Corrector::new(Algorithm::Osa).threshold(0.75).normalizer(
+            Normalizer::new()
+                .trim()
+                .lowercase()
+                .strip_accents()
+                .strip_punctuation()
+                .collapse_whitespace(),
+        );
+        let mut matcher = PresetMatcher {
+            entries: Vec::new(),
+            codes: HashMap::new(),
+            names: FuzzyMap::with_corrector(corrector),
+        };
+        for line in list.lines() {
+            let mut fields = line.split('\t');
+            let (Some(code), Some(name)) = (fields.next(), fields.next()) else {
+                continue;
+            };
+            let position = matcher.entries.len();
+            matcher.entries.push(PresetEntry { code, name });
+            matcher.codes.insert(code.to_string(), position);
+            matcher.names.insert(name, position);
+            for alias in fields {
+                if (2..=3).contains(&alias.len()) && alias.bytes().all(|b| b.is_ascii_uppercase()) {
+                    matcher.codes.insert(alias.to_string(), position);
+                } else {
+                    matcher.names.insert(alias, position);
+                }
+            }
+        }
+        matcher
+    }
+
+    /// The entries of the list, in the order of the file they come from.
+    pub fn entries(&self) -> &[PresetEntry] {
+        &self.entries
+    }
+
+    /// Finds the entry a user meant: the one with this code, in any case, or else the one with the closest name.
+    ///
+    /// # Returns
+    ///
+    /// `Option<PresetEntry>` - The entry, or None if no code is the same and no name is close enough.
+    pub fn find(&self, input: &str) -> Option<PresetEntry> {
+        let input = input.trim();
+        if let Some(&position) = self.codes.get(&input.to_uppercase()) {
+            return Some(self.entries[position]);
+        }
+        self.names
+            .get_fuzzy(input)
+            .map(|(_, &position)| self.entries[position])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_entry_finds_itself() {
+        let mut matchers = Vec::new();
+        #[cfg(feature = "preset-countries")]
+        matchers.push(PresetMatcher::countries());
+        #[cfg(feature = "preset-us-states")]
+        matchers.push(PresetMatcher::us_states());
+        #[cfg(feature = "preset-currencies")]
+        matchers.push(PresetMatcher::currencies());
+        for matcher in matchers {
+            for &entry in matcher.entries() {
+                assert_eq!(matcher.find(entry.code), Some(entry));
+                assert_eq!(matcher.find(entry.name), Some(entry));
+                assert_eq!(matcher.find(&entry.name.to_uppercase()), Some(entry));
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{Algorithm, CorrectWord, Corrector, UnknownAlgorithm};
+
+/// # Struct: Correction
+/// A correction as seen from Python, with the same `word` and `confidence` as a [CorrectWord](../struct.CorrectWord.html).
+/// The word is `None` when no option cleared the threshold.
+#[pyclass(name = "Correction", module = "correct_word", frozen, get_all)]
+pub struct Correction {
+    pub word: Option<String>,
+    pub confidence: f64,
+}
+
+#[pymethods]
+impl Correction {
+    fn __repr__(&self) -> String {
+        match &self.word {
+            Some(word) => format!("Correction(word={word:?}, confidence={})", self.confidence),
+            None => format!("Correction(word=None, confidence={})", self.confidence),
+        }
+    }
+}
+
+impl From<CorrectWord> for Correction {
+    fn from(correction: CorrectWord) -> Self {
+        Correction {
+            word: correction.word,
+            confidence: correction.confidence,
+        }
+    }
+}
+
+/// # Struct: Dictionary
+/// A [Dictionary](../struct.Dictionary.html) for Python, created from a list of words.
+/// The words are copied over once, and the GIL is released while they are scanned,
+/// so other Python threads keep running during a correction.
+#[pyclass(name = "Dictionary", module = "correct_word", frozen)]
+pub struct PyDictionary {
+    dictionary: crate::Dictionary,
+}
+
+#[pymethods]
+impl PyDictionary {
+    /// Creates a dictionary from a list of words.
+    #[new]
+    fn new(words: Vec<String>) -> Self {
+        PyDictionary {
+            dictionary: crate::Dictionary::from(words),
+        }
+    }
+
+    fn __len__(&self) -> usize {
+        self.dictionary.len()
+    }
+
+    fn __contains__(&self, word: &str) -> bool {
+        self.dictionary.contains(word)
+    }
+
+    /// Corrects a word from the words of the dictionary, like `correct_word`.
+    #[pyo3(signature = (input, algorithm=None, threshold=None))]
+    fn correct(
+        &self,
+        py: Python<'_>,
+        input: &str,
+        algorithm: Option<&str>,
+        threshold: Option<f64>,
+    ) -> PyResult<Correction> {
+        let corrector = corrector(algorithm, threshold)?;
+        Ok(py
+            .detach(|| corrector.correct(input, self.dictionary.words()))
+            .into())
+    }
+
+    /// Returns up to `n` corrections from the words of the dictionary, like `correct_word_n`.
+    #[pyo3(signature = (input, n, algorithm=None, threshold=None))]
+    fn correct_n(
+        &self,
+        py: Python<'_>,
+        input: &str,
+        n: usize,
+        algorithm: Option<&str>,
+        threshold: Option<f64>,
+    ) -> PyResult<Vec<Correction>> {
+        let corrector = corrector(algorithm, threshold)?;
+        let corrections = py.detach(|| self.dictionary.suggest(&corrector, input, n));
+        Ok(corrections.into_iter().map(Correction::from).collect())
+    }
+}
+
+/// Corrects a word from a list of options.
+/// The algorithm is a name like "levenshtein" or "ratcliff-obershelp", and defaults to Levenshtein.
+/// The threshold is the minimum similarity, and defaults to 0.5.
+#[pyfunction]
+#[pyo3(signature = (input, options, algorithm=None, threshold=None))]
+fn correct_word(
+    py: Python<'_>,
+    input: &str,
+    options: Vec<String>,
+    algorithm: Option<&str>,
+    threshold: Option<f64>,
+) -> PyResult<Correction> {
+    let corrector = corrector(algorithm, threshold)?;
+    Ok(py.detach(|| corrector.correct(input, &options)).into())
+}
+
+/// Returns up to `n` corrections of a word from a list of options, best first.
+#[pyfunction]
+#[pyo3(signature = (input, options, n, algorithm=None, threshold=None))]
+fn correct_word_n(
+    py: Python<'_>,
+    input: &str,
+    options: Vec<String>,
+    n: usize,
+    algorithm: Option<&str>,
+    threshold: Option<f64>,
+) -> PyResult<Vec<Correction>> {
+    let corrector = corrector(algorithm, threshold)?;
+    let corrections = py.detach(|| corrector.correct_n(input, &options, n));
+    Ok(corrections.into_iter().map(Correction::from).collect())
+}
+
+/// The Levenshtein distance between two strings.
+#[pyfunction]
+fn levenshtein_distance(string1: String, string2: String) -> u16 {
+    crate::metrics::levenshtein::levenshtein_distance(string1, string2)
+}
+
+fn corrector(algorithm: Option<&str>, threshold: Option<f64>) -> PyResult<Corrector> {
+    let algorithm = match algorithm {
+        Some(name) => name
+            .parse()
+            .map_err(|error: UnknownAlgorithm| PyValueError::new_err(error.to_string()))?,
+        None => Algorithm::Levenshtein,
+    };
+    Ok(Corrector::new(algorithm).threshold(threshold.unwrap_or(0.5)))
+}
+
+#[pymodule(name = "correct_word")]
+mod module {
+    #[pymodule_export]
+    use super::{correct_word, correct_word_n, levenshtein_distance, Correction, PyDictionary};
+}
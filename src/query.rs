@@ -0,0 +1,77 @@
+/// # Struct: Query
+/// Everything a caller wants from a lookup in one place, for [Corrector::query](struct.Corrector.html#method.query),
+/// [Corrector::query_from](struct.Corrector.html#method.query_from) and [Dictionary::suggest_query](struct.Dictionary.html#method.suggest_query).
+///
+/// * `max_results`: The number of corrections to return at most. By default, every correction.
+/// * `min_confidence`: A confidence every correction needs, on top of the threshold of the corrector.
+/// * `max_distance`: A [distance](trait.Scorer.html#method.distance) no correction can be over, on top of the threshold.
+/// * `include_exact`: Whether an option equal to the input is returned. By default, it is, and it comes first.
+///   Turn it off for a "did you mean" list, where the input itself is no suggestion.
+///
+/// Unlike filtering the corrections afterwards, the limits of the query also narrow down the candidates,
+/// so a [TrieDictionary](struct.TrieDictionary.html) doesn't look any further than the query allows,
+/// and the options whose lengths are too different aren't scored.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector, Query};
+///
+/// let options = ["hello", "help", "helm", "hell", "world"];
+/// let corrector = Corrector::new(Algorithm::Levenshtein).threshold(0.5);
+/// let query = Query::new().max_results(2).max_distance(1).include_exact(false);
+/// let words: Vec<String> = corrector
+///     .query("hell", &options, &query)
+///     .into_iter()
+///     .filter_map(|correction| correction.word)
+///     .collect();
+/// assert_eq!(words, ["hello", "help"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Query {
+    pub(crate) max_results: usize,
+    pub(crate) min_confidence: Option<f64>,
+    pub(crate) max_distance: Option<u16>,
+    pub(crate) include_exact: bool,
+}
+
+impl Default for Query {
+    fn default() -> Self {
+        Query {
+            max_results: usize::MAX,
+            min_confidence: None,
+            max_distance: None,
+            include_exact: true,
+        }
+    }
+}
+
+impl Query {
+    /// Creates a query for every correction that clears the threshold of the corrector, with an exact match included.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of corrections to return at most.
+    pub fn max_results(mut self, n: usize) -> Self {
+        self.max_results = n;
+        self
+    }
+
+    /// Sets a confidence every correction needs, on top of the threshold of the corrector.
+    pub fn min_confidence(mut self, confidence: f64) -> Self {
+        self.min_confidence = Some(confidence);
+        self
+    }
+
+    /// Sets a distance no correction can be over, on top of the threshold of the corrector.
+    pub fn max_distance(mut self, distance: u16) -> Self {
+        self.max_distance = Some(distance);
+        self
+    }
+
+    /// Sets whether an option equal to the input is returned.
+    pub fn include_exact(mut self, enabled: bool) -> Self {
+        self.include_exact = enabled;
+        self
+    }
+}
@@ -0,0 +1,226 @@
+use std::cell::RefCell;
+
+use crate::metrics::common::similarity;
+use crate::metrics::levenshtein::{levenshtein_similarity_bytes, LevenshteinState};
+use crate::{Algorithm, Granularity, Threshold};
+
+/// # Trait: Scorer
+/// Anything that can score how similar an option is to the input.
+/// The built-in [Algorithm](enum.Algorithm.html) enum implements it, and so does any
+/// `Fn(&str, &str) -> f64` closure.
+///
+/// Implement it for your own type to use a domain-specific similarity, like one for chemical names or file paths,
+/// with the ranking, thresholds and top-N results of a [Corrector](struct.Corrector.html).
+///
+/// Only `similarity` is required. It should return a f64 from 0 to 1, with 1 being equal strings.
+/// All the built-in similarities keep to the same rules at the edges:
+/// two empty strings have a similarity of 1, and an empty string has a similarity of 0 to any other string.
+/// A [Corrector](struct.Corrector.html) treats a NaN as a similarity of 0.
+/// The other methods have defaults:
+/// * `distance`: Used by a [Threshold::MaxDistance](enum.Threshold.html). By default, the Levenshtein distance.
+/// * `similarity_with` and `distance_with`: Used with a [Granularity](enum.Granularity.html) other than characters.
+///   By default, the granularity is ignored and the whole strings are compared.
+/// * `similarity_bytes` and `distance_bytes`: Used by [Corrector::correct_bytes](struct.Corrector.html#method.correct_bytes).
+///   By default, byte strings that are valid UTF-8 are compared with [Granularity::Bytes](enum.Granularity.html),
+///   and the others as strings, with the invalid bytes replaced by `U+FFFD`.
+/// * `max_levenshtein`: Used by indexes like [TrieDictionary](struct.TrieDictionary.html) to skip options.
+///   By default, None, so every option is scored.
+/// * `similarity_within` and `distance_within`: Used by a [Corrector](struct.Corrector.html) when `max_levenshtein` is known,
+///   so a scorer can stop comparing an option once it is too far away, like the banded DP of
+///   [levenshtein_distance_within](metrics/levenshtein/fn.levenshtein_distance_within.html).
+///   By default, the options are compared in full. An option they find further away than that distance
+///   is left out of the scored options, as [out of the band](enum.TraceOutcome.html), without being compared in full.
+///
+/// # Example
+/// ```
+/// use correct_word::{Corrector, Scorer};
+///
+/// // Only compares the file names, ignoring the directories
+/// struct FileName;
+///
+/// impl Scorer for FileName {
+///     fn similarity(&self, a: &str, b: &str) -> f64 {
+///         let name = |path: &str| path.rsplit('/').next().unwrap_or(path).to_string();
+///         correct_word::metrics::levenshtein::levenshtein_similarity(name(a), name(b))
+///     }
+/// }
+///
+/// let corrector = Corrector::new(FileName);
+/// let result = corrector.correct("lib.rs", &["src/main.rs", "src/lib.rs"]);
+/// assert_eq!(result.word.unwrap(), "src/lib.rs");
+///
+/// // Closures work too
+/// let corrector = Corrector::new(|a: &str, b: &str| if b.starts_with(a) { 1.0 } else { 0.0 });
+/// assert_eq!(corrector.correct("wor", &["hello", "world"]).word.unwrap(), "world");
+/// ```
+pub trait Scorer {
+    /// The similarity between the input `a` and the option `b`, from 0 to 1.
+    fn similarity(&self, a: &str, b: &str) -> f64;
+
+    /// The distance between the input `a` and the option `b`. By default, the Levenshtein distance.
+    fn distance(&self, a: &str, b: &str) -> u16 {
+        with_state(|state| state.distance(a, b))
+    }
+
+    /// The similarity between `a` and `b`, comparing the units of the given granularity.
+    /// By default, the granularity is ignored.
+    fn similarity_with(&self, a: &str, b: &str, granularity: Granularity) -> f64 {
+        let _ = granularity;
+        self.similarity(a, b)
+    }
+
+    /// The distance between `a` and `b`, counting the units of the given granularity.
+    /// By default, the granularity is ignored.
+    fn distance_with(&self, a: &str, b: &str, granularity: Granularity) -> u16 {
+        let _ = granularity;
+        self.distance(a, b)
+    }
+
+    /// The similarity between the byte strings `a` and `b`, which don't have to be valid UTF-8.
+    /// By default, they are compared with [Granularity::Bytes](enum.Granularity.html) when they are valid UTF-8,
+    /// and as strings with the invalid bytes replaced otherwise.
+    fn similarity_bytes(&self, a: &[u8], b: &[u8]) -> f64 {
+        match (std::str::from_utf8(a), std::str::from_utf8(b)) {
+            (Ok(a), Ok(b)) => self.similarity_with(a, b, Granularity::Bytes),
+            _ => self.similarity(&String::from_utf8_lossy(a), &String::from_utf8_lossy(b)),
+        }
+    }
+
+    /// The distance between the byte strings `a` and `b`, which don't have to be valid UTF-8.
+    /// By default, they are compared like in [similarity_bytes](#method.similarity_bytes).
+    fn distance_bytes(&self, a: &[u8], b: &[u8]) -> u16 {
+        match (std::str::from_utf8(a), std::str::from_utf8(b)) {
+            (Ok(a), Ok(b)) => self.distance_with(a, b, Granularity::Bytes),
+            _ => self.distance(&String::from_utf8_lossy(a), &String::from_utf8_lossy(b)),
+        }
+    }
+
+    /// The largest Levenshtein distance, in characters, an option can be from the input and still clear the threshold.
+    /// Indexes use it to skip the options that are further away without scoring them.
+    /// By default, None: any option might clear the threshold.
+    fn max_levenshtein(&self, input: &str, threshold: Threshold) -> Option<u16> {
+        let _ = (input, threshold);
+        None
+    }
+
+    /// The similarity between `a` and `b`, or None if they are more than `max_distance` Levenshtein edits apart.
+    /// By default, always the similarity.
+    fn similarity_within(&self, a: &str, b: &str, max_distance: u16) -> Option<f64> {
+        let _ = max_distance;
+        Some(self.similarity(a, b))
+    }
+
+    /// The distance between `a` and `b`, or None if it is more than `max`.
+    /// By default, the [distance](#method.distance) is calculated in full and compared to `max`.
+    fn distance_within(&self, a: &str, b: &str, max: u16) -> Option<u16> {
+        let distance = self.distance(a, b);
+        (distance <= max).then_some(distance)
+    }
+}
+
+impl Scorer for Algorithm {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        with_state(|state| self.similarity_with_state(state, a, b))
+    }
+
+    fn distance(&self, a: &str, b: &str) -> u16 {
+        with_state(|state| self.distance_with_state(state, a, b))
+    }
+
+    fn similarity_with(&self, a: &str, b: &str, granularity: Granularity) -> f64 {
+        if granularity == Granularity::Chars {
+            return Scorer::similarity(self, a, b);
+        }
+        let a = granularity.tokens(a);
+        let b = granularity.tokens(b);
+        with_state(|state| self.similarity_tokens(state, &a, &b))
+    }
+
+    fn distance_with(&self, a: &str, b: &str, granularity: Granularity) -> u16 {
+        if granularity == Granularity::Chars {
+            return Scorer::distance(self, a, b);
+        }
+        let a = granularity.tokens(a);
+        let b = granularity.tokens(b);
+        with_state(|state| self.distance_tokens(state, &a, &b))
+    }
+
+    fn similarity_bytes(&self, a: &[u8], b: &[u8]) -> f64 {
+        if *self == Algorithm::Levenshtein {
+            return levenshtein_similarity_bytes(a, b);
+        }
+        let a: Vec<&[u8]> = a.chunks(1).collect();
+        let b: Vec<&[u8]> = b.chunks(1).collect();
+        with_state(|state| self.similarity_tokens(state, &a, &b))
+    }
+
+    fn distance_bytes(&self, a: &[u8], b: &[u8]) -> u16 {
+        if *self == Algorithm::Levenshtein {
+            return with_state(|state| state.distance_bytes(a, b)) as u16;
+        }
+        let a: Vec<&[u8]> = a.chunks(1).collect();
+        let b: Vec<&[u8]> = b.chunks(1).collect();
+        with_state(|state| self.distance_tokens(state, &a, &b))
+    }
+
+    fn max_levenshtein(&self, input: &str, threshold: Threshold) -> Option<u16> {
+        // The distances of Lcs and RatcliffObershelp are never smaller than the Levenshtein distance,
+        // but their similarities let an option be twice as far away.
+        // A swap is one edit for Osa and DamerauLevenshtein, but two for Levenshtein.
+        let (distance_factor, similarity_factor) = match self {
+            Algorithm::Levenshtein => (1, 1.0),
+            Algorithm::Lcs | Algorithm::RatcliffObershelp => (1, 2.0),
+            Algorithm::Osa | Algorithm::DamerauLevenshtein => (2, 2.0),
+            Algorithm::Partial
+            | Algorithm::Subsequence
+            | Algorithm::Cosine
+            | Algorithm::Jaccard
+            | Algorithm::Sift4 => return None,
+        };
+        match threshold {
+            Threshold::MaxDistance(distance) => Some(distance.saturating_mul(distance_factor)),
+            Threshold::Similarity(similarity) if similarity > 0.0 => {
+                let length = input.chars().count() as f64;
+                let distance = similarity_factor * (1.0 - similarity) * length / similarity;
+                Some((distance + 1e-9).floor().clamp(0.0, u16::MAX as f64) as u16)
+            }
+            Threshold::Similarity(_) => None,
+        }
+    }
+
+    fn similarity_within(&self, a: &str, b: &str, max_distance: u16) -> Option<f64> {
+        if *self != Algorithm::Levenshtein {
+            return Some(Scorer::similarity(self, a, b));
+        }
+        let distance = with_state(|state| state.distance_within(a, b, max_distance))?;
+        let length = std::cmp::max(a.chars().count(), b.chars().count());
+        Some(similarity(distance as usize, length))
+    }
+
+    fn distance_within(&self, a: &str, b: &str, max: u16) -> Option<u16> {
+        if *self != Algorithm::Levenshtein {
+            let distance = Scorer::distance(self, a, b);
+            return (distance <= max).then_some(distance);
+        }
+        with_state(|state| state.distance_within(a, b, max))
+    }
+}
+
+impl<F: Fn(&str, &str) -> f64> Scorer for F {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        self(a, b)
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<LevenshteinState> = RefCell::new(LevenshteinState::new());
+}
+
+/// Runs `f` with this thread's scratch state, so scoring many options reuses the same buffers.
+fn with_state<T>(f: impl FnOnce(&mut LevenshteinState) -> T) -> T {
+    STATE.with(|state| match state.try_borrow_mut() {
+        Ok(mut state) => f(&mut state),
+        // A scorer calling back into another scorer gets its own buffers
+        Err(_) => f(&mut LevenshteinState::new()),
+    })
+}
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+/// # Struct: FuzzyMatch
+/// An approximate occurrence of a needle in a haystack, found by [find_fuzzy](fn.find_fuzzy.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// The byte offset in the haystack where the occurrence starts.
+    pub start: usize,
+    /// The byte offset in the haystack right after the occurrence, so `&haystack[start..end]` is the occurrence.
+    pub end: usize,
+    /// The Levenshtein distance between the needle and the occurrence.
+    pub distance: u16,
+}
+
+/// Finds the approximate occurrences of the needle inside a longer text, like `agrep`:
+/// the parts of the haystack that are at most `max_distance` insertions, deletions and substitutions away from the needle.
+/// Unlike the [Partial](../enum.Algorithm.html) algorithm, this finds every occurrence, and where it is,
+/// instead of scoring the best one.
+///
+/// Every end position of the haystack gets the distance of the best occurrence that ends there.
+/// With needles of up to 64 characters it uses [Myers' bit-parallel algorithm](https://doi.org/10.1145/316542.316550),
+/// which looks at every character of the haystack once, and the Sellers DP otherwise.
+/// An occurrence is reported where that distance is at its lowest, and it starts where the longest of the closest
+/// alignments ending there starts. When occurrences overlap, only the closest one is kept, and the first of equally close ones.
+///
+/// Characters are compared exactly. An empty needle has no occurrences.
+///
+/// # Arguments
+///
+/// * `needle` - The pattern to look for.
+/// * `haystack` - The text to look in.
+/// * `max_distance` - The largest distance an occurrence can have.
+///
+/// # Returns
+///
+/// `Vec<FuzzyMatch>` - The occurrences, in the order they are in the haystack.
+///
+/// # Example
+/// ```
+/// use correct_word::search::find_fuzzy;
+///
+/// let haystack = "the quick brwn fox and the quack brown dog";
+/// let matches = find_fuzzy("brown", haystack, 1);
+/// let found: Vec<(&str, u16)> = matches.iter().map(|m| (&haystack[m.start..m.end], m.distance)).collect();
+/// assert_eq!(found, vec![("brwn", 1), ("brown", 0)]);
+/// ```
+pub fn find_fuzzy(needle: &str, haystack: &str, max_distance: u16) -> Vec<FuzzyMatch> {
+    let needle: Vec<char> = needle.chars().collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    // The byte offset of every character, and of the end
+    let mut offsets: Vec<usize> = haystack.char_indices().map(|(offset, _)| offset).collect();
+    offsets.push(haystack.len());
+    let haystack: Vec<char> = haystack.chars().collect();
+
+    let distances = if needle.len() <= 64 {
+        myers_ends(&needle, &haystack)
+    } else {
+        sellers_ends(&needle, &haystack)
+    };
+
+    // The ends where the distance is at its lowest, and low enough
+    let max_distance = max_distance as usize;
+    let mut found: Vec<(usize, usize, usize)> = Vec::new();
+    for (j, &distance) in distances.iter().enumerate() {
+        let falls = j == 0 || distance <= distances[j - 1];
+        let rises = j + 1 == distances.len() || distance < distances[j + 1];
+        if distance <= max_distance && falls && rises {
+            let end = j + 1;
+            let start = longest_start(&needle, &haystack[..end], distance);
+            found.push((start, end, distance));
+        }
+    }
+
+    // The closest occurrences first, and the first of equally close ones
+    let mut order: Vec<usize> = (0..found.len()).collect();
+    order.sort_by_key(|&i| (found[i].2, found[i].0));
+    let mut kept: Vec<(usize, usize, usize)> = Vec::new();
+    for i in order {
+        let (start, end, _) = found[i];
+        if kept.iter().all(|&(s, e, _)| end <= s || e <= start) {
+            kept.push(found[i]);
+        }
+    }
+    kept.sort_by_key(|&(start, ..)| start);
+    kept.into_iter()
+        .map(|(start, end, distance)| FuzzyMatch {
+            start: offsets[start],
+            end: offsets[end],
+            distance: distance.min(u16::MAX as usize) as u16,
+        })
+        .collect()
+}
+
+/// For every end position of the haystack, the distance of the best occurrence of the needle that ends there.
+/// Myers' algorithm for a needle of up to 64 characters, with the first row of the matrix all zeros,
+/// so an occurrence can start anywhere.
+fn myers_ends(needle: &[char], haystack: &[char]) -> Vec<usize> {
+    let mut peq: HashMap<char, u64> = HashMap::new();
+    for (i, c) in needle.iter().enumerate() {
+        *peq.entry(*c).or_default() |= 1 << i;
+    }
+    let last = 1u64 << (needle.len() - 1);
+
+    let mut positive = u64::MAX;
+    let mut negative = 0u64;
+    let mut distance = needle.len();
+    haystack
+        .iter()
+        .map(|c| {
+            let equal = peq.get(c).copied().unwrap_or(0);
+            let vertical = equal | negative;
+            let horizontal = ((equal & positive).wrapping_add(positive) ^ positive) | equal;
+            let mut horizontal_positive = negative | !(horizontal | positive);
+            let mut horizontal_negative = positive & horizontal;
+            if horizontal_positive & last != 0 {
+                distance += 1;
+            } else if horizontal_negative & last != 0 {
+                distance -= 1;
+            }
+            horizontal_positive <<= 1;
+            horizontal_negative <<= 1;
+            positive = horizontal_negative | !(vertical | horizontal_positive);
+            negative = horizontal_positive & vertical;
+            distance
+        })
+        .collect()
+}
+
+/// The same as `myers_ends`, with the whole DP, for needles of any length.
+fn sellers_ends(needle: &[char], haystack: &[char]) -> Vec<usize> {
+    // `column[i]` is the distance between the first `i` characters of the needle and the best part of the haystack
+    // that ends at the current position
+    let mut column: Vec<usize> = (0..=needle.len()).collect();
+    haystack
+        .iter()
+        .map(|c| {
+            let mut diagonal = column[0];
+            for i in 1..=needle.len() {
+                let substitution = diagonal + usize::from(needle[i - 1] != *c);
+                diagonal = column[i];
+                column[i] = substitution.min(column[i] + 1).min(column[i - 1] + 1);
+            }
+            column[needle.len()]
+        })
+        .collect()
+}
+
+/// Where the longest occurrence of the needle that ends at the end of the haystack, at the given distance, starts.
+/// It can't be longer than the needle and the distance together, so only that much of the haystack is looked at.
+/// The DP runs backwards from the end, so the last row has the distance for every start.
+fn longest_start(needle: &[char], haystack: &[char], distance: usize) -> usize {
+    let window = haystack.len().min(needle.len() + distance);
+    let text: Vec<char> = haystack[haystack.len() - window..]
+        .iter()
+        .rev()
+        .copied()
+        .collect();
+    let pattern: Vec<char> = needle.iter().rev().copied().collect();
+
+    let mut row: Vec<usize> = (0..=text.len()).collect();
+    for (i, a) in pattern.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in text.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j + 1] + 1).min(row[j] + 1);
+        }
+    }
+    let length = (0..=text.len())
+        .rev()
+        .find(|&length| row[length] == distance)
+        .unwrap_or(0);
+    haystack.len() - length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn myers_matches_sellers() {
+        let texts = [
+            "",
+            "a",
+            "abcabc",
+            "xabxcbacx",
+            "the cat sat on the mat",
+            "ünïcödé ünicode",
+        ];
+        let needles = ["a", "ab", "abc", "cat", "mat", "unicode", "xx"];
+        for text in texts {
+            let text: Vec<char> = text.chars().collect();
+            for needle in needles {
+                let needle: Vec<char> = needle.chars().collect();
+                assert_eq!(myers_ends(&needle, &text), sellers_ends(&needle, &text));
+            }
+        }
+
+        let long = "a".repeat(70);
+        let text = format!("xx{}yy{}", long, &long[1..]);
+        let needle: Vec<char> = long.chars().collect();
+        let distances = sellers_ends(&needle, &text.chars().collect::<Vec<_>>());
+        assert_eq!(distances[71], 0);
+        assert_eq!(*distances.last().unwrap(), 1);
+    }
+
+    #[test]
+    fn occurrences_are_found() {
+        let found = |needle: &str, haystack: &str, max_distance| -> Vec<(usize, usize, u16)> {
+            find_fuzzy(needle, haystack, max_distance)
+                .into_iter()
+                .map(|m| (m.start, m.end, m.distance))
+                .collect()
+        };
+        assert_eq!(found("abc", "abcabc", 1), vec![(0, 3, 0), (3, 6, 0)]);
+        assert_eq!(found("abc", "abxc", 1), vec![(0, 4, 1)]);
+        // The substitution is longer than the deletion
+        assert_eq!(found("abc", "xbc", 1), vec![(0, 3, 1)]);
+        assert_eq!(found("abc", "xyz", 2), vec![]);
+        assert_eq!(found("", "abc", 3), vec![]);
+        // Offsets are in bytes
+        assert_eq!(found("cafe", "un café", 1), vec![(3, 8, 1)]);
+
+        let long = "b".repeat(80);
+        let haystack = format!("aa{}", long.replacen('b', "x", 1));
+        assert_eq!(found(&long, &haystack, 2), vec![(2, 82, 1)]);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::Threshold;
+
+/// # Enum: ShortWords
+/// Decides how strict a [Corrector](struct.Corrector.html) is with short inputs.
+///
+/// With a fixed threshold, a one or two character input is close to almost anything: "i" has a similarity of 0.5 to "hi".
+/// Set a policy with [short_words](struct.Corrector.html#method.short_words) to keep them from matching absurd options.
+/// The length is counted in characters, after the [normalizer](struct.Corrector.html#method.normalizer).
+///
+/// * Allow: Short inputs have the same threshold as the others. This is the default.
+/// * ExactUpTo(n): Inputs of up to `n` characters are only matched by an equal option.
+/// * MinLength(n): Inputs shorter than `n` characters are never corrected, not even to an equal option.
+/// * ScaleUpTo(n): The threshold gets stricter for inputs shorter than `n` characters, in proportion to how much shorter they are.
+///   An input of `l` characters needs a similarity of `1 - (1 - threshold) * l / n`,
+///   or a distance of at most `max_distance * l / n`, rounded down.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector, ShortWords};
+///
+/// let options = ["hi", "in", "is"];
+/// let corrector = Corrector::new(Algorithm::Levenshtein);
+/// assert_eq!(corrector.correct("i", &options).word.as_deref(), Some("hi"));
+///
+/// let corrector = corrector.short_words(ShortWords::ExactUpTo(2));
+/// assert_eq!(corrector.correct("i", &options).word, None);
+/// assert_eq!(corrector.correct("in", &options).word.as_deref(), Some("in"));
+///
+/// // "helo" needs a similarity of 0.75 instead of 0.5, and "help" has just that
+/// let corrector = Corrector::new(Algorithm::Levenshtein).short_words(ShortWords::ScaleUpTo(8));
+/// assert_eq!(corrector.correct("helo", &["help"]).word.as_deref(), Some("help"));
+/// assert_eq!(corrector.correct("helo", &["hold"]).word, None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShortWords {
+    #[default]
+    Allow,
+    ExactUpTo(usize),
+    MinLength(usize),
+    ScaleUpTo(usize),
+}
+
+/// What an option needs to be a correction of an input of some length.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Acceptance {
+    Threshold(Threshold),
+    /// Only an option equal to the input.
+    Exact,
+    /// Nothing, not even an option equal to the input.
+    Never,
+}
+
+impl ShortWords {
+    /// What an option needs to be a correction of an input of `length` characters, with this policy and the threshold.
+    pub(crate) fn acceptance(&self, length: usize, threshold: Threshold) -> Acceptance {
+        match *self {
+            ShortWords::ExactUpTo(n) if length <= n => Acceptance::Exact,
+            ShortWords::MinLength(n) if length < n => Acceptance::Never,
+            ShortWords::ScaleUpTo(n) if length < n => {
+                let scale = length as f64 / n as f64;
+                Acceptance::Threshold(match threshold {
+                    Threshold::Similarity(similarity) => {
+                        Threshold::Similarity(1.0 - (1.0 - similarity) * scale)
+                    }
+                    Threshold::MaxDistance(distance) => {
+                        Threshold::MaxDistance((distance as f64 * scale).floor() as u16)
+                    }
+                })
+            }
+            _ => Acceptance::Threshold(threshold),
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::{Algorithm, CandidateSource, CorrectWord, Corrector, Dictionary, Scorer};
+
+/// # Struct: SpellChecker
+/// A spell checking session, with the "Add to dictionary" and "Ignore all" of an interactive application.
+///
+/// The checker is layered over a base [Dictionary](struct.Dictionary.html), which it never changes:
+/// * [add_word](#method.add_word) adds a word to the user dictionary. It is correct from then on, and is suggested like any other word.
+///   The user dictionary can be saved to a file and loaded again in the next session.
+/// * [ignore_word](#method.ignore_word) makes a word correct for this session only. It is never suggested for other words.
+///
+/// # Example
+/// ```
+/// use correct_word::{Dictionary, SpellChecker};
+///
+/// let mut checker = SpellChecker::new(Dictionary::from_iter(["hello", "world"]));
+/// assert!(!checker.is_correct("rustacean"));
+/// assert_eq!(checker.suggest("helo", 1)[0].word.as_deref(), Some("hello"));
+///
+/// checker.add_word("rustacean");
+/// checker.ignore_word("lol");
+/// assert!(checker.is_correct("rustacean") && checker.is_correct("lol"));
+/// assert_eq!(checker.correct("rustaceen").word.as_deref(), Some("rustacean"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpellChecker<S: Scorer = Algorithm> {
+    corrector: Corrector<S>,
+    base: Dictionary,
+    user_words: Vec<String>,
+    user: HashSet<String>,
+    ignored: HashSet<String>,
+}
+
+impl SpellChecker {
+    /// Creates a checker over a base dictionary, which corrects words with Levenshtein and the default threshold.
+    pub fn new(base: Dictionary) -> Self {
+        Self::with_corrector(base, Corrector::new(Algorithm::Levenshtein))
+    }
+}
+
+impl<S: Scorer> SpellChecker<S> {
+    /// Creates a checker over a base dictionary, which corrects words with the given corrector.
+    pub fn with_corrector(base: Dictionary, corrector: Corrector<S>) -> Self {
+        SpellChecker {
+            corrector,
+            base,
+            user_words: Vec::new(),
+            user: HashSet::new(),
+            ignored: HashSet::new(),
+        }
+    }
+
+    /// Adds a word to the user dictionary. Returns false if it was already known.
+    pub fn add_word(&mut self, word: impl Into<String>) -> bool {
+        let word = word.into();
+        if self.base.contains(&word) || self.user.contains(&word) {
+            return false;
+        }
+        self.ignored.remove(&word);
+        self.user.insert(word.clone());
+        self.user_words.push(word);
+        true
+    }
+
+    /// Accepts a word as correct for the rest of the session, without adding it to the user dictionary.
+    pub fn ignore_word(&mut self, word: impl Into<String>) {
+        self.ignored.insert(word.into());
+    }
+
+    /// The words added with [add_word](#method.add_word), in the order they were added.
+    pub fn user_words(&self) -> &[String] {
+        &self.user_words
+    }
+
+    /// Whether the word is in the base dictionary or the user dictionary, or is ignored.
+    pub fn is_correct(&self, word: &str) -> bool {
+        self.user.contains(word) || self.ignored.contains(word) || self.base.contains(word)
+    }
+
+    /// Returns up to `n` corrections of the word, best first, from the words of both dictionaries.
+    /// A correct word has no suggestions.
+    pub fn suggest(&self, word: &str, n: usize) -> Vec<CorrectWord> {
+        if self.is_correct(word) {
+            return Vec::new();
+        }
+        self.corrector.correct_n_from(word, &self.words(), n)
+    }
+
+    /// Corrects the word from the words of both dictionaries.
+    /// A correct word is its own correction, with a confidence of 1.
+    pub fn correct(&self, word: &str) -> CorrectWord {
+        if self.is_correct(word) {
+            return CorrectWord {
+                word: Some(word.to_string()),
+                confidence: 1.0,
+                ops: None,
+            };
+        }
+        self.corrector.correct_from(word, &self.words())
+    }
+
+    /// Adds the words of a user dictionary file, with one word per line, like [Dictionary::from_file](struct.Dictionary.html#method.from_file).
+    /// Returns how many of them were new.
+    pub fn load_user_dictionary<P: AsRef<Path>>(&mut self, path: P) -> io::Result<usize> {
+        let words = Dictionary::from_file(path)?;
+        Ok(words
+            .words()
+            .iter()
+            .filter(|word| self.add_word(word.as_str()))
+            .count())
+    }
+
+    /// Saves the user dictionary to a file, with one word per line, replacing the file if it exists.
+    pub fn save_user_dictionary<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        for word in &self.user_words {
+            writeln!(writer, "{word}")?;
+        }
+        writer.flush()
+    }
+
+    fn words(&self) -> Words<'_> {
+        Words {
+            base: &self.base,
+            user_words: &self.user_words,
+            user: &self.user,
+        }
+    }
+}
+
+/// The words of both dictionaries, as one [CandidateSource](trait.CandidateSource.html):
+/// the candidates of the base dictionary, and then every user word.
+struct Words<'a> {
+    base: &'a Dictionary,
+    user_words: &'a [String],
+    user: &'a HashSet<String>,
+}
+
+impl CandidateSource for Words<'_> {
+    fn candidates(&self, input: &str, max_distance: u16) -> impl Iterator<Item = Cow<'_, str>> {
+        self.base
+            .candidates(input, max_distance)
+            .chain(self.user_words.iter().map(String::as_str))
+            .map(Cow::Borrowed)
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        self.user.contains(word) || self.base.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Partition;
+
+    #[test]
+    fn user_dictionary_round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("correct_word_user_{}.txt", std::process::id()));
+        let mut checker = SpellChecker::new(Dictionary::from_iter(["hello"]));
+        checker.add_word("rustacean");
+        checker.add_word("hello");
+        checker.ignore_word("lol");
+        checker.save_user_dictionary(&path).unwrap();
+
+        let mut next_session = SpellChecker::new(Dictionary::from_iter(["hello"]));
+        assert_eq!(next_session.load_user_dictionary(&path).unwrap(), 1);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(next_session.user_words(), ["rustacean"]);
+        assert!(!next_session.is_correct("lol"));
+    }
+
+    #[test]
+    fn suggests_from_both_dictionaries() {
+        let base = Dictionary::from_iter(["cat", "cart", "kitten"]).partition(Partition::Length);
+        let mut checker = SpellChecker::new(base);
+        checker.add_word("car");
+        checker.add_word("cot");
+        // The base words win a tie with the user words
+        let words: Vec<Option<String>> = checker
+            .suggest("cax", 5)
+            .into_iter()
+            .map(|correction| correction.word)
+            .collect();
+        assert_eq!(
+            words,
+            [Some("cat".into()), Some("car".into()), Some("cart".into())]
+        );
+        assert_eq!(checker.correct("cott").word.as_deref(), Some("cot"));
+    }
+}
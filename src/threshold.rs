@@ -0,0 +1,47 @@
+/// # Enum: Threshold
+/// Decides whether an option is close enough to the input to be returned as a correction.
+///
+/// * Similarity: The minimum similarity, from 0 to 1, between the input and the option. This is what the `threshold`
+///   argument of [correct_word](fn.correct_word.html) means.
+/// * MaxDistance: The maximum distance between the input and the option, like "at most 2 edits".
+///
+/// A distance is counted the way the algorithm sees the strings:
+/// * Levenshtein: The number of insertions, deletions and substitutions.
+/// * Lcs: The number of insertions and deletions.
+/// * RatcliffObershelp: The number of characters, in both strings, that are not part of a matching block.
+/// * Partial: The number of insertions and deletions between the shorter string and the best window of the longer one.
+/// * Osa and DamerauLevenshtein: The number of insertions, deletions, substitutions and swaps of adjacent characters.
+/// * Subsequence: The number of characters of the option that are not matched, and `u16::MAX` if the input is not a subsequence of it.
+/// * Cosine: The number of bigrams that are in one string but not in the other.
+/// * Jaccard: The number of different bigrams that are in one string but not in the other.
+/// * Sift4: An approximation of the number of insertions, deletions, substitutions and transpositions.
+///
+/// Either way, the options are still ranked by similarity, and the confidence of a correction is always a similarity.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector, Threshold};
+///
+/// let options = ["install", "instant"];
+/// let corrector = Corrector::new(Algorithm::Levenshtein).threshold(Threshold::MaxDistance(1));
+/// assert_eq!(corrector.correct("instal", &options).word.unwrap(), "install");
+/// assert!(corrector.correct("insta", &options).word.is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Threshold {
+    Similarity(f64),
+    MaxDistance(u16),
+}
+
+impl Default for Threshold {
+    /// A similarity of 0.5, the default of [correct_word](fn.correct_word.html).
+    fn default() -> Self {
+        Threshold::Similarity(0.5)
+    }
+}
+
+impl From<f64> for Threshold {
+    fn from(similarity: f64) -> Self {
+        Threshold::Similarity(similarity)
+    }
+}
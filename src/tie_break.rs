@@ -0,0 +1,50 @@
+use std::cmp::Ordering;
+
+/// # Enum: TieBreak
+/// Decides which of several options with the same confidence comes first.
+///
+/// * First: The option that comes first in the options. This is the default.
+/// * Shorter: The option with fewer characters. Options of the same length keep their order.
+/// * Lexicographic: The option that sorts first, comparing the strings byte by byte.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector, TieBreak};
+///
+/// // All the options have a Levenshtein similarity of 0.5 to "ab"
+/// let options = ["abcd", "xb", "ac"];
+/// let corrector = Corrector::new(Algorithm::Levenshtein);
+/// assert_eq!(corrector.correct("ab", &options).word.unwrap(), "abcd");
+///
+/// let corrector = corrector.tie_break(TieBreak::Shorter);
+/// assert_eq!(corrector.correct("ab", &options).word.unwrap(), "xb");
+///
+/// let corrector = corrector.tie_break(TieBreak::Lexicographic);
+/// assert_eq!(corrector.correct("ab", &options[1..]).word.unwrap(), "ac");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TieBreak {
+    #[default]
+    First,
+    Shorter,
+    Lexicographic,
+}
+
+impl TieBreak {
+    /// Compares two options with the same confidence. `Less` means `a` comes first.
+    pub(crate) fn compare(&self, a: &str, b: &str) -> Ordering {
+        match self {
+            TieBreak::First => Ordering::Equal,
+            TieBreak::Shorter => a.chars().count().cmp(&b.chars().count()),
+            TieBreak::Lexicographic => a.cmp(b),
+        }
+    }
+    /// Compares two byte strings with the same confidence, like [compare](#method.compare), counting bytes instead of characters.
+    pub(crate) fn compare_bytes(&self, a: &[u8], b: &[u8]) -> Ordering {
+        match self {
+            TieBreak::First => Ordering::Equal,
+            TieBreak::Shorter => a.len().cmp(&b.len()),
+            TieBreak::Lexicographic => a.cmp(b),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt;
+
+use crate::{CorrectWord, Threshold};
+
+/// # Struct: ScoringTrace
+/// A record of everything a [Corrector](struct.Corrector.html) did to correct one input,
+/// returned by [Corrector::trace](struct.Corrector.html#method.trace).
+/// It answers "why wasn't my word suggested?": every option is there, with its confidence,
+/// whether it cleared the threshold, or why it was skipped without being scored.
+///
+/// Its `Display` is a table of the options, best first, to print while tuning a threshold.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector, TraceOutcome};
+///
+/// let corrector = Corrector::new(Algorithm::Levenshtein).threshold(0.7);
+/// let trace = corrector.trace("helo", &["hello", "help", "world", "helicopter"]);
+/// assert_eq!(trace.result.word.as_deref(), Some("hello"));
+///
+/// // Levenshtein stops comparing "world" once it is more than one edit away
+/// let world = trace.entry("world").unwrap();
+/// assert_eq!(world.outcome, TraceOutcome::OutOfBand { max_distance: 1 });
+/// let helicopter = trace.entry("helicopter").unwrap();
+/// assert!(matches!(helicopter.outcome, TraceOutcome::Skipped { .. }));
+/// assert_eq!(trace.entry("help").unwrap().outcome, TraceOutcome::Accepted { confidence: 0.75 });
+/// println!("{trace}");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoringTrace {
+    pub input: String,
+    pub threshold: Threshold,
+    /// Every option, in the order of the options.
+    pub entries: Vec<TraceEntry>,
+    /// The correction, the same as [Corrector::correct](struct.Corrector.html#method.correct) returns.
+    pub result: CorrectWord,
+}
+
+/// # Struct: TraceEntry
+/// What happened to one option in a [ScoringTrace](struct.ScoringTrace.html).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceEntry {
+    /// The position of the option in the options.
+    pub index: usize,
+    pub option: String,
+    pub outcome: TraceOutcome,
+}
+
+/// # Enum: TraceOutcome
+/// What happened to an option in a [ScoringTrace](struct.ScoringTrace.html).
+///
+/// * Skipped: The option was not scored, because its length is too different from the input to clear the threshold,
+///   or more different than the [max_length_difference](struct.Corrector.html#method.max_length_difference).
+/// * Duplicate: The option was not scored, because it is equal to the option at `first`
+///   and the corrector [deduplicates](struct.Corrector.html#method.dedup) the options.
+/// * OutOfBand: The option was not scored in full, because the scorer found it more than `max_distance`
+///   Levenshtein edits away, further than a correction can be, like the banded DP of Levenshtein does.
+///   It has no confidence, and doesn't count for the confidence of a result without a correction.
+/// * Rejected: The option was scored, but didn't clear the threshold.
+/// * Accepted: The option cleared the threshold. The best of these is the correction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TraceOutcome {
+    Skipped {
+        length_difference: usize,
+        max_length_difference: usize,
+    },
+    Duplicate {
+        first: usize,
+    },
+    OutOfBand {
+        max_distance: u16,
+    },
+    Rejected {
+        confidence: f64,
+    },
+    Accepted {
+        confidence: f64,
+    },
+}
+
+impl TraceOutcome {
+    /// The confidence of the option, if it was scored.
+    pub fn confidence(&self) -> Option<f64> {
+        match *self {
+            TraceOutcome::Skipped { .. }
+            | TraceOutcome::Duplicate { .. }
+            | TraceOutcome::OutOfBand { .. } => None,
+            TraceOutcome::Rejected { confidence } | TraceOutcome::Accepted { confidence } => {
+                Some(confidence)
+            }
+        }
+    }
+}
+
+impl ScoringTrace {
+    /// The entry of the first option equal to the given one, if it is one of the options.
+    pub fn entry(&self, option: &str) -> Option<&TraceEntry> {
+        self.entries.iter().find(|entry| entry.option == option)
+    }
+}
+
+impl fmt::Display for ScoringTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:?} with threshold {:?}", self.input, self.threshold)?;
+        let mut entries: Vec<&TraceEntry> = self.entries.iter().collect();
+        // Scored options first, best first, and the skipped ones in their order
+        entries.sort_by(|a, b| {
+            let a = a.outcome.confidence().unwrap_or(f64::NEG_INFINITY);
+            let b = b.outcome.confidence().unwrap_or(f64::NEG_INFINITY);
+            b.total_cmp(&a)
+        });
+        for entry in entries {
+            match entry.outcome {
+                TraceOutcome::Skipped {
+                    length_difference,
+                    max_length_difference,
+                } => writeln!(
+                    f,
+                    "  {:>5}  skipped   {:?} (length differs by {}, more than {})",
+                    "-", entry.option, length_difference, max_length_difference
+                )?,
+                TraceOutcome::Duplicate { first } => writeln!(
+                    f,
+                    "  {:>5}  skipped   {:?} (duplicate of option {})",
+                    "-", entry.option, first
+                )?,
+                TraceOutcome::OutOfBand { max_distance } => writeln!(
+                    f,
+                    "  {:>5}  skipped   {:?} (more than {} edits away)",
+                    "-", entry.option, max_distance
+                )?,
+                TraceOutcome::Rejected { confidence } => {
+                    writeln!(f, "  {:.3}  rejected  {:?}", confidence, entry.option)?
+                }
+                TraceOutcome::Accepted { confidence } => {
+                    writeln!(f, "  {:.3}  accepted  {:?}", confidence, entry.option)?
+                }
+            }
+        }
+        match &self.result.word {
+            Some(word) => write!(f, "correction: {:?}", word),
+            None => write!(f, "no correction"),
+        }
+    }
+}
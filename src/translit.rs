@@ -0,0 +1,199 @@
+use crate::{Algorithm, Granularity, Scorer};
+
+/// Writes a string in plain Latin letters, so names spelled in different scripts, or with and without diacritics,
+/// can be compared: "Müller" is "Mueller", "Straße" is "Strasse", "Горбачёв" is "Gorbachev" and "Αθήνα" is "Athina".
+///
+/// * German umlauts and ß are written out, like they are when there's no way to type them: "ä" is "ae", "ß" is "ss".
+/// * Other Latin letters lose their diacritics, and ligatures are split: "é" is "e", "ø" is "o", "æ" is "ae".
+///   Combining marks, like the accent of an "e" followed by U+0301, are dropped.
+/// * Russian and Ukrainian Cyrillic is romanized in a simple scheme close to the one of passports:
+///   "ж" is "zh", "щ" is "shch", and the hard and soft signs are dropped.
+/// * Greek is romanized like ELOT 743, without its special cases: "θ" is "th", "χ" is "ch".
+///
+/// Everything else is left as it is. An uppercase letter that becomes several letters only capitalizes the first one,
+/// so "Ж" is "Zh".
+///
+/// # Arguments
+///
+/// * `text` - The string to transliterate.
+///
+/// # Returns
+///
+/// `String` - The string in Latin letters.
+///
+/// # Example
+/// ```
+/// use correct_word::translit::transliterate;
+///
+/// assert_eq!(transliterate("Müller"), "Mueller");
+/// assert_eq!(transliterate("Горбачёв"), "Gorbachev");
+/// assert_eq!(transliterate("Αθήνα"), "Athina");
+/// assert_eq!(transliterate("crème brûlée"), "creme brulee");
+/// ```
+pub fn transliterate(text: &str) -> String {
+    let mut latin = String::with_capacity(text.len());
+    for c in text.chars() {
+        if ('\u{300}'..='\u{36f}').contains(&c) {
+            continue;
+        }
+        let lower = c.to_lowercase().next().unwrap_or(c);
+        match latin_of(lower) {
+            Some(replacement) if lower != c => {
+                let mut chars = replacement.chars();
+                if let Some(first) = chars.next() {
+                    latin.extend(first.to_uppercase());
+                    latin.push_str(chars.as_str());
+                }
+            }
+            Some(replacement) => latin.push_str(replacement),
+            None => latin.push(c),
+        }
+    }
+    latin
+}
+
+/// The Latin letters of a lowercase character, or None if it stays as it is.
+fn latin_of(c: char) -> Option<&'static str> {
+    let latin = match c {
+        // German
+        'ä' => "ae",
+        'ö' => "oe",
+        'ü' => "ue",
+        'ß' => "ss",
+        // Other Latin letters
+        'à' | 'á' | 'â' | 'ã' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ș' => "s",
+        'ţ' | 'ť' | 'ŧ' | 'ț' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        // Cyrillic
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' | 'ґ' => "g",
+        'д' => "d",
+        'е' | 'ё' | 'э' => "e",
+        'є' => "ye",
+        'ж' => "zh",
+        'з' => "z",
+        'и' | 'і' => "i",
+        'ї' => "yi",
+        'й' | 'ы' => "y",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' => "u",
+        'ф' => "f",
+        'х' => "kh",
+        'ц' => "ts",
+        'ч' => "ch",
+        'ш' => "sh",
+        'щ' => "shch",
+        'ъ' | 'ь' => "",
+        'ю' => "yu",
+        'я' => "ya",
+        // Greek
+        'α' | 'ά' => "a",
+        'β' => "v",
+        'γ' => "g",
+        'δ' => "d",
+        'ε' | 'έ' => "e",
+        'ζ' => "z",
+        'η' | 'ή' | 'ι' | 'ί' | 'ϊ' | 'ΐ' => "i",
+        'θ' => "th",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "x",
+        'ο' | 'ό' | 'ω' | 'ώ' => "o",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' | 'ς' => "s",
+        'τ' => "t",
+        'υ' | 'ύ' | 'ϋ' | 'ΰ' => "y",
+        'φ' => "f",
+        'χ' => "ch",
+        'ψ' => "ps",
+        _ => return None,
+    };
+    Some(latin)
+}
+
+/// # Struct: TranslitScorer
+/// A [Scorer](../trait.Scorer.html) that [transliterates](fn.transliterate.html) the input and the option
+/// before scoring them with the inner scorer.
+/// Names that are romanized differently in different datasets, like "Müller" and "Mueller", become equal,
+/// instead of being a few edits apart.
+///
+/// The transliterated strings can be longer or shorter, so indexes like [TrieDictionary](../struct.TrieDictionary.html)
+/// score every option with it.
+///
+/// # Example
+/// ```
+/// use correct_word::translit::TranslitScorer;
+/// use correct_word::{Algorithm, Corrector};
+///
+/// let names = ["Mueller", "Muller", "Miller"];
+/// let corrector = Corrector::new(TranslitScorer::new(Algorithm::Levenshtein));
+/// let result = corrector.correct("Müller", &names);
+/// assert_eq!(result.word.unwrap(), "Mueller");
+/// assert_eq!(result.confidence, 1.0);
+///
+/// let result = corrector.correct("Чайковский", &["Tchaikovsky", "Chaykovskiy", "Stravinsky"]);
+/// assert_eq!(result.word.unwrap(), "Chaykovskiy");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TranslitScorer<S: Scorer = Algorithm> {
+    scorer: S,
+}
+
+impl<S: Scorer> TranslitScorer<S> {
+    /// Creates a transliterating scorer that compares the transliterated strings with the given algorithm, or scorer.
+    pub fn new(scorer: S) -> Self {
+        TranslitScorer { scorer }
+    }
+}
+
+impl<S: Scorer> Scorer for TranslitScorer<S> {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        self.scorer.similarity(&transliterate(a), &transliterate(b))
+    }
+
+    fn distance(&self, a: &str, b: &str) -> u16 {
+        self.scorer.distance(&transliterate(a), &transliterate(b))
+    }
+
+    fn similarity_with(&self, a: &str, b: &str, granularity: Granularity) -> f64 {
+        self.scorer
+            .similarity_with(&transliterate(a), &transliterate(b), granularity)
+    }
+
+    fn distance_with(&self, a: &str, b: &str, granularity: Granularity) -> u16 {
+        self.scorer
+            .distance_with(&transliterate(a), &transliterate(b), granularity)
+    }
+}
@@ -0,0 +1,569 @@
+#[cfg(feature = "serialize")]
+use std::io::{self, Read, Write};
+
+use crate::automaton::{AutomatonState, LevenshteinAutomaton};
+#[cfg(feature = "serialize")]
+use crate::binary;
+use crate::{instrument, CorrectWord, Corrector, Scorer};
+
+/// # Struct: TrieDictionary
+/// A dictionary that keeps its words in a [trie](https://en.wikipedia.org/wiki/Trie), a tree of shared prefixes.
+///
+/// Finding the words within a Levenshtein distance of the input walks the tree once,
+/// sharing the state of a [Levenshtein automaton](automaton/struct.LevenshteinAutomaton.html) between all the words with the same prefix,
+/// and leaves a branch as soon as the automaton can't match anymore.
+/// So with a small distance, most of a big dictionary is never looked at.
+/// This is the approach of [Steve Hanov's "Fast and Easy Levenshtein distance using a Trie"](http://stevehanov.ca/blog/?id=114).
+///
+/// As a [CandidateSource](trait.CandidateSource.html), it gives the same corrections as [Dictionary](struct.Dictionary.html),
+/// only scoring the words that are close enough when the [Scorer](trait.Scorer.html) knows how far away an option can be
+/// and still clear the threshold.
+///
+/// # Example
+/// ```
+/// use correct_word::{Algorithm, Corrector, TrieDictionary};
+///
+/// let dictionary: TrieDictionary = ["apple", "apply", "ample", "maple"].into_iter().collect();
+/// assert_eq!(dictionary.search("aple", 1), vec![("apple", 1), ("ample", 1), ("maple", 1)]);
+///
+/// let suggestions = dictionary.suggest(&Corrector::new(Algorithm::Levenshtein), "aple", 1);
+/// assert_eq!(suggestions[0].word.as_deref(), Some("apple"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrieDictionary {
+    nodes: Vec<Node>,
+    /// The words in the order they were added. A removed word is left as None until the next rebuild.
+    words: Vec<Option<String>>,
+    len: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// The children of the node, sorted by their character.
+    children: Vec<(char, usize)>,
+    /// The position in `words` of the word that ends at this node.
+    word: Option<usize>,
+}
+
+impl TrieDictionary {
+    /// Creates an empty dictionary.
+    pub fn new() -> Self {
+        TrieDictionary {
+            nodes: vec![Node::default()],
+            words: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Adds a word to the dictionary. Returns false if the word was already there.
+    pub fn insert(&mut self, word: impl Into<String>) -> bool {
+        let word = word.into();
+
+        let mut node = 0;
+        for c in word.chars() {
+            node = match self.nodes[node]
+                .children
+                .binary_search_by_key(&c, |&(c, _)| c)
+            {
+                Ok(child) => self.nodes[node].children[child].1,
+                Err(child) => {
+                    let next = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[node].children.insert(child, (c, next));
+                    next
+                }
+            };
+        }
+
+        if self.nodes[node].word.is_some() {
+            return false;
+        }
+        self.nodes[node].word = Some(self.words.len());
+        self.words.push(Some(word));
+        self.len += 1;
+        true
+    }
+
+    /// Removes a word from the dictionary. Returns false if the word wasn't there.
+    ///
+    /// Only the end of the word is unmarked, so this is as quick as [contains](#method.contains).
+    /// The nodes of the word stay in the tree until the next [rebuild](#method.rebuild).
+    pub fn remove(&mut self, word: &str) -> bool {
+        let Some(node) = self.find(word) else {
+            return false;
+        };
+        let Some(position) = self.nodes[node].word.take() else {
+            return false;
+        };
+        self.words[position] = None;
+        self.len -= 1;
+        true
+    }
+
+    /// Builds the tree again from the words left, dropping what removed words left behind.
+    /// The words keep their order.
+    pub fn rebuild(&mut self) {
+        let words = std::mem::take(&mut self.words);
+        *self = words.into_iter().flatten().collect();
+    }
+
+    /// The words of the dictionary, in the order they were added.
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.words.iter().flatten().map(String::as_str)
+    }
+
+    /// The number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the dictionary has no words.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether the dictionary has exactly this word.
+    pub fn contains(&self, word: &str) -> bool {
+        self.find(word)
+            .is_some_and(|node| self.nodes[node].word.is_some())
+    }
+
+    /// The node the word ends at, if there is one.
+    fn find(&self, word: &str) -> Option<usize> {
+        let mut node = 0;
+        for c in word.chars() {
+            let children = &self.nodes[node].children;
+            let child = children.binary_search_by_key(&c, |&(c, _)| c).ok()?;
+            node = children[child].1;
+        }
+        Some(node)
+    }
+
+    /// Returns the words within `max_distance` Levenshtein edits of the input, with their distance.
+    /// The closest words come first, and words at the same distance keep the order they were added in.
+    pub fn search(&self, input: &str, max_distance: u16) -> Vec<(&str, u16)> {
+        let mut found = self.within(input, max_distance);
+        found.sort_by_key(|&(word, distance)| (distance, word));
+        found
+            .into_iter()
+            .map(|(word, distance)| (self.word(word), distance))
+            .collect()
+    }
+
+    /// Returns up to `n` corrections of the input from the words of the dictionary, best first,
+    /// the same as [Corrector::correct_n_from](struct.Corrector.html#method.correct_n_from).
+    pub fn suggest<S: Scorer>(
+        &self,
+        corrector: &Corrector<S>,
+        input: &str,
+        n: usize,
+    ) -> Vec<CorrectWord> {
+        corrector.correct_n_from(input, self, n)
+    }
+
+    /// The words within `max_distance` of the input, in the order they were added.
+    pub(crate) fn within_in_order(
+        &self,
+        input: &str,
+        max_distance: u16,
+    ) -> impl Iterator<Item = &str> {
+        let mut found = self.within(input, max_distance);
+        found.sort_unstable();
+        found.into_iter().map(|(word, _)| self.word(word))
+    }
+
+    /// Writes the dictionary, tree and all, in a compact binary format, to be loaded with [deserialize](#method.deserialize).
+    /// Loading it is much quicker than inserting the words again, which is handy for big dictionaries built ahead of time.
+    ///
+    /// # Example
+    /// ```
+    /// use correct_word::TrieDictionary;
+    ///
+    /// let dictionary: TrieDictionary = ["apple", "apply", "ample"].into_iter().collect();
+    /// let mut bytes = Vec::new();
+    /// dictionary.serialize(&mut bytes).unwrap();
+    ///
+    /// let loaded = TrieDictionary::deserialize(bytes.as_slice()).unwrap();
+    /// assert_eq!(loaded.search("aple", 1), dictionary.search("aple", 1));
+    /// ```
+    #[cfg(feature = "serialize")]
+    pub fn serialize<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        binary::write_header(&mut writer, MAGIC)?;
+        binary::write_u32(&mut writer, self.words.len())?;
+        for word in &self.words {
+            match word {
+                Some(word) => binary::write_string(&mut writer, word)?,
+                None => binary::write_u32(&mut writer, binary::NONE as usize)?,
+            }
+        }
+
+        binary::write_u32(&mut writer, self.nodes.len())?;
+        for node in &self.nodes {
+            binary::write_u32(&mut writer, node.word.unwrap_or(binary::NONE as usize))?;
+            binary::write_u32(&mut writer, node.children.len())?;
+            for &(c, child) in &node.children {
+                binary::write_u32(&mut writer, c as usize)?;
+                binary::write_u32(&mut writer, child)?;
+            }
+        }
+        writer.flush()
+    }
+
+    /// Loads a dictionary written by [serialize](#method.serialize).
+    /// A reader that doesn't hold a serialized dictionary gives an `InvalidData` error,
+    /// and so does one whose nodes don't make a tree, or whose paths don't spell the words they end.
+    #[cfg(feature = "serialize")]
+    pub fn deserialize<R: Read>(mut reader: R) -> io::Result<Self> {
+        binary::read_header(&mut reader, MAGIC)?;
+        let count = binary::read_u32(&mut reader)?;
+        let mut words = Vec::with_capacity(binary::capacity(count));
+        for _ in 0..count {
+            words.push(match binary::read_u32(&mut reader)? {
+                binary::NONE => None,
+                len => Some(binary::read_string_of(&mut reader, len)?),
+            });
+        }
+
+        let count = binary::read_u32(&mut reader)? as usize;
+        let mut nodes = Vec::with_capacity(binary::capacity(count as u32));
+        let mut ended = vec![false; words.len()];
+        for position in 0..count {
+            let word = match binary::read_u32(&mut reader)? {
+                binary::NONE => None,
+                word => Some(word as usize),
+            };
+            if let Some(word) = word {
+                if words.get(word).is_none_or(Option::is_none) || ended[word] {
+                    return Err(binary::invalid("a node ends a word that is not there"));
+                }
+                ended[word] = true;
+            }
+
+            let children_count = binary::read_u32(&mut reader)?;
+            let mut children: Vec<(char, usize)> =
+                Vec::with_capacity(binary::capacity(children_count));
+            for _ in 0..children_count {
+                let c = char::from_u32(binary::read_u32(&mut reader)?)
+                    .ok_or_else(|| binary::invalid("a node has an invalid character"))?;
+                let child = binary::read_u32(&mut reader)? as usize;
+                // Children always come after their parent, so the tree can't have cycles
+                if child <= position
+                    || child >= count
+                    || children.last().is_some_and(|&(last, _)| last >= c)
+                {
+                    return Err(binary::invalid("a node has an invalid child"));
+                }
+                children.push((c, child));
+            }
+            nodes.push(Node { children, word });
+        }
+
+        if nodes.is_empty()
+            || words
+                .iter()
+                .zip(&ended)
+                .any(|(word, &ended)| word.is_some() != ended)
+        {
+            return Err(binary::invalid("the tree doesn't match the words"));
+        }
+
+        // Every node but the root has exactly one parent, so the nodes make a tree and each is visited once
+        let mut parents = vec![0u8; nodes.len()];
+        for &(_, child) in nodes.iter().flat_map(|node| &node.children) {
+            parents[child] = parents[child].saturating_add(1);
+        }
+        if parents[1..].iter().any(|&parents| parents != 1) {
+            return Err(binary::invalid("a node doesn't have exactly one parent"));
+        }
+
+        let len = ended.iter().filter(|&&ended| ended).count();
+        let dictionary = TrieDictionary { nodes, words, len };
+        for (position, word) in dictionary.words.iter().enumerate() {
+            let Some(word) = word else {
+                continue;
+            };
+            let node = dictionary.find(word);
+            if node.is_none_or(|node| dictionary.nodes[node].word != Some(position)) {
+                return Err(binary::invalid("the path to a word doesn't spell it"));
+            }
+        }
+        Ok(dictionary)
+    }
+
+    fn word(&self, position: usize) -> &str {
+        self.words[position]
+            .as_deref()
+            .expect("removed words are not in the tree")
+    }
+
+    /// The positions of the words within `max_distance` of the input, with their distance, in no particular order.
+    fn within(&self, input: &str, max_distance: u16) -> Vec<(usize, u16)> {
+        let mut found = Vec::new();
+        let automaton = LevenshteinAutomaton::new(input, max_distance);
+        self.visit(0, &automaton, &automaton.start(), &mut found);
+        found
+    }
+
+    /// Checks the word ending at `node`, in the given state of the automaton,
+    /// and then goes down into every child that can still be close enough.
+    fn visit(
+        &self,
+        node: usize,
+        automaton: &LevenshteinAutomaton,
+        state: &AutomatonState,
+        found: &mut Vec<(usize, u16)>,
+    ) {
+        let node = &self.nodes[node];
+        if let Some(word) = node.word {
+            if let Some(distance) = automaton.distance(state) {
+                found.push((word, distance));
+            }
+        }
+
+        for &(c, child) in &node.children {
+            let next = automaton.step(state, c);
+            if automaton.can_match(&next) {
+                self.visit(child, automaton, &next, found);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+const MAGIC: &[u8; 4] = b"CWT1";
+
+impl Default for TrieDictionary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Into<String>> FromIterator<W> for TrieDictionary {
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    fn from_iter<I: IntoIterator<Item = W>>(words: I) -> Self {
+        let mut dictionary = TrieDictionary::new();
+        dictionary.extend(words);
+        instrument::debug!(words = dictionary.len(), "built a trie");
+        dictionary
+    }
+}
+
+impl<W: Into<String>> Extend<W> for TrieDictionary {
+    fn extend<I: IntoIterator<Item = W>>(&mut self, words: I) {
+        for word in words {
+            self.insert(word);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::levenshtein::levenshtein_distance;
+    use crate::{Algorithm, Threshold};
+
+    fn words() -> Vec<String> {
+        let mut seed: u64 = 7;
+        let mut next = move |bound: u64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed % bound
+        };
+        (0..500)
+            .map(|_| {
+                let length = 1 + next(8);
+                (0..length)
+                    .map(|_| char::from(b'a' + next(4) as u8))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn search_matches_brute_force() {
+        let words = words();
+        let dictionary: TrieDictionary = words.iter().cloned().collect();
+        for input in ["", "a", "abcd", "dcbaab", "bbbbbbbbbb"] {
+            for max_distance in 0..4 {
+                let mut expected: Vec<(&str, u16)> = dictionary
+                    .words()
+                    .map(|word| {
+                        let distance = levenshtein_distance(input.to_string(), word.to_string());
+                        (word, distance)
+                    })
+                    .filter(|&(_, distance)| distance <= max_distance)
+                    .collect();
+                expected.sort_by_key(|&(_, distance)| distance);
+                assert_eq!(dictionary.search(input, max_distance), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn suggest_matches_linear_scan() {
+        let words = words();
+        let dictionary: TrieDictionary = words.iter().cloned().collect();
+        let unique: Vec<&str> = dictionary.words().collect();
+        for algorithm in [
+            Algorithm::Levenshtein,
+            Algorithm::Lcs,
+            Algorithm::RatcliffObershelp,
+            Algorithm::Osa,
+            Algorithm::DamerauLevenshtein,
+        ] {
+            for threshold in [
+                Threshold::Similarity(0.5),
+                Threshold::Similarity(0.8),
+                Threshold::MaxDistance(2),
+            ] {
+                let corrector = Corrector::new(algorithm).threshold(threshold);
+                for corrector in [corrector.clone(), corrector.prefix_bonus(4, 0.1)] {
+                    for input in ["abc", "dcbaab", "aaaa"] {
+                        assert_eq!(
+                            dictionary.suggest(&corrector, input, 10),
+                            corrector.correct_n(input, &unique, 10),
+                            "{algorithm:?} {threshold:?} {input}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn remove_and_rebuild() {
+        let mut dictionary: TrieDictionary =
+            ["apple", "apply", "ample", "app"].into_iter().collect();
+        assert!(dictionary.remove("apply"));
+        assert!(!dictionary.remove("apply"));
+        assert!(!dictionary.remove("ap"));
+        assert!(!dictionary.contains("apply") && dictionary.contains("app"));
+        assert_eq!(dictionary.search("apply", 1), vec![("apple", 1)]);
+
+        dictionary.extend(["apply", "maple"]);
+        let before: Vec<(String, u16)> = dictionary
+            .search("aple", 2)
+            .into_iter()
+            .map(|(word, distance)| (word.to_string(), distance))
+            .collect();
+        dictionary.rebuild();
+        assert_eq!(dictionary.len(), 5);
+        assert_eq!(
+            dictionary.words().collect::<Vec<_>>(),
+            ["apple", "ample", "app", "apply", "maple"]
+        );
+        let after: Vec<(String, u16)> = dictionary
+            .search("aple", 2)
+            .into_iter()
+            .map(|(word, distance)| (word.to_string(), distance))
+            .collect();
+        assert_eq!(before, after);
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn serialize_round_trip() {
+        let mut dictionary: TrieDictionary = words().into_iter().collect();
+        dictionary.remove("abc");
+        dictionary.remove(&words()[3]);
+        let mut bytes = Vec::new();
+        dictionary.serialize(&mut bytes).unwrap();
+
+        let loaded = TrieDictionary::deserialize(bytes.as_slice()).unwrap();
+        assert_eq!(loaded.len(), dictionary.len());
+        assert!(loaded.words().eq(dictionary.words()));
+        assert_eq!(loaded.search("abcd", 2), dictionary.search("abcd", 2));
+
+        for len in [0, 3, bytes.len() / 2, bytes.len() - 1] {
+            assert!(TrieDictionary::deserialize(&bytes[..len]).is_err());
+        }
+        // Points the first child of the root back at the root
+        let words_len: usize = dictionary
+            .words
+            .iter()
+            .map(|word| 4 + word.as_ref().map_or(0, String::len))
+            .sum();
+        let first_child = 8 + words_len + 4 + 4 + 4 + 4;
+        let mut corrupt = bytes.clone();
+        corrupt[first_child..first_child + 4].copy_from_slice(&0u32.to_le_bytes());
+        assert!(TrieDictionary::deserialize(corrupt.as_slice()).is_err());
+    }
+
+    /// A node as it is written: the word it ends and its children.
+    #[cfg(feature = "serialize")]
+    type RawNode<'a> = (Option<usize>, &'a [(char, usize)]);
+
+    /// Writes a dictionary of the given words and nodes.
+    #[cfg(feature = "serialize")]
+    fn raw(words: &[&str], nodes: &[RawNode]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        binary::write_header(&mut bytes, MAGIC).unwrap();
+        binary::write_u32(&mut bytes, words.len()).unwrap();
+        for word in words {
+            binary::write_string(&mut bytes, word).unwrap();
+        }
+        binary::write_u32(&mut bytes, nodes.len()).unwrap();
+        for &(word, children) in nodes {
+            binary::write_u32(&mut bytes, word.unwrap_or(binary::NONE as usize)).unwrap();
+            binary::write_u32(&mut bytes, children.len()).unwrap();
+            for &(c, child) in children {
+                binary::write_u32(&mut bytes, c as usize).unwrap();
+                binary::write_u32(&mut bytes, child).unwrap();
+            }
+        }
+        bytes
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn deserialize_rejects_malformed_trees() {
+        let valid = raw(
+            &["ab"],
+            &[(None, &[('a', 1)]), (None, &[('b', 2)]), (Some(0), &[])],
+        );
+        let loaded = TrieDictionary::deserialize(valid.as_slice()).unwrap();
+        assert!(loaded.contains("ab") && !loaded.contains("xb"));
+
+        // The path to the word spells "xb"
+        let misspelt = raw(
+            &["ab"],
+            &[(None, &[('x', 1)]), (None, &[('b', 2)]), (Some(0), &[])],
+        );
+        // Two edges of the root lead to the same node, so "ab" would be found twice
+        let shared = raw(
+            &["ab"],
+            &[
+                (None, &[('a', 1), ('b', 1)]),
+                (None, &[('b', 2)]),
+                (Some(0), &[]),
+            ],
+        );
+        // A node nothing leads to
+        let orphan = raw(&["a"], &[(None, &[('a', 1)]), (Some(0), &[]), (None, &[])]);
+        for bytes in [misspelt, shared, orphan] {
+            assert!(TrieDictionary::deserialize(bytes.as_slice()).is_err());
+        }
+
+        // Layers where every node leads to both nodes of the next one, so a search would go down 2^26 paths
+        let mut children: Vec<Vec<(char, usize)>> = Vec::new();
+        for layer in 0..26 {
+            let next = 1 + 2 * layer;
+            let count = if layer == 0 { 1 } else { 2 };
+            children.extend(std::iter::repeat_n(
+                vec![('a', next), ('b', next + 1)],
+                count,
+            ));
+        }
+        children.extend([Vec::new(), Vec::new()]);
+        let last = children.len() - 2;
+        let word = "a".repeat(26);
+        let nodes: Vec<RawNode> = children
+            .iter()
+            .enumerate()
+            .map(|(node, children)| ((node == last).then_some(0), children.as_slice()))
+            .collect();
+        let layered = raw(&[&word], &nodes);
+        assert!(TrieDictionary::deserialize(layered.as_slice()).is_err());
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::OnceLock;
+
+use crate::confusion::{detect_confusables, ConfusionScorer};
+use crate::{Algorithm, Scorer};
+
+/// # Enum: SquatPattern
+/// How a name imitates a legitimate one, in a [TyposquatRisk](struct.TyposquatRisk.html), from the riskiest down.
+///
+/// * Confusable: It looks the same, with characters that look alike, like a Cyrillic "а" for a Latin "a", or "1" for "l".
+///   See [detect_confusables](../confusion/fn.detect_confusables.html).
+/// * Normalized: It is the same name once the case and the separators `-`, `_` and `.` are ignored,
+///   like "python_dateutil" for "python-dateutil", which some registries treat as different names.
+/// * Swap: Two neighboring characters were swapped, like "reqeusts" for "requests".
+/// * Keyboard: It is a few edits away, and some of them are keys next to the right one on a QWERTY keyboard.
+/// * Edit: It is a few edits away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SquatPattern {
+    Confusable,
+    Normalized,
+    Swap,
+    Keyboard,
+    Edit,
+}
+
+/// # Struct: TyposquatRisk
+/// The legitimate name a candidate is the most likely to imitate, returned by [typosquat_score](fn.typosquat_score.html).
+///
+/// * `target`: The legitimate name.
+/// * `score`: The risk, from 0 to 1. The closer the candidate looks to the target, the higher.
+/// * `pattern`: How the candidate imitates the target. See [SquatPattern](enum.SquatPattern.html).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TyposquatRisk<'a> {
+    pub target: &'a str,
+    pub score: f64,
+    pub pattern: SquatPattern,
+}
+
+/// Scores how likely a new package or domain name is to imitate one of the legitimate ones, to flag it for review.
+///
+/// Every legitimate name is compared to the candidate with the patterns of [SquatPattern](enum.SquatPattern.html).
+/// A look-alike scores 1, a name that only differs by case and separators 0.95, and a swap of two neighboring characters 0.9.
+/// Any other name scores its similarity with a weighted edit distance, where a look-alike or a neighboring key
+/// costs less than another edit, so "reqursts" is riskier than "reqmests" for "requests".
+/// The riskiest legitimate name is returned, and the first of them on a tie.
+///
+/// # Arguments
+/// * `candidate` - The name to check, like the name of a package being published
+/// * `legit_names` - The legitimate names, like the most downloaded packages of the registry
+///
+/// # Returns
+/// `Option<TyposquatRisk>` - The legitimate name the candidate is the most likely to imitate,
+/// or None if the candidate is one of them, or there are none.
+///
+/// # Example
+/// ```
+/// use correct_word::typosquat::{typosquat_score, SquatPattern};
+///
+/// let popular = ["requests", "numpy", "python-dateutil"];
+/// let risk = typosquat_score("reqeusts", &popular).unwrap();
+/// assert_eq!((risk.target, risk.score, risk.pattern), ("requests", 0.9, SquatPattern::Swap));
+///
+/// // With a Cyrillic "у"
+/// assert_eq!(typosquat_score("numpу", &popular).unwrap().pattern, SquatPattern::Confusable);
+/// assert_eq!(typosquat_score("python_dateutil", &popular).unwrap().pattern, SquatPattern::Normalized);
+///
+/// let keyboard = typosquat_score("reqursts", &popular).unwrap();
+/// let other = typosquat_score("reqmests", &popular).unwrap();
+/// assert_eq!(keyboard.pattern, SquatPattern::Keyboard);
+/// assert!(keyboard.score > other.score);
+///
+/// assert_eq!(typosquat_score("numpy", &popular), None);
+/// ```
+pub fn typosquat_score<'a, S: AsRef<str>>(
+    candidate: &str,
+    legit_names: &'a [S],
+) -> Option<TyposquatRisk<'a>> {
+    if legit_names.iter().any(|name| name.as_ref() == candidate) {
+        return None;
+    }
+    let mut riskiest: Option<TyposquatRisk> = None;
+    for name in legit_names {
+        let risk = assess(candidate, name.as_ref());
+        if riskiest.is_none_or(|riskiest| risk.score > riskiest.score) {
+            riskiest = Some(risk);
+        }
+    }
+    riskiest
+}
+
+/// How much a candidate looks like a legitimate name that is different from it.
+fn assess<'a>(candidate: &str, target: &'a str) -> TyposquatRisk<'a> {
+    let (score, pattern) = if detect_confusables(candidate, target).is_some() {
+        (1.0, SquatPattern::Confusable)
+    } else if normalize(candidate) == normalize(target) {
+        (0.95, SquatPattern::Normalized)
+    } else if Algorithm::Osa.distance(candidate, target) == 1
+        && Algorithm::Levenshtein.distance(candidate, target) == 2
+    {
+        (0.9, SquatPattern::Swap)
+    } else {
+        static SCORER: OnceLock<ConfusionScorer> = OnceLock::new();
+        let scorer = SCORER
+            .get_or_init(|| ConfusionScorer::keyboard().merge(ConfusionScorer::confusables()));
+        let weighted = scorer.similarity(candidate, target);
+        if weighted > Algorithm::Levenshtein.similarity(candidate, target) {
+            (weighted, SquatPattern::Keyboard)
+        } else {
+            (weighted, SquatPattern::Edit)
+        }
+    };
+    TyposquatRisk {
+        target,
+        score,
+        pattern,
+    }
+}
+
+/// The name without its case and its separators, the way registries like PyPI compare names.
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '-' | '_' | '.'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
@@ -0,0 +1,118 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{Algorithm, CorrectWord, Corrector, UnknownAlgorithm};
+
+/// # Struct: Correction
+/// A correction as seen from JavaScript, with the same `word` and `confidence` as a [CorrectWord](../struct.CorrectWord.html).
+/// The word is `undefined` when no option cleared the threshold.
+#[wasm_bindgen(getter_with_clone)]
+pub struct Correction {
+    pub word: Option<String>,
+    pub confidence: f64,
+}
+
+impl From<CorrectWord> for Correction {
+    fn from(correction: CorrectWord) -> Self {
+        Correction {
+            word: correction.word,
+            confidence: correction.confidence,
+        }
+    }
+}
+
+/// # Struct: Dictionary
+/// A [Dictionary](../struct.Dictionary.html) for JavaScript, created from an array of words.
+/// It is kept in WebAssembly memory, so the words are only copied over once, however many words are corrected.
+#[wasm_bindgen(js_name = Dictionary)]
+pub struct WasmDictionary {
+    dictionary: crate::Dictionary,
+}
+
+#[wasm_bindgen(js_class = Dictionary)]
+impl WasmDictionary {
+    /// Creates a dictionary from an array of words.
+    #[wasm_bindgen(constructor)]
+    pub fn new(words: Vec<String>) -> WasmDictionary {
+        WasmDictionary {
+            dictionary: crate::Dictionary::from(words),
+        }
+    }
+
+    /// The number of words in the dictionary.
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.dictionary.len()
+    }
+
+    /// Corrects a word from the words of the dictionary, like `correctWord`.
+    pub fn correct(
+        &self,
+        input: &str,
+        algorithm: Option<String>,
+        threshold: Option<f64>,
+    ) -> Result<Correction, JsError> {
+        Ok(corrector(algorithm, threshold)?
+            .correct(input, self.dictionary.words())
+            .into())
+    }
+
+    /// Returns up to `n` corrections from the words of the dictionary, like `correctWordN`.
+    #[wasm_bindgen(js_name = correctN)]
+    pub fn correct_n(
+        &self,
+        input: &str,
+        n: usize,
+        algorithm: Option<String>,
+        threshold: Option<f64>,
+    ) -> Result<Vec<Correction>, JsError> {
+        let corrector = corrector(algorithm, threshold)?;
+        Ok(self
+            .dictionary
+            .suggest(&corrector, input, n)
+            .into_iter()
+            .map(Correction::from)
+            .collect())
+    }
+}
+
+/// Corrects a word from an array of options.
+/// The algorithm is a name like "levenshtein" or "ratcliff-obershelp", and defaults to Levenshtein.
+/// The threshold is the minimum similarity, and defaults to 0.5.
+#[wasm_bindgen(js_name = correctWord)]
+pub fn correct_word(
+    input: &str,
+    options: Vec<String>,
+    algorithm: Option<String>,
+    threshold: Option<f64>,
+) -> Result<Correction, JsError> {
+    Ok(corrector(algorithm, threshold)?
+        .correct(input, &options)
+        .into())
+}
+
+/// Returns up to `n` corrections of a word from an array of options, best first.
+#[wasm_bindgen(js_name = correctWordN)]
+pub fn correct_word_n(
+    input: &str,
+    options: Vec<String>,
+    n: usize,
+    algorithm: Option<String>,
+    threshold: Option<f64>,
+) -> Result<Vec<Correction>, JsError> {
+    let corrector = corrector(algorithm, threshold)?;
+    Ok(corrector
+        .correct_n(input, &options, n)
+        .into_iter()
+        .map(Correction::from)
+        .collect())
+}
+
+fn corrector(algorithm: Option<String>, threshold: Option<f64>) -> Result<Corrector, JsError> {
+    let algorithm = match algorithm {
+        Some(name) => name
+            .parse()
+            .map_err(|error: UnknownAlgorithm| JsError::new(&error.to_string()))?,
+        None => Algorithm::Levenshtein,
+    };
+    Ok(Corrector::new(algorithm).threshold(threshold.unwrap_or(0.5)))
+}
@@ -0,0 +1,103 @@
+use correct_word::automaton::LevenshteinAutomaton;
+use correct_word::metrics::{damerau, lcs, levenshtein};
+use correct_word::{Algorithm, AlgorithmInfo, Scorer};
+use proptest::prelude::*;
+
+/// The algorithms that don't care which string is the input.
+const SYMMETRIC: [Algorithm; 4] = [
+    Algorithm::Levenshtein,
+    Algorithm::Lcs,
+    Algorithm::Osa,
+    Algorithm::DamerauLevenshtein,
+];
+
+/// Short strings over a few characters, so they often share some of them, with non-ASCII ones
+/// to take the paths that don't work on bytes.
+fn word() -> impl Strategy<Value = String> {
+    prop_oneof!["[abc]{0,10}", "[ab\u{e9}\u{301}\u{436} ]{0,10}", ".{0,16}",]
+}
+
+proptest! {
+    #[test]
+    fn similarity_is_between_0_and_1(a in word(), b in word()) {
+        for algorithm in Algorithm::ALL {
+            let similarity = algorithm.similarity(&a, &b);
+            prop_assert!((0.0..=1.0).contains(&similarity), "{algorithm:?} {similarity}");
+        }
+    }
+
+    #[test]
+    fn equal_strings_are_similar(a in word()) {
+        for algorithm in Algorithm::ALL {
+            prop_assert_eq!(algorithm.similarity(&a, &a), 1.0, "{:?}", algorithm);
+            prop_assert_eq!(algorithm.distance(&a, &a), 0, "{:?}", algorithm);
+        }
+    }
+
+    #[test]
+    fn distance_is_0_only_for_equal_strings(a in word(), b in word()) {
+        for algorithm in SYMMETRIC {
+            prop_assert_eq!(algorithm.distance(&a, &b) == 0, a == b, "{:?}", algorithm);
+        }
+    }
+
+    #[test]
+    fn distance_is_symmetric(a in word(), b in word()) {
+        for algorithm in SYMMETRIC {
+            prop_assert_eq!(algorithm.distance(&a, &b), algorithm.distance(&b, &a), "{:?}", algorithm);
+            prop_assert_eq!(algorithm.similarity(&a, &b), algorithm.similarity(&b, &a), "{:?}", algorithm);
+        }
+    }
+
+    #[test]
+    fn triangle_inequality(a in word(), b in word(), c in word()) {
+        for algorithm in Algorithm::ALL.into_iter().filter(AlgorithmInfo::is_metric) {
+            let direct = algorithm.distance(&a, &c);
+            let through = algorithm.distance(&a, &b) + algorithm.distance(&b, &c);
+            prop_assert!(direct <= through, "{:?}", algorithm);
+        }
+    }
+
+    #[test]
+    fn edits_are_bounded(a in word(), b in word()) {
+        let levenshtein = Algorithm::Levenshtein.distance(&a, &b);
+        let longest = a.chars().count().max(b.chars().count()) as u16;
+        prop_assert!(levenshtein <= longest);
+        prop_assert!(Algorithm::DamerauLevenshtein.distance(&a, &b) <= Algorithm::Osa.distance(&a, &b));
+        prop_assert!(Algorithm::Osa.distance(&a, &b) <= levenshtein);
+        prop_assert!(levenshtein <= Algorithm::Lcs.distance(&a, &b));
+    }
+
+    #[test]
+    fn automaton_agrees_with_distance(a in word(), b in word(), max_edits in 0u16..4) {
+        let distance = Algorithm::Levenshtein.distance(&a, &b);
+        let automaton = LevenshteinAutomaton::new(&a, max_edits);
+        prop_assert_eq!(automaton.matches(&b), distance <= max_edits);
+    }
+
+    #[test]
+    fn slices_agree_with_strings(a in word(), b in word()) {
+        let chars1: Vec<char> = a.chars().collect();
+        let chars2: Vec<char> = b.chars().collect();
+        let distance = |algorithm: Algorithm| algorithm.distance(&a, &b) as usize;
+        prop_assert_eq!(levenshtein::levenshtein_distance_slice(&chars1, &chars2), distance(Algorithm::Levenshtein));
+        prop_assert_eq!(damerau::osa_distance_slice(&chars1, &chars2), distance(Algorithm::Osa));
+        prop_assert_eq!(
+            damerau::damerau_levenshtein_distance_slice(&chars1, &chars2),
+            distance(Algorithm::DamerauLevenshtein)
+        );
+        prop_assert_eq!(lcs::lcs_distance_slice(&chars1, &chars2), distance(Algorithm::Lcs));
+    }
+
+    #[test]
+    fn bytes_agree_with_slices(
+        a in prop::collection::vec(any::<u8>(), 0..150),
+        b in prop::collection::vec(any::<u8>(), 0..150),
+    ) {
+        prop_assert_eq!(levenshtein::levenshtein_distance_bytes(&a, &b), levenshtein::levenshtein_distance_slice(&a, &b));
+        for algorithm in Algorithm::ALL {
+            let similarity = algorithm.similarity_bytes(&a, &b);
+            prop_assert!((0.0..=1.0).contains(&similarity), "{:?} {}", algorithm, similarity);
+        }
+    }
+}